@@ -22,6 +22,7 @@ use module_replace_rpc::{Replace, ReplaceApiServer};
 use module_vault_registry_rpc::{VaultRegistry, VaultRegistryApiServer};
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 use substrate_frame_rpc_system::{System, SystemApiServer};
+use treasury_buyout_extension_rpc::{TreasuryBuyout, TreasuryBuyoutApiServer};
 
 use crate::service::{AmplitudeClient, FoucocoClient, PendulumClient};
 
@@ -58,6 +59,7 @@ where
 	module.merge(VaultRegistry::new(client.clone()).into_rpc())?;
 	module.merge(Oracle::new(client.clone()).into_rpc())?;
 	module.merge(FarmingRpc::new(client.clone()).into_rpc())?;
+	module.merge(TreasuryBuyout::new(client.clone()).into_rpc())?;
 	module.merge(ZenlinkProtocol::new(client).into_rpc())?;
 	Ok(module)
 }
@@ -79,6 +81,7 @@ where
 	module.merge(VaultRegistry::new(client.clone()).into_rpc())?;
 	module.merge(Oracle::new(client.clone()).into_rpc())?;
 	module.merge(FarmingRpc::new(client.clone()).into_rpc())?;
+	module.merge(TreasuryBuyout::new(client.clone()).into_rpc())?;
 	module.merge(ZenlinkProtocol::new(client).into_rpc())?;
 
 	Ok(module)
@@ -100,6 +103,7 @@ where
 	module.merge(VaultRegistry::new(client.clone()).into_rpc())?;
 	module.merge(Oracle::new(client.clone()).into_rpc())?;
 	module.merge(FarmingRpc::new(client.clone()).into_rpc())?;
+	module.merge(TreasuryBuyout::new(client.clone()).into_rpc())?;
 	module.merge(ZenlinkProtocol::new(client).into_rpc())?;
 
 	Ok(module)
@@ -0,0 +1,85 @@
+#![allow(warnings)]
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::{Pallet as LiquidStaking, *};
+use crate::types::{AccountIdOf, BalanceOf};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{assert_ok, traits::Currency};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const UNIT: u128 = 1_000_000_000_000;
+
+fn endow<T: Config>(who: &AccountIdOf<T>, amount: u128) {
+	<T as parachain_staking::Config>::Currency::make_free_balance_be(who, amount.into());
+}
+
+// Deposits into the pool and redeems half of the minted derivative
+fn set_up_redemption<T: Config>(caller: &AccountIdOf<T>) -> BalanceOf<T> {
+	endow::<T>(caller, 1_000_000 * UNIT);
+	let amount: BalanceOf<T> = T::MinDeposit::get().max((1_000 * UNIT).into());
+	assert_ok!(LiquidStaking::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), amount));
+	let redeemed = amount / 2u32.into();
+	assert_ok!(LiquidStaking::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), redeemed));
+	redeemed
+}
+
+benchmarks! {
+	deposit {
+		let caller: AccountIdOf<T> = account("Caller", 0, 0);
+		endow::<T>(&caller, 1_000_000 * UNIT);
+		let amount: BalanceOf<T> = T::MinDeposit::get().max((1_000 * UNIT).into());
+	}: deposit(RawOrigin::Signed(caller.clone()), amount)
+	verify {
+		assert_eq!(T::Tokens::free_balance(T::DerivativeCurrencyId::get(), &caller), amount);
+	}
+
+	redeem {
+		let caller: AccountIdOf<T> = account("Caller", 0, 0);
+		endow::<T>(&caller, 1_000_000 * UNIT);
+		let amount: BalanceOf<T> = T::MinDeposit::get().max((1_000 * UNIT).into());
+		assert_ok!(LiquidStaking::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), amount));
+	}: redeem(RawOrigin::Signed(caller.clone()), amount)
+	verify {
+		assert_eq!(PendingRedemption::<T>::get(), amount);
+	}
+
+	withdraw_unstaked {
+		let caller: AccountIdOf<T> = account("Caller", 0, 0);
+		let redeemed = set_up_redemption::<T>(&caller);
+		let now = frame_system::Pallet::<T>::block_number();
+		LiquidStaking::<T>::process_batch(now);
+		let unlock_block = now + parachain_staking::Pallet::<T>::staking_config().stake_duration;
+		frame_system::Pallet::<T>::set_block_number(unlock_block);
+	}: withdraw_unstaked(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Redemptions::<T>::get(&caller).is_empty());
+	}
+
+	compound {
+		let caller: AccountIdOf<T> = account("Caller", 0, 0);
+		endow::<T>(&caller, 1_000_000 * UNIT);
+		let amount: BalanceOf<T> = T::MinDeposit::get().max((1_000 * UNIT).into());
+		assert_ok!(LiquidStaking::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), amount));
+		// idle funds of the pool which have to be staked, e.g. rewards which
+		// could not be restaked automatically
+		let idle: BalanceOf<T> = (1_000 * UNIT).into();
+		let pool = LiquidStaking::<T>::account_id();
+		endow::<T>(&pool, 1_000_000 * UNIT);
+		TotalPooled::<T>::mutate(|pooled| *pooled = pooled.saturating_add(idle));
+	}: compound(RawOrigin::Signed(caller))
+
+	process_batch {
+		let caller: AccountIdOf<T> = account("Caller", 0, 0);
+		let redeemed = set_up_redemption::<T>(&caller);
+		let batch = CurrentBatch::<T>::get();
+		let now = frame_system::Pallet::<T>::block_number();
+	}: {
+		LiquidStaking::<T>::process_batch(now);
+	}
+	verify {
+		assert_eq!(CurrentBatch::<T>::get(), batch + 1);
+	}
+}
+
+impl_benchmark_test_suite!(LiquidStaking, crate::mock::ExtBuilder::build(), crate::mock::Test);
@@ -0,0 +1,636 @@
+#![deny(warnings)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Liquid Staking Pallet
+//!
+//! Pools native token deposits and delegates them to the collator candidates
+//! selected by `parachain-staking`. Depositors receive a derivative token
+//! minted via `orml-tokens` which can be transferred and traded while the
+//! underlying stake keeps earning rewards.
+//!
+//! ## Exchange rate
+//!
+//! The derivative is backed by the native tokens tracked in `TotalPooled`,
+//! which only changes by deposits, redemptions and staking rewards paid out to
+//! the pool. Tokens transferred to the pool account by other means do not back
+//! the derivative, so they cannot be used to manipulate the exchange rate.
+//!
+//! Rewards are restaked by setting the auto-compound rate of the pool to 100%.
+//! They are reported to the pallet via the `OnStakingUpdate` hooks of
+//! `parachain-staking`, which have to include this pallet, no matter whether
+//! they are paid out by the permissionless `compound` call or by anyone else.
+//! Each reward increases the backing and thus the exchange rate of the
+//! derivative.
+//!
+//! ## Redemption
+//!
+//! Redeemed derivatives are burned immediately. The native tokens they are
+//! worth are collected into a batch which is unstaked every
+//! `RedemptionPeriod` blocks. Unstaking goes through the `Unstaking` queue of
+//! `parachain-staking`, so redeemers can withdraw their tokens after the
+//! stake duration has passed.
+//!
+//! `RedemptionPeriod` has to be chosen large enough that the number of batches
+//! unlocking at the same time never exceeds `MaxUnstakeRequests`.
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+pub mod default_weights;
+
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+use crate::types::{AccountIdOf, BalanceOf, CurrencyIdOf};
+
+pub use crate::{
+	default_weights::WeightInfo,
+	types::{BatchIndex, RedemptionRequest, UnstakedBatch},
+};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{Currency, ExistenceRequirement, Get},
+	weights::Weight,
+	PalletId,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use orml_traits::MultiCurrency;
+pub use pallet::*;
+use parachain_staking::WeightInfo as StakingWeightInfo;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, Saturating, StaticLookup, Zero},
+	DispatchError, Perquintill, Rounding, SaturatedConversion,
+};
+use sp_std::vec::Vec;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::{ensure_signed, pallet_prelude::*};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + parachain_staking::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Used for minting and burning the derivative token
+		type Tokens: MultiCurrency<AccountIdOf<Self>, Balance = BalanceOf<Self>>;
+
+		/// The currency id of the derivative token
+		#[pallet::constant]
+		type DerivativeCurrencyId: Get<CurrencyIdOf<Self>>;
+
+		/// Used for deriving the account of the staking pool
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Min amount of native token to deposit
+		#[pallet::constant]
+		type MinDeposit: Get<BalanceOf<Self>>;
+
+		/// Number of blocks after which the pending redemptions are unstaked
+		#[pallet::constant]
+		type RedemptionPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of redemptions an account can wait for at once
+		#[pallet::constant]
+		type MaxRedemptionRequests: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Less than minimum amount allowed for deposit
+		LessThanMinDeposit,
+		/// The amount is worth nothing at the current exchange rate
+		AmountTooLow,
+		/// Exceeds number of allowed redemption requests
+		TooManyRedemptionRequests,
+		/// None of the redemptions of the account has been unlocked yet
+		NothingToWithdraw,
+		/// The pool does not have enough stake to unstake the pending redemptions
+		InsufficientStake,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Native tokens were deposited in exchange for the derivative
+		Deposited { who: AccountIdOf<T>, amount: BalanceOf<T>, minted: BalanceOf<T> },
+		/// Derivative tokens were burned and added to the current redemption batch
+		Redeemed {
+			who: AccountIdOf<T>,
+			burned: BalanceOf<T>,
+			amount: BalanceOf<T>,
+			batch: BatchIndex,
+		},
+		/// Unlocked redemptions were paid out
+		Withdrawn { who: AccountIdOf<T>, amount: BalanceOf<T> },
+		/// Rewards were claimed and idle funds of the pool were staked
+		Compounded { rewards: BalanceOf<T>, staked: BalanceOf<T> },
+		/// A redemption batch was unstaked
+		BatchUnstaked { batch: BatchIndex, amount: BalanceOf<T>, unlock_block: BlockNumberFor<T> },
+		/// Unstaking a redemption batch failed, it is retried after the next period
+		BatchUnstakingFailed { batch: BatchIndex, error: DispatchError },
+	}
+
+	/// The index of the batch which collects new redemptions.
+	#[pallet::storage]
+	#[pallet::getter(fn current_batch)]
+	pub type CurrentBatch<T: Config> = StorageValue<_, BatchIndex, ValueQuery>;
+
+	/// The amount of native tokens backing the derivative.
+	#[pallet::storage]
+	#[pallet::getter(fn total_pooled)]
+	pub type TotalPooled<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The amount of native tokens redeemed in the current batch. These are
+	/// still staked until the batch is unstaked.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_redemption)]
+	pub type PendingRedemption<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The amount of native tokens owed to redeemers, including the pending
+	/// ones. These do not back the derivative anymore.
+	#[pallet::storage]
+	#[pallet::getter(fn total_redeeming)]
+	pub type TotalRedeeming<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The unstaked batches which have not been withdrawn completely.
+	#[pallet::storage]
+	#[pallet::getter(fn unstaked_batch)]
+	pub type UnstakedBatches<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BatchIndex,
+		UnstakedBatch<BlockNumberFor<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The redemptions of each account which have not been withdrawn yet.
+	#[pallet::storage]
+	#[pallet::getter(fn redemptions)]
+	pub type Redemptions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		BoundedVec<RedemptionRequest<BalanceOf<T>>, T::MaxRedemptionRequests>,
+		ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let period = T::RedemptionPeriod::get();
+			if period.is_zero() || !(now % period).is_zero() {
+				return Weight::zero();
+			}
+			if PendingRedemption::<T>::get().is_zero() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			Self::process_batch(now);
+			Self::process_batch_weight()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Deposit native tokens into the staking pool and receive the
+		/// derivative token at the current exchange rate.
+		///
+		/// The deposited tokens are delegated right away, either to a selected
+		/// collator which is not backed by the pool yet or by adding them to
+		/// the smallest delegation of the pool.
+		///
+		/// Emits `Deposited`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit())]
+		pub fn deposit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(amount >= T::MinDeposit::get(), Error::<T>::LessThanMinDeposit);
+
+			let minted = Self::native_to_derivative(amount);
+			ensure!(!minted.is_zero(), Error::<T>::AmountTooLow);
+
+			let pool = Self::account_id();
+			<T as parachain_staking::Config>::Currency::transfer(
+				&who,
+				&pool,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			// nothing backs the derivative without supply, e.g. rewards which were
+			// paid out after everything had been redeemed
+			if T::Tokens::total_issuance(T::DerivativeCurrencyId::get()).is_zero() {
+				TotalPooled::<T>::kill();
+			}
+			T::Tokens::deposit(T::DerivativeCurrencyId::get(), &who, minted)?;
+			TotalPooled::<T>::mutate(|pooled| *pooled = pooled.saturating_add(amount));
+
+			Self::do_stake(&pool, Self::idle_balance(&pool))?;
+
+			Self::deposit_event(Event::<T>::Deposited { who, amount, minted });
+			Ok(())
+		}
+
+		/// Burn derivative tokens and add the native tokens they are worth to
+		/// the current redemption batch.
+		///
+		/// The native tokens can be withdrawn via `withdraw_unstaked` once the
+		/// batch has been unstaked and unlocked.
+		///
+		/// Emits `Redeemed`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem())]
+		pub fn redeem(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let native = Self::derivative_to_native(amount);
+			ensure!(!native.is_zero(), Error::<T>::AmountTooLow);
+
+			T::Tokens::withdraw(T::DerivativeCurrencyId::get(), &who, amount)?;
+
+			let batch = CurrentBatch::<T>::get();
+			Redemptions::<T>::try_mutate(&who, |requests| -> DispatchResult {
+				match requests.iter_mut().find(|request| request.batch == batch) {
+					Some(request) => request.amount = request.amount.saturating_add(native),
+					None => requests
+						.try_push(RedemptionRequest { batch, amount: native })
+						.map_err(|_| Error::<T>::TooManyRedemptionRequests)?,
+				}
+				Ok(())
+			})?;
+			TotalPooled::<T>::mutate(|pooled| *pooled = pooled.saturating_sub(native));
+			PendingRedemption::<T>::mutate(|pending| *pending = pending.saturating_add(native));
+			TotalRedeeming::<T>::mutate(|total| *total = total.saturating_add(native));
+
+			Self::deposit_event(Event::<T>::Redeemed {
+				who,
+				burned: amount,
+				amount: native,
+				batch,
+			});
+			Ok(())
+		}
+
+		/// Withdraw the native tokens of all redemptions of the caller whose
+		/// batch has been unlocked.
+		///
+		/// Emits `Withdrawn`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unstaked())]
+		pub fn withdraw_unstaked(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			let mut requests = Redemptions::<T>::get(&who);
+			let mut amount = BalanceOf::<T>::zero();
+			requests.retain(|request| {
+				let unlocked = UnstakedBatches::<T>::mutate_exists(request.batch, |maybe_batch| {
+					match maybe_batch {
+						Some(batch) if batch.unlock_block <= now => {
+							batch.remaining = batch.remaining.saturating_sub(request.amount);
+							if batch.remaining.is_zero() {
+								*maybe_batch = None;
+							}
+							true
+						},
+						_ => false,
+					}
+				});
+				if unlocked {
+					amount = amount.saturating_add(request.amount);
+				}
+				!unlocked
+			});
+			ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+			let pool = Self::account_id();
+			// fails if there are no unstaked funds which can be unlocked, e.g. if
+			// they have already been unlocked by another withdrawal
+			let _ = parachain_staking::Pallet::<T>::unlock_unstaked(
+				RawOrigin::Signed(pool.clone()).into(),
+				T::Lookup::unlookup(pool.clone()),
+			);
+			<T as parachain_staking::Config>::Currency::transfer(
+				&pool,
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			TotalRedeeming::<T>::mutate(|total| *total = total.saturating_sub(amount));
+			if requests.is_empty() {
+				Redemptions::<T>::remove(&who);
+			} else {
+				Redemptions::<T>::insert(&who, requests);
+			}
+
+			Self::deposit_event(Event::<T>::Withdrawn { who, amount });
+			Ok(())
+		}
+
+		/// Claim the rewards of the staking pool and stake all idle funds.
+		///
+		/// Idle funds are claimed rewards which could not be restaked
+		/// automatically and stake which was returned because the pool has
+		/// been removed from a collator. Tokens which were transferred to the
+		/// pool account by other means are not staked.
+		///
+		/// Can be called by any signed origin.
+		///
+		/// Emits `Compounded`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::compound())]
+		pub fn compound(origin: OriginFor<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let pool = Self::account_id();
+			let pooled_before = Self::total_pooled();
+
+			// fails if the pool has not earned any rewards since the last call
+			let _ = parachain_staking::Pallet::<T>::increment_delegator_rewards(
+				RawOrigin::Signed(pool.clone()).into(),
+			);
+			if !parachain_staking::Pallet::<T>::rewards(&pool).is_zero() {
+				parachain_staking::Pallet::<T>::claim_rewards(
+					RawOrigin::Signed(pool.clone()).into(),
+				)?;
+			}
+			let rewards = Self::total_pooled().saturating_sub(pooled_before);
+
+			let staked = Self::idle_balance(&pool);
+			Self::do_stake(&pool, staked)?;
+
+			Self::deposit_event(Event::<T>::Compounded { rewards, staked });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account holding and staking the pooled native tokens.
+	pub fn account_id() -> AccountIdOf<T> {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The amount of derivative tokens minted for `amount` native tokens.
+	pub fn native_to_derivative(amount: BalanceOf<T>) -> BalanceOf<T> {
+		let supply = T::Tokens::total_issuance(T::DerivativeCurrencyId::get());
+		let pooled = Self::total_pooled();
+		if supply.is_zero() || pooled.is_zero() {
+			return amount;
+		}
+		Self::mul_div(amount, supply, pooled)
+	}
+
+	/// The amount of native tokens `amount` derivative tokens are worth.
+	pub fn derivative_to_native(amount: BalanceOf<T>) -> BalanceOf<T> {
+		let supply = T::Tokens::total_issuance(T::DerivativeCurrencyId::get());
+		if supply.is_zero() {
+			return BalanceOf::<T>::zero();
+		}
+		Self::mul_div(amount, Self::total_pooled(), supply)
+	}
+
+	fn mul_div(
+		amount: BalanceOf<T>,
+		numerator: BalanceOf<T>,
+		denominator: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		multiply_by_rational_with_rounding(
+			amount.saturated_into(),
+			numerator.saturated_into(),
+			denominator.saturated_into(),
+			Rounding::Down,
+		)
+		.unwrap_or_default()
+		.saturated_into()
+	}
+
+	/// The sum of all delegations of the pool.
+	fn total_staked(pool: &AccountIdOf<T>) -> BalanceOf<T> {
+		parachain_staking::Pallet::<T>::get_delegations(pool)
+			.into_iter()
+			.fold(BalanceOf::<T>::zero(), |total, delegation| {
+				total.saturating_add(delegation.amount)
+			})
+	}
+
+	/// The amount of native tokens which should be staked but are not.
+	///
+	/// The pending redemptions are still staked until their batch is
+	/// processed.
+	fn idle_balance(pool: &AccountIdOf<T>) -> BalanceOf<T> {
+		Self::total_pooled()
+			.saturating_add(PendingRedemption::<T>::get())
+			.saturating_sub(Self::total_staked(pool))
+	}
+
+	/// Delegate `amount` to a selected collator which is neither invulnerable
+	/// nor backed by the pool yet, starting with the one with the least stake.
+	/// Falls back to increasing the smallest delegation of the pool.
+	///
+	/// Keeps the funds idle if the pool does not delegate yet and `amount` is
+	/// below the minimum delegator stake.
+	fn do_stake(pool: &AccountIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		let delegations = parachain_staking::Pallet::<T>::get_delegations(pool);
+		let min_stake = parachain_staking::Pallet::<T>::staking_config().min_delegator_stake;
+		if delegations.len() <
+			<T as parachain_staking::Config>::MaxCollatorsPerDelegator::get() as usize &&
+			amount >= min_stake
+		{
+			let candidates = parachain_staking::Pallet::<T>::selected_candidates();
+			// invulnerables are not rewarded
+			let invulnerables = parachain_staking::Pallet::<T>::invulnerables();
+			for collator in candidates
+				.iter()
+				.rev()
+				.filter(|candidate| !invulnerables.contains(*candidate))
+				.filter(|candidate| !delegations.iter().any(|d| &d.collator == *candidate))
+			{
+				let origin = RawOrigin::Signed(pool.clone());
+				let collator = T::Lookup::unlookup(collator.clone());
+				let result = if delegations.is_empty() {
+					parachain_staking::Pallet::<T>::join_delegators(
+						origin.into(),
+						collator,
+						amount,
+						None,
+					)
+				} else {
+					parachain_staking::Pallet::<T>::delegate_another_candidate(
+						origin.into(),
+						collator,
+						amount,
+					)
+				};
+				if result.is_ok() {
+					// the auto-compound rate is removed when the pool stops delegating
+					if delegations.is_empty() {
+						parachain_staking::Pallet::<T>::set_auto_compound(
+							RawOrigin::Signed(pool.clone()).into(),
+							Perquintill::one(),
+						)?;
+					}
+					return Ok(());
+				}
+			}
+		}
+
+		match delegations.iter().min_by_key(|delegation| delegation.amount) {
+			Some(delegation) => parachain_staking::Pallet::<T>::delegator_stake_more(
+				RawOrigin::Signed(pool.clone()).into(),
+				T::Lookup::unlookup(delegation.collator.clone()),
+				amount,
+				None,
+			)
+			.map(|_| ())
+			.map_err(|e| e.error),
+			None => Ok(()),
+		}
+	}
+
+	/// Unstake `amount` from the delegations of the pool, starting with the
+	/// largest one.
+	///
+	/// Delegations are only revoked if reducing them would fall below the
+	/// minimum delegator stake. Any surplus of a revocation stays idle until
+	/// it is staked again by `compound`.
+	fn do_unstake(pool: &AccountIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+		let min_stake = parachain_staking::Pallet::<T>::staking_config().min_delegator_stake;
+		let mut delegations: Vec<_> = parachain_staking::Pallet::<T>::get_delegations(pool);
+		delegations.sort_by(|a, b| b.amount.cmp(&a.amount));
+
+		let mut left = amount;
+		let mut remaining = Vec::new();
+		for mut delegation in delegations {
+			if left.is_zero() {
+				break;
+			}
+			let origin = RawOrigin::Signed(pool.clone());
+			let collator = T::Lookup::unlookup(delegation.collator.clone());
+			if left >= delegation.amount {
+				parachain_staking::Pallet::<T>::revoke_delegation(origin.into(), collator)
+					.map_err(|e| e.error)?;
+				left = left.saturating_sub(delegation.amount);
+				continue;
+			}
+
+			let less = left.min(delegation.amount.saturating_sub(min_stake));
+			if !less.is_zero() {
+				parachain_staking::Pallet::<T>::delegator_stake_less(origin.into(), collator, less)
+					.map_err(|e| e.error)?;
+				left = left.saturating_sub(less);
+				delegation.amount = delegation.amount.saturating_sub(less);
+			}
+			remaining.push(delegation);
+		}
+
+		// the remaining delegations are at the minimum stake
+		for delegation in remaining.into_iter().rev() {
+			if left.is_zero() {
+				break;
+			}
+			parachain_staking::Pallet::<T>::revoke_delegation(
+				RawOrigin::Signed(pool.clone()).into(),
+				T::Lookup::unlookup(delegation.collator),
+			)
+			.map_err(|e| e.error)?;
+			left = left.saturating_sub(delegation.amount);
+		}
+
+		ensure!(left.is_zero(), Error::<T>::InsufficientStake);
+		Ok(())
+	}
+
+	/// Unstake the pending redemptions and close the current batch.
+	///
+	/// Pending redemptions which are covered by idle funds are unlocked right
+	/// away. If unstaking fails, the batch stays open and is retried after
+	/// the next period.
+	/// The weight of processing a redemption batch.
+	///
+	/// Unstaking may reduce and revoke every delegation of the pool, thus the
+	/// weight of both staking calls is added for each possible delegation.
+	fn process_batch_weight() -> Weight {
+		let max_delegations =
+			<T as parachain_staking::Config>::MaxCollatorsPerDelegator::get() as u64;
+		let max_candidates = <T as parachain_staking::Config>::MaxTopCandidates::get();
+		let max_delegators = <T as parachain_staking::Config>::MaxDelegatorsPerCollator::get();
+		let per_delegation = <T as parachain_staking::Config>::WeightInfo::delegator_stake_less(
+			max_candidates,
+			max_delegators,
+		)
+		.saturating_add(<T as parachain_staking::Config>::WeightInfo::revoke_delegation(
+			max_candidates,
+			max_delegators,
+		));
+
+		<T as Config>::WeightInfo::process_batch()
+			.saturating_add(per_delegation.saturating_mul(max_delegations))
+	}
+
+	pub(crate) fn process_batch(now: BlockNumberFor<T>) {
+		let pool = Self::account_id();
+		let batch = CurrentBatch::<T>::get();
+		let amount = PendingRedemption::<T>::get();
+		let to_unstake = Self::total_staked(&pool).saturating_sub(Self::total_pooled());
+
+		match frame_support::storage::with_storage_layer(|| Self::do_unstake(&pool, to_unstake)) {
+			Ok(()) => {
+				let unlock_block = if to_unstake.is_zero() {
+					now
+				} else {
+					now.saturating_add(
+						parachain_staking::Pallet::<T>::staking_config().stake_duration,
+					)
+				};
+				UnstakedBatches::<T>::insert(
+					batch,
+					UnstakedBatch { unlock_block, remaining: amount },
+				);
+				PendingRedemption::<T>::kill();
+				CurrentBatch::<T>::put(batch.saturating_add(1));
+
+				Self::deposit_event(Event::<T>::BatchUnstaked { batch, amount, unlock_block });
+			},
+			Err(error) => {
+				log::warn!(
+					target: "liquid-staking",
+					"💥 failed to unstake redemption batch {:?}: {:?}",
+					batch,
+					error
+				);
+				Self::deposit_event(Event::<T>::BatchUnstakingFailed { batch, error });
+			},
+		}
+	}
+}
+
+impl<T: Config> parachain_staking::OnStakingUpdate<AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	/// Adds the rewards paid out to the pool to the backing of the derivative.
+	fn on_rewards_paid(who: &AccountIdOf<T>, amount: BalanceOf<T>) {
+		if who == &Self::account_id() {
+			TotalPooled::<T>::mutate(|pooled| *pooled = pooled.saturating_add(amount));
+		}
+	}
+}
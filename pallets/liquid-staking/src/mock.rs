@@ -0,0 +1,348 @@
+use crate::{self as liquid_staking, Config};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstBool, ConstU32, Everything, OnFinalize, OnInitialize},
+	PalletId,
+};
+use orml_traits::parameter_type_with_key;
+use pallet_authorship::EventHandler;
+use parachain_staking::InflationInfo;
+use sp_consensus_aura::sr25519::AuthorityId;
+use sp_core::H256;
+use sp_runtime::{
+	impl_opaque_keys,
+	testing::UintAuthorityId,
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
+	BuildStorage, Perquintill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+pub type Amount = i64;
+pub type CurrencyId = u64;
+
+pub const UNIT: Balance = 1_000_000_000_000;
+pub const BLOCKS_PER_ROUND: BlockNumber = 5;
+pub const TREASURY_ACC: AccountId = u64::MAX;
+pub const TREASURY_INITIAL_BALANCE: Balance = 1_000_000 * UNIT;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Aura: pallet_aura,
+		Session: pallet_session,
+		StakePallet: parachain_staking,
+		Authorship: pallet_authorship,
+		LiquidStaking: liquid_staking,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type Block = Block;
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type RuntimeTask = RuntimeTask;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ConstU32<1>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		0
+	};
+}
+
+pub struct CurrencyHooks<T>(sp_std::marker::PhantomData<T>);
+impl<T: orml_tokens::Config>
+	orml_traits::currency::MutationHooks<T::AccountId, T::CurrencyId, T::Balance>
+	for CurrencyHooks<T>
+{
+	type OnDust = orml_tokens::BurnDust<T>;
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = CurrencyHooks<Self>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<0>;
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+impl pallet_aura::Config for Test {
+	type AuthorityId = AuthorityId;
+	type DisabledValidators = ();
+	type MaxAuthorities = MaxCollatorCandidates;
+	type AllowMultipleBlocksPerSlot = ();
+}
+
+impl pallet_authorship::Config for Test {
+	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
+	type EventHandler = StakePallet;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 1;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinBlocksPerRound: BlockNumber = 3;
+	pub const StakeDuration: BlockNumber = 2;
+	pub const MaxStakeDuration: BlockNumber = 10;
+	pub const ExitQueueDelay: u32 = 2;
+	pub const DefaultBlocksPerRound: BlockNumber = BLOCKS_PER_ROUND;
+	pub const MinCollators: u32 = 2;
+	pub const MaxDelegationsPerRound: u32 = 2;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxDelegatorsPerCollator: u32 = 4;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorsPerDelegator: u32 = 4;
+	pub const MinCollatorStake: Balance = 10 * UNIT;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorCandidates: u32 = 10;
+	pub const MinDelegatorStake: Balance = 5 * UNIT;
+	pub const MaxUnstakeRequests: u32 = 6;
+	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
+	pub const MaxNetworkRewardRate: Perquintill = Perquintill::from_percent(20);
+	pub const NetworkRewardStart: BlockNumber = 5 * 5 * 60 * 24 * 36525 / 100;
+	pub const CollatorRewardRateDecay: Perquintill = Perquintill::from_percent(98);
+	pub const TreasuryAccount: AccountId = TREASURY_ACC;
+	pub const MaxCollatorCommission: Perquintill = Perquintill::from_percent(20);
+	pub const CommissionChangeDelay: u32 = 2;
+	pub const MaxRoundSnapshots: u32 = 3;
+	pub const MaxPayoutStakers: u32 = 4;
+	pub StakeLockPeriods: Vec<(BlockNumber, Perquintill)> = vec![];
+	pub const ExtraRewardsPotId: PalletId = PalletId(*b"pe/stkrw");
+}
+
+impl parachain_staking::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CurrencyBalance = Balance;
+	type MinBlocksPerRound = MinBlocksPerRound;
+	type DefaultBlocksPerRound = DefaultBlocksPerRound;
+	type StakeDuration = StakeDuration;
+	type ExitQueueDelay = ExitQueueDelay;
+	type MinCollators = MinCollators;
+	type MinRequiredCollators = MinCollators;
+	type MaxDelegationsPerRound = MaxDelegationsPerRound;
+	type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
+	type MinCollatorStake = MinCollatorStake;
+	type MinCollatorCandidateStake = MinCollatorStake;
+	type MaxTopCandidates = MaxCollatorCandidates;
+	type MinDelegatorStake = MinDelegatorStake;
+	type MaxUnstakeRequests = MaxUnstakeRequests;
+	type NetworkRewardRate = NetworkRewardRate;
+	type MaxNetworkRewardRate = MaxNetworkRewardRate;
+	type MaxStakeDuration = MaxStakeDuration;
+	type NetworkRewardStart = NetworkRewardStart;
+	type NetworkRewardBeneficiary = ();
+	type CollatorRewardRateDecay = CollatorRewardRateDecay;
+	type WeightInfo = ();
+	const BLOCKS_PER_YEAR: BlockNumber = 5 * 60 * 24 * 36525 / 100;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxCollatorCommission = MaxCollatorCommission;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type MaxRoundSnapshots = MaxRoundSnapshots;
+	type MaxPayoutStakers = MaxPayoutStakers;
+	type StakeLockPeriods = StakeLockPeriods;
+	type OnStakingUpdate = LiquidStaking;
+	type DisplacedDelegatorGracePeriod = ConstBool<true>;
+	type ExtraRewardCurrency = Tokens;
+	type ExtraRewardsPotId = ExtraRewardsPotId;
+	type MaxExtraRewardCurrencies = ConstU32<2>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ExtraRewardBenchmarkCurrencyId = frame_support::traits::ConstU64<1>;
+}
+
+impl_opaque_keys! {
+	pub struct MockSessionKeys {
+		pub aura: Aura,
+	}
+}
+
+impl pallet_session::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = StakePallet;
+	type NextSessionRotation = StakePallet;
+	type SessionManager = StakePallet;
+	type SessionHandler = <MockSessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = MockSessionKeys;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const DerivativeCurrencyId: CurrencyId = 1;
+	pub const LiquidStakingPalletId: PalletId = PalletId(*b"pe/lqstk");
+	pub const MinDeposit: Balance = UNIT;
+	pub const RedemptionPeriod: BlockNumber = 10;
+	pub const MaxRedemptionRequests: u32 = 3;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Tokens = Tokens;
+	type DerivativeCurrencyId = DerivativeCurrencyId;
+	type PalletId = LiquidStakingPalletId;
+	type MinDeposit = MinDeposit;
+	type RedemptionPeriod = RedemptionPeriod;
+	type MaxRedemptionRequests = MaxRedemptionRequests;
+	type WeightInfo = ();
+}
+
+// ------- Constants and Genesis Config ------ //
+
+pub const ALICE: AccountId = 10;
+pub const BOB: AccountId = 11;
+pub const USERS_INITIAL_BALANCE: Balance = 1_000 * UNIT;
+pub const COLLATOR_STAKE: Balance = 100 * UNIT;
+
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+	/// Collators `1` and `2` are selected, `3` is a candidate.
+	pub fn build() -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		let collators: Vec<(AccountId, Balance)> =
+			vec![(1, COLLATOR_STAKE), (2, COLLATOR_STAKE), (3, COLLATOR_STAKE / 2)];
+
+		let mut balances: Vec<(AccountId, Balance)> =
+			collators.iter().map(|(acc, _)| (*acc, COLLATOR_STAKE)).collect();
+		balances.push((ALICE, USERS_INITIAL_BALANCE));
+		balances.push((BOB, USERS_INITIAL_BALANCE));
+		balances.push((TREASURY_ACC, TREASURY_INITIAL_BALANCE));
+		pallet_balances::GenesisConfig::<Test> { balances }
+			.assimilate_storage(&mut storage)
+			.unwrap();
+
+		parachain_staking::GenesisConfig::<Test> {
+			stakers: collators.iter().map(|(acc, stake)| (*acc, None, *stake)).collect(),
+			inflation_config: InflationInfo::new(
+				<Test as parachain_staking::Config>::BLOCKS_PER_YEAR,
+				Perquintill::from_percent(10),
+				Perquintill::from_percent(15),
+				Perquintill::from_percent(40),
+				Perquintill::from_percent(10),
+			),
+			max_candidate_stake: 1_000_000 * UNIT,
+			max_selected_candidates: 2,
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+		let session_keys: Vec<_> = collators
+			.iter()
+			.map(|(k, _)| (*k, *k, MockSessionKeys { aura: UintAuthorityId(*k).to_public_key() }))
+			.collect();
+		pallet_session::GenesisConfig::<Test> { keys: session_keys }
+			.assimilate_storage(&mut storage)
+			.unwrap();
+
+		sp_io::TestExternalities::from(storage)
+	}
+}
+
+pub fn run_test<T>(test: T)
+where
+	T: FnOnce(),
+{
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		test();
+	});
+}
+
+/// Traverses from the current block to `n`, noting `author` as the author of
+/// each block.
+pub fn roll_to(n: BlockNumber, author: Option<AccountId>) {
+	while System::block_number() < n {
+		if let Some(author) = author {
+			StakePallet::note_author(author);
+		}
+		<AllPalletsWithSystem as OnFinalize<u64>>::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		<AllPalletsWithSystem as OnInitialize<u64>>::on_initialize(System::block_number());
+	}
+}
+
+pub fn last_event() -> RuntimeEvent {
+	System::events().pop().expect("Event expected").event
+}
+
+pub fn pool() -> AccountId {
+	LiquidStaking::account_id()
+}
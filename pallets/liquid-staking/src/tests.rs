@@ -0,0 +1,282 @@
+#![cfg(test)]
+use crate::{
+	mock::*, CurrentBatch, Error, Event, PendingRedemption, RedemptionRequest, Redemptions,
+	TotalRedeeming, UnstakedBatch, UnstakedBatches,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, ExistenceRequirement},
+};
+use orml_traits::MultiCurrency;
+
+fn derivative_balance(account: &AccountId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(DerivativeCurrencyId::get(), account)
+}
+
+fn pool_delegations() -> Vec<(AccountId, Balance)> {
+	let mut delegations: Vec<_> = StakePallet::get_delegations(&pool())
+		.into_iter()
+		.map(|delegation| (delegation.collator, delegation.amount))
+		.collect();
+	delegations.sort();
+	delegations
+}
+
+#[test]
+fn deposit_mints_derivative_and_delegates() {
+	run_test(|| {
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+
+		assert_eq!(derivative_balance(&ALICE), 20 * UNIT);
+		assert_eq!(Balances::free_balance(ALICE), USERS_INITIAL_BALANCE - 20 * UNIT);
+		assert_eq!(LiquidStaking::total_pooled(), 20 * UNIT);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::LiquidStaking(Event::Deposited {
+				who: ALICE,
+				amount: 20 * UNIT,
+				minted: 20 * UNIT
+			})
+		);
+
+		// the pool delegates to one of the selected candidates and compounds its rewards
+		let delegations = pool_delegations();
+		assert_eq!(delegations.len(), 1);
+		assert!(StakePallet::selected_candidates().contains(&delegations[0].0));
+		assert_eq!(delegations[0].1, 20 * UNIT);
+		assert_eq!(StakePallet::auto_compound(pool()), sp_runtime::Perquintill::one());
+	});
+}
+
+#[test]
+fn deposit_spreads_over_selected_candidates() {
+	run_test(|| {
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), 10 * UNIT));
+		assert_eq!(derivative_balance(&BOB), 10 * UNIT);
+
+		let delegations = pool_delegations();
+		assert_eq!(
+			delegations.iter().map(|(collator, _)| *collator).collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+
+		// all selected candidates are backed, thus the smallest delegation is increased
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), 5 * UNIT));
+		let mut amounts: Vec<_> =
+			pool_delegations().into_iter().map(|(_, amount)| amount).collect();
+		amounts.sort();
+		assert_eq!(amounts, vec![15 * UNIT, 20 * UNIT]);
+		assert_eq!(LiquidStaking::total_pooled(), 35 * UNIT);
+	});
+}
+
+#[test]
+fn deposit_below_minimum_fails() {
+	run_test(|| {
+		assert_noop!(
+			LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), UNIT - 1),
+			Error::<Test>::LessThanMinDeposit
+		);
+	});
+}
+
+#[test]
+fn deposit_below_min_delegator_stake_stays_idle() {
+	run_test(|| {
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 2 * UNIT));
+		assert!(pool_delegations().is_empty());
+		assert_eq!(LiquidStaking::total_pooled(), 2 * UNIT);
+
+		// the idle funds are staked together with the next deposit
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), 3 * UNIT));
+		assert_eq!(pool_delegations().len(), 1);
+		assert_eq!(pool_delegations()[0].1, 5 * UNIT);
+	});
+}
+
+#[test]
+fn compound_increases_exchange_rate() {
+	run_test(|| {
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+		let collator = pool_delegations()[0].0;
+
+		roll_to(20, Some(collator));
+		assert_ok!(LiquidStaking::compound(RuntimeOrigin::signed(BOB)));
+
+		let pooled = LiquidStaking::total_pooled();
+		assert!(pooled > 20 * UNIT);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::LiquidStaking(Event::Compounded {
+				rewards: pooled - 20 * UNIT,
+				staked: 0
+			})
+		);
+		// the rewards have been restaked
+		assert_eq!(pool_delegations()[0].1, pooled);
+
+		// the derivative is worth more than before, new depositors receive less of it
+		assert_eq!(LiquidStaking::derivative_to_native(20 * UNIT), pooled);
+		assert!(LiquidStaking::native_to_derivative(20 * UNIT) < 20 * UNIT);
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), 20 * UNIT));
+		assert!(derivative_balance(&BOB) < 20 * UNIT);
+	});
+}
+
+#[test]
+fn donations_do_not_change_exchange_rate() {
+	run_test(|| {
+		// the first depositor redeems down to a tiny supply and donates to the pool
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), 20 * UNIT - 1));
+		assert_eq!(LiquidStaking::total_pooled(), 1);
+		assert_ok!(<Balances as Currency<AccountId>>::transfer(
+			&ALICE,
+			&pool(),
+			100 * UNIT,
+			ExistenceRequirement::AllowDeath
+		));
+		assert_eq!(LiquidStaking::total_pooled(), 1);
+		assert_eq!(LiquidStaking::derivative_to_native(1), 1);
+
+		// later depositors receive the derivative at the unchanged rate
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), 10 * UNIT));
+		assert_eq!(derivative_balance(&BOB), 10 * UNIT);
+		assert_eq!(LiquidStaking::derivative_to_native(10 * UNIT), 10 * UNIT);
+
+		// the donation is not staked
+		assert_ok!(LiquidStaking::compound(RuntimeOrigin::signed(BOB)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::LiquidStaking(Event::Compounded { rewards: 0, staked: 0 })
+		);
+		let staked: Balance = pool_delegations().into_iter().map(|(_, amount)| amount).sum();
+		assert_eq!(staked, 30 * UNIT);
+	});
+}
+
+#[test]
+fn redeem_and_withdraw() {
+	run_test(|| {
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), 5 * UNIT));
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), 3 * UNIT));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::LiquidStaking(Event::Redeemed {
+				who: ALICE,
+				burned: 3 * UNIT,
+				amount: 3 * UNIT,
+				batch: 0
+			})
+		);
+		assert_eq!(derivative_balance(&ALICE), 12 * UNIT);
+		assert_eq!(
+			Redemptions::<Test>::get(ALICE).to_vec(),
+			vec![RedemptionRequest { batch: 0, amount: 8 * UNIT }]
+		);
+		assert_eq!(PendingRedemption::<Test>::get(), 8 * UNIT);
+		assert_eq!(TotalRedeeming::<Test>::get(), 8 * UNIT);
+		assert_eq!(LiquidStaking::total_pooled(), 12 * UNIT);
+
+		// the batch is still staked
+		assert_eq!(pool_delegations()[0].1, 20 * UNIT);
+		assert_noop!(
+			LiquidStaking::withdraw_unstaked(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NothingToWithdraw
+		);
+
+		// the batch is unstaked at the end of the redemption period
+		roll_to(RedemptionPeriod::get(), None);
+		let unlock_block = RedemptionPeriod::get() + StakeDuration::get();
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::LiquidStaking(Event::BatchUnstaked {
+				batch: 0,
+				amount: 8 * UNIT,
+				unlock_block
+			})
+		);
+		assert_eq!(pool_delegations()[0].1, 12 * UNIT);
+		assert_eq!(PendingRedemption::<Test>::get(), 0);
+		assert_eq!(CurrentBatch::<Test>::get(), 1);
+		assert_eq!(
+			UnstakedBatches::<Test>::get(0),
+			Some(UnstakedBatch { unlock_block, remaining: 8 * UNIT })
+		);
+		assert_noop!(
+			LiquidStaking::withdraw_unstaked(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NothingToWithdraw
+		);
+
+		roll_to(unlock_block, None);
+		assert_ok!(LiquidStaking::withdraw_unstaked(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::LiquidStaking(Event::Withdrawn { who: ALICE, amount: 8 * UNIT })
+		);
+		assert_eq!(Balances::free_balance(ALICE), USERS_INITIAL_BALANCE - 12 * UNIT);
+		assert!(Redemptions::<Test>::get(ALICE).is_empty());
+		assert_eq!(UnstakedBatches::<Test>::get(0), None);
+		assert_eq!(TotalRedeeming::<Test>::get(), 0);
+		assert_eq!(LiquidStaking::total_pooled(), 12 * UNIT);
+	});
+}
+
+#[test]
+fn unstaking_keeps_min_delegator_stake() {
+	run_test(|| {
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), 10 * UNIT));
+
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), 18 * UNIT));
+		roll_to(RedemptionPeriod::get(), None);
+
+		// the largest delegation is reduced to the minimum before the next one is touched
+		let mut amounts: Vec<_> =
+			pool_delegations().into_iter().map(|(_, amount)| amount).collect();
+		amounts.sort();
+		assert_eq!(amounts, vec![MinDelegatorStake::get(), 7 * UNIT]);
+		assert_eq!(LiquidStaking::total_pooled(), 12 * UNIT);
+	});
+}
+
+#[test]
+fn redeem_fails_without_derivative() {
+	run_test(|| {
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), UNIT),
+			Error::<Test>::AmountTooLow
+		);
+
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(BOB), UNIT),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn redemption_requests_are_limited() {
+	run_test(|| {
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 20 * UNIT));
+
+		for batch in 0..MaxRedemptionRequests::get() {
+			assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), UNIT));
+			roll_to(RedemptionPeriod::get() * (batch as u64 + 1), None);
+			assert_eq!(CurrentBatch::<Test>::get(), batch + 1);
+		}
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), UNIT),
+			Error::<Test>::TooManyRedemptionRequests
+		);
+
+		// withdrawing frees up the requests
+		assert_ok!(LiquidStaking::withdraw_unstaked(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Redemptions::<Test>::get(ALICE).len(), 1);
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), UNIT));
+	});
+}
@@ -0,0 +1,40 @@
+use crate::Config;
+use codec::{Decode, Encode, MaxEncodedLen};
+use orml_traits::MultiCurrency;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[allow(type_alias_bounds)]
+pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+#[allow(type_alias_bounds)]
+pub(crate) type BalanceOf<T: Config> = <T as parachain_staking::Config>::CurrencyBalance;
+
+#[allow(type_alias_bounds)]
+pub(crate) type CurrencyIdOf<T: Config> =
+	<<T as Config>::Tokens as MultiCurrency<AccountIdOf<T>>>::CurrencyId;
+
+/// Index of a redemption batch.
+pub type BatchIndex = u32;
+
+/// A redemption which is paid out once its batch has been unstaked.
+#[derive(
+	Copy, Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct RedemptionRequest<Balance> {
+	/// The batch the redemption was requested in.
+	pub batch: BatchIndex,
+	/// The amount of native tokens owed to the redeemer.
+	pub amount: Balance,
+}
+
+/// A batch of redemptions which has been unstaked.
+#[derive(
+	Copy, Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct UnstakedBatch<BlockNumber, Balance> {
+	/// The block from which on the batch can be withdrawn.
+	pub unlock_block: BlockNumber,
+	/// The amount of native tokens which has not been withdrawn yet.
+	pub remaining: Balance,
+}
@@ -5,7 +5,8 @@ use module_oracle_rpc_runtime_api::BalanceWrapper;
 use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_arithmetic::per_things::Perquintill;
-use sp_std::fmt::Debug;
+use sp_runtime::traits::NumberFor;
+use sp_std::{fmt::Debug, vec::Vec};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,175 @@ pub struct StakingRates {
 	pub delegator_reward_rate: Perquintill,
 }
 
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollatorCommission {
+	/// The commission which is currently charged on the delegators' rewards.
+	pub commission: Perquintill,
+	/// The requested commission and the round from which on it can be applied.
+	pub pending: Option<(Perquintill, u32)>,
+}
+
+/// The staking information of a selected collator in a past round.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "AccountId: Serialize, Balance: std::fmt::Display",
+		deserialize = "AccountId: Deserialize<'de>, Balance: std::str::FromStr"
+	))
+)]
+pub struct CollatorSnapshot<AccountId, Balance> {
+	/// The account of the collator.
+	pub collator: AccountId,
+	/// The own stake of the collator plus the stake of their delegators when
+	/// they were selected.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub total: Balance,
+	/// The number of blocks authored by the collator.
+	pub blocks_authored: u32,
+	/// The rewards credited to the collator during the round including the
+	/// commission. Rewards are credited lazily, e.g. on stake changes or
+	/// claims, so they can stem from blocks authored in earlier rounds.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub collator_rewards: Balance,
+	/// The rewards credited to all delegators of the collator during the
+	/// round after deducting the commission.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub delegator_rewards: Balance,
+}
+
+/// The status of a collator candidate.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CandidateState {
+	/// The candidate can be selected to author blocks.
+	Active,
+	/// The candidate is not selected until going online again.
+	Idle,
+	/// The candidate is leaving and stays staked until the inner round.
+	Leaving(u32),
+}
+
+/// The staking information of a collator candidate.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "AccountId: Serialize, Balance: std::fmt::Display",
+		deserialize = "AccountId: Deserialize<'de>, Balance: std::str::FromStr"
+	))
+)]
+pub struct CandidateInfo<AccountId, Balance> {
+	/// The account of the candidate.
+	pub id: AccountId,
+	/// The status of the candidate.
+	pub status: CandidateState,
+	/// The own stake of the candidate.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub stake: Balance,
+	/// The own stake of the candidate plus the stake of their delegators.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub total: Balance,
+	/// The number of delegators of the candidate.
+	pub delegators: u32,
+	/// The commission which is charged on the delegators' rewards.
+	pub commission: Perquintill,
+	/// Whether the candidate is selected to author blocks in the next round.
+	pub is_selected: bool,
+}
+
+/// A delegation of an account to a collator candidate.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "AccountId: Serialize, Balance: std::fmt::Display",
+		deserialize = "AccountId: Deserialize<'de>, Balance: std::str::FromStr"
+	))
+)]
+pub struct DelegationInfo<AccountId, Balance> {
+	/// The account of the collator candidate.
+	pub collator: AccountId,
+	/// The delegated stake.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub amount: Balance,
+}
+
+/// Unstaked funds which can be unlocked from the given block on.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "BlockNumber: Serialize, Balance: std::fmt::Display",
+		deserialize = "BlockNumber: Deserialize<'de>, Balance: std::str::FromStr"
+	))
+)]
+pub struct UnstakingInfo<BlockNumber, Balance> {
+	/// The block from which on the funds can be unlocked.
+	pub unlock_block: BlockNumber,
+	/// The unstaked amount.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub amount: Balance,
+}
+
+/// A delegation which has been replaced by a higher delegation to the same
+/// collator candidate.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "AccountId: Serialize, Balance: std::fmt::Display",
+		deserialize = "AccountId: Deserialize<'de>, Balance: std::str::FromStr"
+	))
+)]
+pub struct DisplacedDelegation<AccountId, Balance> {
+	/// The account of the collator candidate.
+	pub collator: AccountId,
+	/// The round in which the delegation was replaced.
+	pub round: u32,
+	/// The delegated stake which has been moved to unstaking.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub amount: Balance,
+}
+
+/// The estimated annual rewards of a collator candidate and their delegators
+/// relative to their stake.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollatorApy {
+	/// The rate of the collator including the commission. Saturates at 100%.
+	pub collator: Perquintill,
+	/// The rate of the delegators after deducting the commission.
+	pub delegators: Perquintill,
+}
+
+/// Balances are serialized as strings because they might exceed the range of
+/// JSON numbers.
+#[cfg(feature = "std")]
+mod balance_as_string {
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer, T: std::fmt::Display>(
+		value: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&value.to_string())
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		s.parse::<T>().map_err(|_| D::Error::custom("Parse from string failed"))
+	}
+}
+
 sp_api::decl_runtime_apis! {
 	pub trait ParachainStakingApi<AccountId, Balance>
 	where
@@ -27,5 +197,15 @@ sp_api::decl_runtime_apis! {
 	{
 		fn get_unclaimed_staking_rewards(account: AccountId) -> BalanceWrapper<Balance>;
 		fn get_staking_rates() -> StakingRates;
+		fn get_collator_commission(account: AccountId) -> Option<CollatorCommission>;
+		fn get_auto_compound(account: AccountId) -> Perquintill;
+		fn get_round_snapshot(round: u32) -> Vec<CollatorSnapshot<AccountId, Balance>>;
+		fn get_candidates() -> Vec<CandidateInfo<AccountId, Balance>>;
+		fn get_delegations(account: AccountId) -> Vec<DelegationInfo<AccountId, Balance>>;
+		fn get_unstaking(account: AccountId) -> Vec<UnstakingInfo<NumberFor<Block>, Balance>>;
+		fn get_collator_apy(account: AccountId) -> Option<CollatorApy>;
+		fn get_next_round_start() -> NumberFor<Block>;
+		fn would_enter_top_delegators(delegator: AccountId, collator: AccountId, amount: Balance) -> bool;
+		fn get_displaced_delegations(account: AccountId) -> Vec<DisplacedDelegation<AccountId, Balance>>;
 	}
 }
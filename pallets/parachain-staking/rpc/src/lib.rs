@@ -8,18 +8,24 @@ use jsonrpsee::{
 };
 use module_oracle_rpc_runtime_api::BalanceWrapper;
 use module_pallet_staking_rpc_runtime_api::{
-	ParachainStakingApi as ParachainStakingRuntimeApi, StakingRates,
+	CandidateInfo, CollatorApy, CollatorCommission, CollatorSnapshot, DelegationInfo,
+	DisplacedDelegation, ParachainStakingApi as ParachainStakingRuntimeApi, StakingRates,
+	UnstakingInfo,
 };
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+use sp_runtime::{
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr, MaybeSerializeDeserialize, NumberFor},
+	Perquintill,
+};
 use std::sync::Arc;
 
 #[rpc(client, server)]
-pub trait ParachainStakingApi<BlockHash, AccountId, Balance>
+pub trait ParachainStakingApi<BlockHash, AccountId, Balance, BlockNumber>
 where
 	Balance: Codec + MaybeDisplay + MaybeFromStr,
-	AccountId: Codec,
+	AccountId: Codec + MaybeSerializeDeserialize,
+	BlockNumber: Codec + MaybeSerializeDeserialize,
 {
 	#[method(name = "staking_getUnclaimedStakingRewards")]
 	fn get_unclaimed_staking_rewards(
@@ -30,6 +36,73 @@ where
 
 	#[method(name = "staking_getStakingRates")]
 	fn get_staking_rates(&self, at: Option<BlockHash>) -> RpcResult<StakingRates>;
+
+	#[method(name = "staking_getCollatorCommission")]
+	fn get_collator_commission(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CollatorCommission>>;
+
+	#[method(name = "staking_getAutoCompound")]
+	fn get_auto_compound(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Perquintill>;
+
+	#[method(name = "staking_getRoundSnapshot")]
+	fn get_round_snapshot(
+		&self,
+		round: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CollatorSnapshot<AccountId, Balance>>>;
+
+	#[method(name = "staking_getCandidates")]
+	fn get_candidates(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CandidateInfo<AccountId, Balance>>>;
+
+	#[method(name = "staking_getDelegations")]
+	fn get_delegations(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DelegationInfo<AccountId, Balance>>>;
+
+	#[method(name = "staking_getUnstaking")]
+	fn get_unstaking(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<UnstakingInfo<BlockNumber, Balance>>>;
+
+	#[method(name = "staking_getCollatorApy")]
+	fn get_collator_apy(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CollatorApy>>;
+
+	#[method(name = "staking_getNextRoundStart")]
+	fn get_next_round_start(&self, at: Option<BlockHash>) -> RpcResult<BlockNumber>;
+
+	#[method(name = "staking_wouldEnterTopDelegators")]
+	fn would_enter_top_delegators(
+		&self,
+		delegator: AccountId,
+		collator: AccountId,
+		amount: BalanceWrapper<Balance>,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	#[method(name = "staking_getDisplacedDelegations")]
+	fn get_displaced_delegations(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DisplacedDelegation<AccountId, Balance>>>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -55,13 +128,14 @@ impl<C, B> Staking<C, B> {
 
 #[async_trait]
 impl<C, Block, AccountId, Balance>
-	ParachainStakingApiServer<<Block as BlockT>::Hash, AccountId, Balance> for Staking<C, Block>
+	ParachainStakingApiServer<<Block as BlockT>::Hash, AccountId, Balance, NumberFor<Block>>
+	for Staking<C, Block>
 where
 	Block: BlockT,
 	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
 	C::Api: ParachainStakingRuntimeApi<Block, AccountId, Balance>,
 	Balance: Codec + MaybeDisplay + MaybeFromStr,
-	AccountId: Codec,
+	AccountId: Codec + MaybeSerializeDeserialize,
 {
 	fn get_unclaimed_staking_rewards(
 		&self,
@@ -82,4 +156,123 @@ where
 		api.get_staking_rates(at)
 			.map_err(|_e| internal_err("Unable to get staking rates"))
 	}
+
+	fn get_collator_commission(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CollatorCommission>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_collator_commission(at, account)
+			.map_err(|_e| internal_err("Unable to get collator commission"))
+	}
+
+	fn get_auto_compound(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Perquintill> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_auto_compound(at, account)
+			.map_err(|_e| internal_err("Unable to get auto-compounding share"))
+	}
+
+	fn get_round_snapshot(
+		&self,
+		round: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CollatorSnapshot<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_round_snapshot(at, round)
+			.map_err(|_e| internal_err("Unable to get round snapshot"))
+	}
+
+	fn get_candidates(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CandidateInfo<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_candidates(at).map_err(|_e| internal_err("Unable to get candidates"))
+	}
+
+	fn get_delegations(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DelegationInfo<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_delegations(at, account)
+			.map_err(|_e| internal_err("Unable to get delegations"))
+	}
+
+	fn get_unstaking(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<UnstakingInfo<NumberFor<Block>, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_unstaking(at, account)
+			.map_err(|_e| internal_err("Unable to get unstaking schedule"))
+	}
+
+	fn get_collator_apy(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CollatorApy>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_collator_apy(at, account)
+			.map_err(|_e| internal_err("Unable to get collator APY"))
+	}
+
+	fn get_next_round_start(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberFor<Block>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_next_round_start(at)
+			.map_err(|_e| internal_err("Unable to get next round start"))
+	}
+
+	fn would_enter_top_delegators(
+		&self,
+		delegator: AccountId,
+		collator: AccountId,
+		amount: BalanceWrapper<Balance>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.would_enter_top_delegators(at, delegator, collator, amount.amount)
+			.map_err(|_e| internal_err("Unable to check top delegators"))
+	}
+
+	fn get_displaced_delegations(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DisplacedDelegation<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_displaced_delegations(at, account)
+			.map_err(|_e| internal_err("Unable to get displaced delegations"))
+	}
 }
@@ -17,20 +17,25 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 //! Benchmarking
-use crate::{types::RoundInfo, *};
+use crate::{
+	types::{LivenessConfig, LivenessPenalty, RewardDestination, RoundInfo, Stake, StakingConfig},
+	*,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::{
 	assert_ok,
 	traits::{Currency, Get, OnInitialize},
+	BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
+use orml_traits::MultiCurrency;
 use pallet_session::Pallet as Session;
 use sp_arithmetic::traits::Zero;
 use sp_runtime::{
-	traits::{One, SaturatedConversion, StaticLookup},
-	Perquintill,
+	traits::{One, SaturatedConversion, Saturating, StaticLookup},
+	FixedU128, Perquintill,
 };
-use sp_std::{convert::TryInto, vec::Vec};
+use sp_std::{convert::TryInto, vec, vec::Vec};
 
 const COLLATOR_ACCOUNT_SEED: u32 = 0;
 const DELEGATOR_ACCOUNT_SEED: u32 = 1;
@@ -88,6 +93,7 @@ fn fill_delegators<T: Config>(
 			T::RuntimeOrigin::from(Some(acc.clone()).into()),
 			T::Lookup::unlookup(collator.clone()),
 			T::MinDelegatorStake::get(),
+			None,
 		));
 	}
 
@@ -412,7 +418,7 @@ benchmarks! {
 
 
 		let origin = RawOrigin::Signed(delegator.clone());
-	}: _(origin, unlookup_collator, amount)
+	}: _(origin, unlookup_collator, amount, None)
 	verify {
 		let state = <CandidatePool<T>>::get(&collator).unwrap();
 		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
@@ -435,24 +441,24 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = <CandidatePool<T>>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, amount);
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, amount);
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
-		assert_ok!(<Pallet<T>>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), T::CurrencyBalance::from(u as u64)));
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, amount + T::CurrencyBalance::from(u as u64));
+		assert_ok!(<Pallet<T>>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), T::CurrencyBalance::from(u as u64), None));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, amount + T::CurrencyBalance::from(u as u64));
 
 		// fill unstake BTreeMap by unstaked many entries of 1
 		fill_unstaking::<T>(&collator, Some(&delegator), u as u64);
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, amount);
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, amount);
 		let unlookup_collator = T::Lookup::unlookup(collator.clone());
 
 		let origin = RawOrigin::Signed(delegator.clone());
-	}: _(origin, unlookup_collator, amount)
+	}: _(origin, unlookup_collator, amount, None)
 	verify {
 		let state = <CandidatePool<T>>::get(&collator).unwrap();
 		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, amount + amount);
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, amount + amount);
 		assert!(<Unstaking<T>>::get(&delegator).is_empty());
 	}
 
@@ -472,16 +478,16 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = <CandidatePool<T>>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
-		assert_ok!(<Pallet<T>>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount));
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount + amount);
+		assert_ok!(<Pallet<T>>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount, None));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount + amount);
 
 		// decrease stake once so we have an unstaking entry for this block
 		assert_ok!(<Pallet<T>>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount));
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount);
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount);
 		assert_eq!(<Unstaking<T>>::get(&delegator).len(), 1);
 		let unlookup_collator = T::Lookup::unlookup(collator.clone());
 
@@ -490,7 +496,7 @@ benchmarks! {
 	verify {
 		let state = <CandidatePool<T>>::get(&collator).unwrap();
 		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 		assert_eq!(<Unstaking<T>>::get(&delegator).len(), 2);
 	}
 
@@ -510,16 +516,16 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = <CandidatePool<T>>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
-		assert_ok!(<Pallet<T>>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount));
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount + amount);
+		assert_ok!(<Pallet<T>>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount, None));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount + amount);
 
 		// decrease stake once so we have an unstaking entry for this block
 		assert_ok!(<Pallet<T>>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount));
-		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount);
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount);
 		assert_eq!(<Unstaking<T>>::get(&delegator).len(), 1);
 
 		let origin = RawOrigin::Signed(delegator.clone());
@@ -577,14 +583,21 @@ benchmarks! {
 
 		// mock high values to compensate for tiny values in unit test env
 		let stake = T::CurrencyBalance::from(1_000_000_000_000_000_000u128);
-		DelegatorState::<T>::insert(&delegator, crate::types::Delegator { owner: Some(collator.clone()), amount: stake});
+		let mut state = crate::types::Delegator::default();
+		assert_ok!(state.add_delegation(Stake { owner: collator.clone(), amount: stake }));
+		DelegatorState::<T>::insert(&delegator, state);
 		RewardCount::<T>::insert(&collator, u32::MAX);
 
+		// worst case: the rewards are restaked right away
+		T::Currency::make_free_balance_be(&delegator, stake + stake);
+		T::Currency::make_free_balance_be(&T::TreasuryAccount::get(), T::CurrencyBalance::from(u128::MAX));
+		AutoCompound::<T>::insert(&delegator, Perquintill::one());
+
 		assert!(Rewards::<T>::get(&delegator).is_zero());
 		let origin = RawOrigin::Signed(delegator.clone());
 	}: _(origin)
 	verify {
-		assert!(!Rewards::<T>::get(&delegator).is_zero());
+		assert!(<DelegatorState<T>>::get(&delegator).unwrap().total > stake);
 	}
 
 	increment_collator_rewards {
@@ -596,10 +609,18 @@ benchmarks! {
 		// mock high counter to compensate for tiny amounts in unit test env
 		RewardCount::<T>::insert(&collator, u32::MAX);
 		assert!(Rewards::<T>::get(&collator).is_zero(), "reward {:?}", Rewards::<T>::get(&collator));
+
+		// worst case: the collator and all delegators restake their rewards right away
+		T::Currency::make_free_balance_be(&T::TreasuryAccount::get(), T::CurrencyBalance::from(u128::MAX));
+		for acc in delegators.iter().chain(sp_std::iter::once(&collator)) {
+			AutoCompound::<T>::insert(acc, Perquintill::one());
+		}
+		let stake = <CandidatePool<T>>::get(&collator).unwrap().stake;
+
 		let origin = RawOrigin::Signed(collator.clone());
 	}: _(origin)
 	verify {
-		assert!(!Rewards::<T>::get(&collator).is_zero());
+		assert!(<CandidatePool<T>>::get(&collator).unwrap().stake > stake);
 	}
 
 	claim_rewards {
@@ -655,6 +676,255 @@ benchmarks! {
 		assert!(new.delegator.reward_rate.annual < old.delegator.reward_rate.annual);
 	}
 
+	request_commission_change {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let commission = T::MaxCollatorCommission::get();
+		let origin = RawOrigin::Signed(collator.clone());
+	}: _(origin, commission)
+	verify {
+		assert_eq!(CommissionChangeRequests::<T>::get(&collator).map(|c| c.commission), Some(commission));
+	}
+
+	execute_commission_change {
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		fill_delegators::<T>(m, collator.clone(), COLLATOR_ACCOUNT_SEED);
+
+		// mock high counter to compensate for tiny amounts in unit test env
+		RewardCount::<T>::insert(&collator, u32::MAX);
+
+		let commission = T::MaxCollatorCommission::get();
+		assert_ok!(<Pallet<T>>::request_commission_change(
+			RawOrigin::Signed(collator.clone()).into(),
+			commission,
+		));
+		let round = <Round<T>>::get();
+		<Round<T>>::put(RoundInfo {
+			current: round.current + T::CommissionChangeDelay::get(),
+			..round
+		});
+
+		let origin = RawOrigin::Signed(collator.clone());
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+	}: _(origin, unlookup_collator)
+	verify {
+		assert_eq!(<CandidatePool<T>>::get(&collator).unwrap().commission, commission);
+		assert!(CommissionChangeRequests::<T>::get(&collator).is_none());
+	}
+
+	delegate_another_candidate {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+		let delegator = <CandidatePool<T>>::get(&candidates[1]).unwrap().delegators.into_bounded_vec()[0].owner.clone();
+		let amount = T::MinDelegatorStake::get();
+		T::Currency::make_free_balance_be(&delegator, amount + amount + amount + amount);
+		// reset the delegation counter of the current round
+		<LastDelegation<T>>::remove(&delegator);
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_collator, amount)
+	verify {
+		let state = <CandidatePool<T>>::get(&collator).unwrap();
+		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().delegations.len(), 2);
+	}
+
+	revoke_delegation {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+		let delegator = <CandidatePool<T>>::get(&candidates[1]).unwrap().delegators.into_bounded_vec()[0].owner.clone();
+		let amount = T::MinDelegatorStake::get();
+		T::Currency::make_free_balance_be(&delegator, amount + amount + amount + amount);
+		<LastDelegation<T>>::remove(&delegator);
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+		assert_ok!(<Pallet<T>>::delegate_another_candidate(RawOrigin::Signed(delegator.clone()).into(), unlookup_collator.clone(), amount));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().delegations.len(), 2);
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_collator)
+	verify {
+		let state = <CandidatePool<T>>::get(&collator).unwrap();
+		assert!(!state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().delegations.len(), 1);
+		assert_eq!(<Unstaking<T>>::get(&delegator).len(), 1);
+	}
+
+	redelegate {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let from = candidates[1].clone();
+		let to = candidates[0].clone();
+		let delegator = <CandidatePool<T>>::get(&from).unwrap().delegators.into_bounded_vec()[0].owner.clone();
+		let amount = T::MinDelegatorStake::get();
+		// reset the delegation counter of the current round
+		<LastDelegation<T>>::remove(&delegator);
+		let unlookup_from = T::Lookup::unlookup(from.clone());
+		let unlookup_to = T::Lookup::unlookup(to.clone());
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_from, unlookup_to, amount)
+	verify {
+		assert!(!<CandidatePool<T>>::get(&from).unwrap().delegators.into_iter().any(|x| x.owner == delegator));
+		assert!(<CandidatePool<T>>::get(&to).unwrap().delegators.into_iter().any(|x| x.owner == delegator));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().delegation(to), Ok(amount));
+		assert!(<Unstaking<T>>::get(&delegator).is_empty());
+	}
+
+	set_auto_compound {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let delegator = fill_delegators::<T>(1, collator, COLLATOR_ACCOUNT_SEED)[0].clone();
+		let percentage = Perquintill::from_percent(50);
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, percentage)
+	verify {
+		assert_eq!(AutoCompound::<T>::get(&delegator), percentage);
+	}
+
+	set_liveness_config {
+		let n in 0 .. T::MaxTopCandidates::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for c in candidates.iter() {
+			MissedRounds::<T>::insert(c, 1);
+		}
+		let config = LivenessConfig {
+			min_authored: Perquintill::from_percent(50),
+			max_missed_rounds: 2,
+			penalty: LivenessPenalty::Idle,
+		};
+	}: _(RawOrigin::Root, Some(config))
+	verify {
+		assert_eq!(LivenessConfiguration::<T>::get(), Some(config));
+		assert!(MissedRounds::<T>::iter().next().is_none());
+	}
+
+	go_online {
+		let n in (T::MinCollators::get() + 1) .. T::MaxTopCandidates::get() - 1;
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+
+		// leaving removes the candidate from the top candidates
+		let candidate = candidates[0].clone();
+		assert_ok!(<Pallet<T>>::init_leave_candidates(RawOrigin::Signed(candidate.clone()).into()));
+		<CandidatePool<T>>::mutate(&candidate, |state| {
+			if let Some(state) = state {
+				state.go_idle();
+			}
+		});
+
+		let origin = RawOrigin::Signed(candidate.clone());
+	}: _(origin)
+	verify {
+		let candidates = TopCandidates::<T>::get();
+		assert!(candidates.into_iter().any(|other| other.owner == candidate));
+		assert!(<Pallet<T>>::is_active_candidate(&candidate).unwrap());
+	}
+
+	set_staking_config {
+		let config = StakingConfig {
+			min_collator_stake: T::MinCollatorCandidateStake::get(),
+			min_delegator_stake: T::MinDelegatorStake::get(),
+			max_delegators_per_collator: T::MaxDelegatorsPerCollator::get(),
+			stake_duration: T::StakeDuration::get(),
+			exit_queue_delay: T::ExitQueueDelay::get().max(1),
+			network_reward_rate: T::MaxNetworkRewardRate::get(),
+		};
+	}: _(RawOrigin::Root, config)
+	verify {
+		assert_eq!(StakingConfiguration::<T>::get(), config);
+	}
+
+	set_reward_destination {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let delegator = fill_delegators::<T>(1, collator.clone(), COLLATOR_ACCOUNT_SEED)[0].clone();
+		let destination = RewardDestination::Account(collator);
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, destination.clone())
+	verify {
+		assert_eq!(RewardDestinations::<T>::get(&delegator), destination);
+	}
+
+	set_invulnerables {
+		let n in 0 .. T::MinRequiredCollators::get();
+		let invulnerables: BoundedVec<T::AccountId, T::MinRequiredCollators> = (0..n)
+			.map(|i| account("invulnerable", i, COLLATOR_ACCOUNT_SEED))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Root, invulnerables.clone())
+	verify {
+		assert_eq!(Invulnerables::<T>::get(), invulnerables);
+	}
+
+	set_extra_reward_rate {
+		let currency_id = T::ExtraRewardBenchmarkCurrencyId::get();
+		let amount = T::MinCollatorCandidateStake::get();
+	}: _(RawOrigin::Root, currency_id, amount)
+	verify {
+		assert_eq!(ExtraRewardRates::<T>::get().into_inner(), vec![(currency_id, amount)]);
+	}
+
+	claim_extra_rewards {
+		let n in 1 .. T::MaxCollatorsPerDelegator::get();
+
+		let currency_id = T::ExtraRewardBenchmarkCurrencyId::get();
+		let collators = setup_collator_candidates::<T>(n, None);
+		let delegator = fill_delegators::<T>(1, collators[0].clone(), COLLATOR_ACCOUNT_SEED)[0].clone();
+
+		// mock a delegation to each collator which has accumulated extra rewards
+		let stake = T::MinDelegatorStake::get();
+		let mut state = crate::types::Delegator::default();
+		for collator in collators.iter().take(n as usize) {
+			assert_ok!(state.add_delegation(Stake { owner: collator.clone(), amount: stake }));
+			let rewards_per_stake: BoundedVec<_, T::MaxExtraRewardCurrencies> =
+				vec![(currency_id, FixedU128::one())].try_into().unwrap();
+			ExtraRewardsPerStake::<T>::insert(collator, rewards_per_stake);
+		}
+		DelegatorState::<T>::insert(&delegator, state);
+
+		let rewards = stake.saturating_mul(n.into());
+		assert_ok!(T::ExtraRewardCurrency::deposit(
+			currency_id,
+			&Pallet::<T>::extra_rewards_pot(),
+			rewards.saturating_mul(2u32.into())
+		));
+		ExtraRewardsAllocated::<T>::insert(currency_id, rewards);
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, currency_id)
+	verify {
+		assert!(ExtraRewards::<T>::get(&delegator, currency_id).is_zero());
+		assert_eq!(T::ExtraRewardCurrency::free_balance(currency_id, &delegator), rewards);
+	}
+
 }
 
 impl_benchmark_test_suite!(
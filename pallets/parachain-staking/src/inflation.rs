@@ -20,7 +20,7 @@
 use crate::{pallet::Config, types::BalanceOf};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Saturating, Perquintill, RuntimeDebug};
+use sp_runtime::{traits::Saturating, PerThing, Perquintill, RuntimeDebug};
 
 use serde_derive::{Deserialize, Serialize};
 
@@ -82,21 +82,35 @@ impl StakingInfo {
 	///
 	/// NOTE: If we exceed the max staking rate, the reward will be reduced by
 	/// max_rate / current_rate.
+	///
+	/// The reward is increased by the given `bonus`, e.g., for delegators
+	/// which committed to lock their stake for a longer period. The bonus is
+	/// only paid out as long as the reward does not exceed the one at the max
+	/// staking rate, i.e., reward * max_rate / current_rate. Hence, the bonus
+	/// cannot push the issuance above the configured inflation limit.
 	pub fn compute_reward<T: Config>(
 		&self,
 		stake: BalanceOf<T>,
 		current_staking_rate: Perquintill,
 		authors_per_round: BalanceOf<T>,
+		bonus: Perquintill,
 	) -> BalanceOf<T> {
-		// Perquintill automatically bounds to [0, 100]% in case staking_rate is greater
-		// than self.max_rate
-		let reduction = Perquintill::from_rational(
-			self.max_rate.deconstruct(),
-			current_staking_rate.deconstruct(),
-		);
 		// multiplication with perbill cannot overflow
 		let reward = (self.reward_rate.per_block * stake).saturating_mul(authors_per_round);
-		reduction * reward
+		let cap = if current_staking_rate > self.max_rate {
+			Perquintill::from_rational(
+				self.max_rate.deconstruct(),
+				current_staking_rate.deconstruct(),
+			) * reward
+		} else {
+			// saturates if the current staking rate is zero
+			Perquintill::from_rational(
+				current_staking_rate.deconstruct(),
+				self.max_rate.deconstruct(),
+			)
+			.saturating_reciprocal_mul(reward)
+		};
+		reward.saturating_add(bonus * reward).min(cap)
 	}
 }
 
@@ -206,6 +220,7 @@ mod tests {
 			MAX_COLLATOR_STAKE,
 			Perquintill::from_percent(9),
 			2,
+			Perquintill::zero(),
 		);
 		let expected = <Test as Config>::CurrencyBalance::from(15210282150733u64);
 		assert!(
@@ -214,6 +229,36 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn bonus_does_not_exceed_max_rate() {
+		let inflation = InflationInfo::new(
+			<Test as Config>::BLOCKS_PER_YEAR,
+			Perquintill::from_percent(10),
+			Perquintill::from_percent(10),
+			Perquintill::from_percent(40),
+			Perquintill::from_percent(8),
+		);
+		let stake = 1_000 * DECIMALS;
+		let bonus = Perquintill::from_percent(50);
+		let reward = |rate, bonus| {
+			inflation.delegator.compute_reward::<Test>(
+				stake,
+				Perquintill::from_percent(rate),
+				1,
+				bonus,
+			)
+		};
+		let base = reward(20, Perquintill::zero());
+
+		// the full bonus is paid out as long as the max rate is not reached
+		assert_eq!(reward(20, bonus), base + bonus * base);
+		// the bonus is limited to the reward at the max rate
+		assert!(almost_equal(reward(30, bonus), base * 4 / 3, Perbill::from_perthousand(1)));
+		// above the max rate, the bonus does not increase the reduced reward
+		assert_eq!(reward(50, bonus), reward(50, Perquintill::zero()));
+		assert!(almost_equal(reward(50, bonus), base * 4 / 5, Perbill::from_perthousand(1)));
+	}
+
 	#[test]
 	fn simple_block_reward_check() {
 		let precision = Perbill::from_perthousand(1);
@@ -266,7 +311,8 @@ mod tests {
 					inflation.collator.compute_reward::<Test>(
 						0,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					),
 					0
 				);
@@ -276,7 +322,8 @@ mod tests {
 						inflation.collator.compute_reward::<Test>(
 							5000 * DECIMALS,
 							current_staking_rate,
-							authors_per_round
+							authors_per_round,
+							Perquintill::zero()
 						) * years_u128,
 						Perquintill::from_percent(15) * 5000 * DECIMALS,
 						Perbill::from_percent(1)
@@ -285,7 +332,8 @@ mod tests {
 					inflation.collator.compute_reward::<Test>(
 						5000 * DECIMALS,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					) * years_u128,
 					Perquintill::from_percent(15) * 5000 * DECIMALS,
 				);
@@ -296,7 +344,8 @@ mod tests {
 						inflation.collator.compute_reward::<Test>(
 							10_000 * DECIMALS,
 							current_staking_rate,
-							authors_per_round
+							authors_per_round,
+							Perquintill::zero()
 						) * years_u128,
 						Perquintill::from_percent(15) * 10_000 * DECIMALS,
 						Perbill::from_percent(1)
@@ -305,7 +354,8 @@ mod tests {
 					inflation.collator.compute_reward::<Test>(
 						10_000 * DECIMALS,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					) * years_u128,
 					Perquintill::from_percent(15) * 10_000 * DECIMALS,
 				);
@@ -317,7 +367,8 @@ mod tests {
 						inflation.collator.compute_reward::<Test>(
 							50_000 * DECIMALS,
 							current_staking_rate,
-							authors_per_round
+							authors_per_round,
+							Perquintill::zero()
 						) * years_u128,
 						Perquintill::from_percent(15) * 10_000 * DECIMALS,
 						Perbill::from_percent(1)
@@ -326,7 +377,8 @@ mod tests {
 					inflation.collator.compute_reward::<Test>(
 						50_000 * DECIMALS,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					) * years_u128,
 					Perquintill::from_percent(15) * 10_000 * DECIMALS,
 				);
@@ -337,7 +389,8 @@ mod tests {
 					inflation.delegator.compute_reward::<Test>(
 						0,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					),
 					0
 				);
@@ -347,7 +400,8 @@ mod tests {
 						inflation.delegator.compute_reward::<Test>(
 							5000 * DECIMALS,
 							current_staking_rate,
-							authors_per_round
+							authors_per_round,
+							Perquintill::zero()
 						) * years_u128,
 						Perquintill::from_percent(10) * 5000 * DECIMALS,
 						Perbill::from_percent(1)
@@ -356,7 +410,8 @@ mod tests {
 					inflation.delegator.compute_reward::<Test>(
 						5000 * DECIMALS,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					) * years_u128,
 					Perquintill::from_percent(10) * 5000 * DECIMALS,
 				);
@@ -367,7 +422,8 @@ mod tests {
 						inflation.delegator.compute_reward::<Test>(
 							40_000 * DECIMALS,
 							current_staking_rate,
-							authors_per_round
+							authors_per_round,
+							Perquintill::zero()
 						) * years_u128,
 						Perquintill::from_percent(10) * 40_000 * DECIMALS,
 						Perbill::from_percent(1)
@@ -376,7 +432,8 @@ mod tests {
 					inflation.delegator.compute_reward::<Test>(
 						40_000 * DECIMALS,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					) * years_u128,
 					Perquintill::from_percent(10) * 40_000 * DECIMALS,
 				);
@@ -388,7 +445,8 @@ mod tests {
 						inflation.delegator.compute_reward::<Test>(
 							50_000 * DECIMALS,
 							current_staking_rate,
-							authors_per_round
+							authors_per_round,
+							Perquintill::zero()
 						) * years_u128,
 						Perquintill::from_percent(8) * 50_000 * DECIMALS,
 						Perbill::from_percent(1)
@@ -397,7 +455,8 @@ mod tests {
 					inflation.delegator.compute_reward::<Test>(
 						50_000 * DECIMALS,
 						current_staking_rate,
-						authors_per_round
+						authors_per_round,
+						Perquintill::zero()
 					) * years_u128,
 					Perquintill::from_percent(8) * 50_000 * DECIMALS,
 				);
@@ -62,12 +62,31 @@
 //!   `MaxSelectedCandidates` are chosen to become active collators for the next
 //!   session. That makes the set of active collators the set of block authors
 //!   by handing it over to the session and the authority pallet.
-//! - Delegating to a collator candidate by staking for them.
+//! - Delegating to up to `MaxCollatorsPerDelegator` many collator candidates
+//!   by staking for them.
 //! - Increasing and reducing your stake as a collator or delegator.
-//! - Revoking your delegation entirely.
+//! - Revoking a single delegation or all of your delegations at once.
+//! - Moving delegated stake between collator candidates without unstaking.
 //! - Requesting to leave the set of collator candidates.
+//! - Charging a commission on the rewards of your delegators as a collator
+//!   candidate.
+//! - Choosing whether your rewards are paid into your own account, into
+//!   another account, restaked or donated to the treasury.
 //! - Withdrawing your unstaked balance after waiting for a certain number of
 //!   blocks.
+//! - Penalizing selected collators which author too few blocks in too many
+//!   rounds in a row by either marking them idle or slashing them.
+//! - Changing the minimum stakes, the maximum number of delegators per
+//!   collator, the unstaking and exit delays and the network reward rate by
+//!   governance.
+//! - Always selecting a governance-managed set of invulnerable collators
+//!   which do not receive any rewards.
+//! - Keeping a snapshot of the total backing, the authored blocks and the
+//!   rewards of each selected collator for the last `MaxRoundSnapshots`
+//!   rounds.
+//! - Distributing governance-funded rewards in other currencies on top of
+//!   the inflation rewards, pro rata to the stake of the collators which
+//!   authored blocks in a round.
 //!
 //! ### Terminology
 //!
@@ -83,6 +102,10 @@
 //! - **Total Stake:** A collator’s own stake + the sum of delegated stake to
 //!   this collator.
 //!
+//! - **Commission:** The share of the delegators' rewards which is paid to
+//!   their collator. Changes only take effect after `CommissionChangeDelay`
+//!   rounds.
+//!
 //! - **Total collator stake:** The sum of tokens locked for staking from all
 //!   collator candidates.
 //!
@@ -118,6 +141,7 @@
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 pub mod default_weights;
+pub mod migrations;
 
 #[cfg(test)]
 pub(crate) mod mock;
@@ -129,13 +153,23 @@ mod try_state;
 
 mod inflation;
 mod set;
+mod traits;
 mod types;
+mod voting;
 
 use frame_support::pallet;
 
-pub use crate::{default_weights::WeightInfo, pallet::*};
-pub use module_pallet_staking_rpc_runtime_api::StakingRates;
-pub use crate::types::AccountIdOf;
+pub use crate::{
+	default_weights::WeightInfo,
+	pallet::*,
+	traits::{OnStakingUpdate, VotingPowerProvider},
+	types::AccountIdOf,
+	voting::StakeVotingCurrency,
+};
+pub use module_pallet_staking_rpc_runtime_api::{
+	CandidateInfo, CandidateState, CollatorApy, CollatorCommission, CollatorSnapshot,
+	DelegationInfo, DisplacedDelegation, StakingRates, UnstakingInfo,
+};
 
 #[pallet]
 pub mod pallet {
@@ -145,21 +179,25 @@ pub mod pallet {
 	use frame_support::{
 		assert_ok,
 		pallet_prelude::*,
-		storage::bounded_btree_map::BoundedBTreeMap,
+		storage::{bounded_btree_map::BoundedBTreeMap, with_storage_layer},
 		traits::{
-			Currency, EstimateNextSessionRotation, Get, LockIdentifier,
+			Currency, EstimateNextSessionRotation, Get, Imbalance, LockIdentifier,
 			LockableCurrency, OnUnbalanced, ReservableCurrency, StorageVersion, WithdrawReasons,
 		},
-		BoundedVec,
+		BoundedVec, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
+	use orml_traits::MultiCurrency;
 	use pallet_balances::{BalanceLock, Locks};
 	use pallet_session::ShouldEndSession;
 	use scale_info::TypeInfo;
 	use sp_arithmetic::per_things::Perquintill;
 	use sp_runtime::{
-		traits::{Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero},
-		Permill,
+		traits::{
+			AccountIdConversion, CheckedSub, Convert, One, SaturatedConversion, Saturating,
+			StaticLookup, Zero,
+		},
+		FixedPointNumber, FixedU128, Permill,
 	};
 	use sp_staking::SessionIndex;
 	use sp_std::prelude::*;
@@ -167,17 +205,20 @@ pub mod pallet {
 	use crate::{
 		set::OrderedSet,
 		types::{
-			BalanceOf, Candidate, CandidateOf, CandidateStatus, DelegationCounter, Delegator,
-			NegativeImbalanceOf, RoundInfo, Stake, StakeOf, TotalStake,
+			BalanceOf, Candidate, CandidateOf, CandidateStatus, CollatorSnapshotOf,
+			CommissionChange, DelegationCounter, Delegator, DisplacedDelegationOf,
+			ExtraRewardCurrencyIdOf, LivenessConfig, LivenessPenalty, NegativeImbalanceOf,
+			RewardDestination, RewardDestinationOf, RoundInfo, Stake, StakeLock, StakeLockOf,
+			StakeOf, StakingConfig, StakingConfigOf, TotalStake,
 		},
 	};
-	use sp_std::{convert::TryInto, fmt::Debug};
+	use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, fmt::Debug};
 
 	/// Kilt-specific lock for staking rewards.
 	pub(crate) const STAKING_ID: LockIdentifier = *b"kiltpstk";
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
 	/// Pallet for parachain staking.
 	#[pallet::pallet]
@@ -225,13 +266,14 @@ pub mod pallet {
 		/// genesis configuration.
 		#[pallet::constant]
 		type DefaultBlocksPerRound: Get<BlockNumberFor<Self>>;
-		/// Number of blocks for which unstaked balance will still be locked
-		/// before it can be unlocked by actively calling the extrinsic
-		/// `unlock_unstaked`.
+		/// Default number of blocks for which unstaked balance will still be
+		/// locked before it can be unlocked by actively calling the extrinsic
+		/// `unlock_unstaked`, as set in the genesis configuration.
 		#[pallet::constant]
 		type StakeDuration: Get<BlockNumberFor<Self>>;
-		/// Number of rounds a collator has to stay active after submitting a
-		/// request to leave the set of collator candidates.
+		/// Default number of rounds a collator has to stay active after
+		/// submitting a request to leave the set of collator candidates, as set
+		/// in the genesis configuration.
 		#[pallet::constant]
 		type ExitQueueDelay: Get<u32>;
 
@@ -253,16 +295,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxDelegationsPerRound: Get<u32>;
 
-		/// Maximum number of delegators a single collator can have.
+		/// Maximum number of delegators a single collator can have. Upper bound
+		/// of the number of delegators set in the staking configuration.
 		#[pallet::constant]
 		type MaxDelegatorsPerCollator: Get<u32> + Debug + PartialEq;
 
+		/// Maximum number of collators a single delegator can delegate.
+		#[pallet::constant]
+		type MaxCollatorsPerDelegator: Get<u32> + Debug + PartialEq;
+
 		/// Maximum size of the top candidates set.
 		#[pallet::constant]
 		type MaxTopCandidates: Get<u32> + Debug + PartialEq;
 
-		/// Minimum stake required for any account to be elected as validator
-		/// for a round.
+		/// Default minimum stake required for any account to be elected as
+		/// validator for a round, as set in the genesis configuration.
 		#[pallet::constant]
 		type MinCollatorStake: Get<BalanceOf<Self>>;
 
@@ -271,7 +318,8 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinCollatorCandidateStake: Get<BalanceOf<Self>>;
 
-		/// Minimum stake required for any account to become a delegator.
+		/// Default minimum stake required for any account to become a
+		/// delegator, as set in the genesis configuration.
 		#[pallet::constant]
 		type MinDelegatorStake: Get<BalanceOf<Self>>;
 
@@ -298,12 +346,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type NetworkRewardStart: Get<BlockNumberFor<Self>>;
 
-		/// The rate in percent for the network rewards which are based on the
-		/// maximum number of collators and the maximum amount a collator can
-		/// stake.
+		/// Default rate in percent for the network rewards which are based on
+		/// the maximum number of collators and the maximum amount a collator
+		/// can stake, as set in the genesis configuration.
 		#[pallet::constant]
 		type NetworkRewardRate: Get<Perquintill>;
 
+		/// Upper bound of the network reward rate set in the staking
+		/// configuration.
+		#[pallet::constant]
+		type MaxNetworkRewardRate: Get<Perquintill>;
+
+		/// Upper bound of the number of blocks for which unstaked balance is
+		/// locked, as set in the staking configuration.
+		#[pallet::constant]
+		type MaxStakeDuration: Get<BlockNumberFor<Self>>;
+
 		/// The beneficiary to receive the network rewards.
 		type NetworkRewardBeneficiary: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
@@ -315,7 +373,58 @@ pub mod pallet {
 		/// Used for getting the treasury account
 		#[pallet::constant]
 		type TreasuryAccount: Get<AccountIdOf<Self>>;
-		
+
+		/// The maximum commission a collator candidate can charge their
+		/// delegators.
+		#[pallet::constant]
+		type MaxCollatorCommission: Get<Perquintill>;
+
+		/// Number of rounds a collator candidate has to wait after requesting
+		/// a commission change before it can be executed.
+		#[pallet::constant]
+		type CommissionChangeDelay: Get<u32>;
+
+		/// Number of past rounds for which the snapshot of the selected
+		/// collators is kept in storage.
+		#[pallet::constant]
+		type MaxRoundSnapshots: Get<u32>;
+
+		/// Maximum number of accounts whose rewards can be paid out in a
+		/// single `payout_stakers` call.
+		#[pallet::constant]
+		type MaxPayoutStakers: Get<u32>;
+
+		/// The lock periods in blocks which delegators can commit to and the
+		/// bonus on their rewards for each of them.
+		#[pallet::constant]
+		type StakeLockPeriods: Get<Vec<(BlockNumberFor<Self>, Perquintill)>>;
+
+		/// The currencies in which extra rewards are paid out on top of the
+		/// inflation rewards.
+		type ExtraRewardCurrency: MultiCurrency<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// The id of the pot which holds the governance-funded extra rewards.
+		#[pallet::constant]
+		type ExtraRewardsPotId: Get<PalletId>;
+
+		/// Maximum number of currencies in which extra rewards can be paid
+		/// out.
+		#[pallet::constant]
+		type MaxExtraRewardCurrencies: Get<u32>;
+
+		/// Hooks which are called whenever a delegation changes, when rewards
+		/// are paid out and when a new round starts.
+		type OnStakingUpdate: OnStakingUpdate<Self::AccountId, BalanceOf<Self>>;
+
+		/// Whether a delegator whose delegation has been replaced can delegate
+		/// again within the same round without counting towards
+		/// `MaxDelegationsPerRound`.
+		#[pallet::constant]
+		type DisplacedDelegatorGracePeriod: Get<bool>;
+
+		/// The currency in which extra rewards are paid out in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type ExtraRewardBenchmarkCurrencyId: Get<ExtraRewardCurrencyIdOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -414,6 +523,36 @@ pub mod pallet {
 		TreasuryBalanceInsufficient,
 		/// Failed to transfer from treasury.
 		TreasuryTransferFailed,
+		/// The commission exceeds `MaxCollatorCommission`.
+		CommissionTooHigh,
+		/// The collator candidate has not requested a commission change.
+		NoCommissionChangeRequested,
+		/// The commission change cannot be executed before waiting at least
+		/// `CommissionChangeDelay` many rounds.
+		CannotChangeCommissionYet,
+		/// The stake cannot be redelegated to the same collator candidate.
+		RedelegationToSameCollator,
+		/// The account is neither a collator candidate nor a delegator.
+		NotStaking,
+		/// The collator candidate has not been marked as idle.
+		NotIdle,
+		/// The same account has been provided more than once.
+		DuplicateInvulnerable,
+		/// The lock period is not one of the configured `StakeLockPeriods`.
+		InvalidLockPeriod,
+		/// The new lock would end before the current lock of the delegator.
+		LockCannotBeShortened,
+		/// Extra rewards are already paid out in the maximum number of
+		/// currencies.
+		TooManyExtraRewardCurrencies,
+		/// The extra rewards could not be transferred out of the pot.
+		ExtraRewardTransferFailed,
+		/// The account has no pending unstaking request for the given block.
+		UnstakingNotFound,
+		/// The stake duration is zero or exceeds `MaxStakeDuration`.
+		InvalidStakeDuration,
+		/// The network reward rate exceeds `MaxNetworkRewardRate`.
+		NetworkRewardRateTooHigh,
 	}
 
 	#[pallet::event]
@@ -502,6 +641,73 @@ pub mod pallet {
 		/// \[round number, first block in the current round, old value, new
 		/// value\]
 		BlocksPerRoundSet(SessionIndex, BlockNumberFor<T>, BlockNumberFor<T>, BlockNumberFor<T>),
+		/// A collator candidate has requested to change their commission.
+		/// \[collator's account, current commission, requested commission,
+		/// round number from which on the change can be executed\]
+		CommissionChangeRequested(T::AccountId, Perquintill, Perquintill, SessionIndex),
+		/// The commission of a collator candidate has changed.
+		/// \[collator's account, old commission, new commission\]
+		CommissionChanged(T::AccountId, Perquintill, Perquintill),
+		/// A delegator has moved stake from one collator candidate to another
+		/// one without unstaking it.
+		/// \[delegator's account, old collator's account, new collator's
+		/// account, moved amount\]
+		Redelegated(T::AccountId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// An account has set the share of their rewards which is restaked
+		/// automatically. \[account, auto-compounding share\]
+		AutoCompoundSet(T::AccountId, Perquintill),
+		/// Rewards of an account have been restaked automatically.
+		/// \[account, collator's account, restaked amount\]
+		RewardsCompounded(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// The liveness requirements of selected collators have changed.
+		/// \[new liveness config, if enabled\]
+		LivenessConfigSet(Option<LivenessConfig>),
+		/// A collator has been marked as idle because they missed too many
+		/// rounds. \[collator's account, number of missed rounds\]
+		CollatorWentIdle(T::AccountId, u32),
+		/// A collator has been slashed because they missed too many rounds.
+		/// \[collator's account, slashed amount\]
+		CollatorSlashed(T::AccountId, BalanceOf<T>),
+		/// An idle collator candidate went online again.
+		/// \[collator's account\]
+		CollatorWentOnline(T::AccountId),
+		/// The staking configuration has changed.
+		/// \[new staking config\]
+		StakingConfigSet(StakingConfigOf<T>),
+		/// An account has set the destination of their rewards.
+		/// \[account, reward destination\]
+		RewardDestinationSet(T::AccountId, RewardDestinationOf<T>),
+		/// Rewards have been paid into the reward destination of an account.
+		/// \[account, destination account, amount\]
+		RewardedTo(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// Rewards of an account have been donated to the treasury.
+		/// \[account, amount\]
+		RewardsDonated(T::AccountId, BalanceOf<T>),
+		/// The set of invulnerable collators has changed.
+		/// \[new invulnerables\]
+		InvulnerablesSet(Vec<T::AccountId>),
+		/// A delegator has committed to keep their stake locked.
+		/// \[delegator's account, block until which the stake is locked,
+		/// reward bonus\]
+		StakeLocked(T::AccountId, BlockNumberFor<T>, Perquintill),
+		/// The amount of extra rewards distributed per round has been set.
+		/// \[currency, amount per round\]
+		ExtraRewardRateSet(ExtraRewardCurrencyIdOf<T>, BalanceOf<T>),
+		/// Extra rewards have been distributed among the collators of a round.
+		/// \[round, currency, amount\]
+		ExtraRewardsDistributed(SessionIndex, ExtraRewardCurrencyIdOf<T>, BalanceOf<T>),
+		/// Extra rewards have been paid into the reward destination of an
+		/// account.
+		/// \[account, destination account, currency, amount\]
+		ExtraRewardsClaimed(T::AccountId, T::AccountId, ExtraRewardCurrencyIdOf<T>, BalanceOf<T>),
+		/// (Part of) a pending unstaking request has been cancelled and the
+		/// funds have been staked again.
+		/// \[account, block of the unstaking request, amount\]
+		UnstakingCancelled(T::AccountId, BlockNumberFor<T>, BalanceOf<T>),
+		/// The rewards of an account could not be claimed during a batch
+		/// payout and remain claimable.
+		/// \[account, error\]
+		PayoutFailed(T::AccountId, DispatchError),
 	}
 
 	#[pallet::hooks]
@@ -517,6 +723,7 @@ pub mod pallet {
 				// start next round
 				<Round<T>>::put(round);
 
+				T::OnStakingUpdate::on_new_round(round.current);
 				Self::deposit_event(Event::NewRound(round.first, round.current));
 				post_weight = <T as Config>::WeightInfo::on_initialize_round_update();
 			}
@@ -560,7 +767,7 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		T::AccountId,
-		Delegator<T::AccountId, BalanceOf<T>>,
+		Delegator<T::AccountId, BalanceOf<T>, T::MaxCollatorsPerDelegator>,
 		OptionQuery,
 	>;
 
@@ -640,23 +847,37 @@ pub mod pallet {
 	#[pallet::getter(fn last_reward_reduction)]
 	pub(crate) type LastRewardReduction<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
-	/// The counter of accumulated rewards for an account.
-	///
-	/// For collators, it reflects the number of authored blocks since the last
-	/// reward raise. Thus, everytime a collator authors a block, the
-	/// counter is increased. It is reset, when the collator increments their
-	/// rewards.
+	/// The counter of accumulated rewards for a collator.
 	///
-	/// For delegators, it is used to determine the difference between the
-	/// delegator and corresponding collator when incrementing the delegator's
-	/// rewards. In this case, the counter is never incremented but reset to the
-	/// collator one when the delegator reward increment happens.
+	/// It reflects the number of authored blocks since the last reward raise.
+	/// Thus, everytime a collator authors a block, the counter is increased.
+	/// It is reset, when the collator increments their rewards.
 	// TODO: Maybe rather use u64. Assuming 30 validators, u32 would suffice for 27 years of constant 12s blocktime.
 	#[pallet::storage]
 	#[pallet::getter(fn reward_count)]
 	pub(crate) type RewardCount<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
 
+	/// The counter of accumulated rewards for a single delegation.
+	///
+	/// It maps from a delegator and a delegated collator to the value of the
+	/// collator's [RewardCount] at the last reward increment of the
+	/// delegation. It is used to determine the difference between the
+	/// delegation and the corresponding collator when incrementing the
+	/// delegator's rewards. Thus, the counter is never incremented but reset
+	/// to the collator one when the delegator reward increment happens.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation_reward_count)]
+	pub(crate) type DelegationRewardCount<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
 	/// The accumulated rewards for collator candidates and delegators.
 	///
 	/// It maps from accounts to their total rewards since the last payout.
@@ -675,6 +896,187 @@ pub mod pallet {
 	#[pallet::getter(fn new_round_forced)]
 	pub(crate) type ForceNewRound<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The pending commission changes of collator candidates.
+	///
+	/// It maps from a candidate to the commission which can be applied once
+	/// the stored round has been reached.
+	#[pallet::storage]
+	#[pallet::getter(fn commission_change_request)]
+	pub(crate) type CommissionChangeRequests<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, CommissionChange, OptionQuery>;
+
+	/// The share of newly accumulated rewards which is restaked
+	/// automatically.
+	///
+	/// It maps from an account to their auto-compounding share. Accounts
+	/// without an entry do not compound their rewards.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound)]
+	pub(crate) type AutoCompound<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Perquintill, ValueQuery>;
+
+	/// The destination to which the rewards of an account are paid.
+	///
+	/// Accounts without an entry receive their rewards in their own account.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_destination)]
+	pub(crate) type RewardDestinations<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RewardDestinationOf<T>, ValueQuery>;
+
+	/// The stake locks of delegators which committed to keep their stake
+	/// locked for a longer period.
+	///
+	/// While a lock is active, the delegator receives a bonus on their
+	/// rewards and cannot unlock unstaked funds before the lock ends, unless
+	/// the delegation was forcedly removed.
+	#[pallet::storage]
+	#[pallet::getter(fn stake_lock)]
+	pub(crate) type StakeLocks<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, StakeLockOf<T>, OptionQuery>;
+
+	/// The latest delegations of an account which have been replaced by
+	/// higher delegations.
+	///
+	/// It maps from an account to at most `MaxCollatorsPerDelegator` records,
+	/// the oldest ones are dropped first.
+	#[pallet::storage]
+	#[pallet::getter(fn displaced_delegations)]
+	pub(crate) type DisplacedDelegations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<DisplacedDelegationOf<T>, T::MaxCollatorsPerDelegator>,
+		ValueQuery,
+	>;
+
+	/// The amount of extra rewards which is distributed per round for each
+	/// extra reward currency.
+	///
+	/// The rewards are paid out of the pot, thus less is distributed if the
+	/// pot runs dry.
+	#[pallet::storage]
+	#[pallet::getter(fn extra_reward_rates)]
+	pub(crate) type ExtraRewardRates<T: Config> = StorageValue<
+		_,
+		BoundedVec<(ExtraRewardCurrencyIdOf<T>, BalanceOf<T>), T::MaxExtraRewardCurrencies>,
+		ValueQuery,
+	>;
+
+	/// The accumulated extra rewards per staked token of a collator for each
+	/// extra reward currency.
+	///
+	/// It maps from collator accounts to the rewards per stake.
+	#[pallet::storage]
+	#[pallet::getter(fn extra_rewards_per_stake)]
+	pub(crate) type ExtraRewardsPerStake<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(ExtraRewardCurrencyIdOf<T>, FixedU128), T::MaxExtraRewardCurrencies>,
+		ValueQuery,
+	>;
+
+	/// The extra rewards per stake of a collator which have already been
+	/// credited to a staker of the collator.
+	///
+	/// It maps from (staker, collator) to the rewards per stake at the last
+	/// time the rewards of the staker were incremented.
+	#[pallet::storage]
+	#[pallet::getter(fn extra_reward_checkpoint)]
+	pub(crate) type ExtraRewardCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(ExtraRewardCurrencyIdOf<T>, FixedU128), T::MaxExtraRewardCurrencies>,
+		ValueQuery,
+	>;
+
+	/// The accumulated extra rewards of collator candidates and delegators
+	/// which can be claimed per currency.
+	#[pallet::storage]
+	#[pallet::getter(fn extra_rewards)]
+	pub(crate) type ExtraRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ExtraRewardCurrencyIdOf<T>,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The amount of extra rewards in the pot which has been distributed but
+	/// not yet claimed.
+	#[pallet::storage]
+	#[pallet::getter(fn extra_rewards_allocated)]
+	pub(crate) type ExtraRewardsAllocated<T: Config> =
+		StorageMap<_, Twox64Concat, ExtraRewardCurrencyIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// The liveness requirements of selected collators.
+	///
+	/// Liveness checks are disabled if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn liveness_config)]
+	pub(crate) type LivenessConfiguration<T: Config> = StorageValue<_, LivenessConfig, OptionQuery>;
+
+	/// The number of blocks authored by each collator in the current session.
+	#[pallet::storage]
+	#[pallet::getter(fn blocks_authored)]
+	pub(crate) type BlocksAuthored<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The number of rounds in a row in which a collator authored fewer blocks
+	/// than required by the [LivenessConfiguration].
+	#[pallet::storage]
+	#[pallet::getter(fn missed_rounds)]
+	pub(crate) type MissedRounds<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The snapshots of the selected collators of past rounds.
+	///
+	/// It maps from a round to the total backing, the number of authored
+	/// blocks and the rewards credited during that round of each collator
+	/// selected for it. Only the last `MaxRoundSnapshots` rounds are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn round_snapshot)]
+	pub(crate) type RoundSnapshots<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		BoundedVec<CollatorSnapshotOf<T>, T::MaxTopCandidates>,
+		ValueQuery,
+	>;
+
+	/// The staking configuration as set by the runtime constants.
+	#[pallet::type_value]
+	pub(crate) fn DefaultStakingConfig<T: Config>() -> StakingConfigOf<T> {
+		StakingConfig {
+			min_collator_stake: T::MinCollatorStake::get(),
+			min_delegator_stake: T::MinDelegatorStake::get(),
+			max_delegators_per_collator: T::MaxDelegatorsPerCollator::get(),
+			stake_duration: T::StakeDuration::get(),
+			exit_queue_delay: T::ExitQueueDelay::get(),
+			network_reward_rate: T::NetworkRewardRate::get(),
+		}
+	}
+
+	/// The staking parameters which can be changed by governance.
+	#[pallet::storage]
+	#[pallet::getter(fn staking_config)]
+	pub(crate) type StakingConfiguration<T: Config> =
+		StorageValue<_, StakingConfigOf<T>, ValueQuery, DefaultStakingConfig<T>>;
+
+	/// The collators which are always selected regardless of their stake.
+	///
+	/// Invulnerables do not need to be collator candidates and do not receive
+	/// any block authoring rewards.
+	#[pallet::storage]
+	#[pallet::getter(fn invulnerables)]
+	pub(crate) type Invulnerables<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MinRequiredCollators>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub stakers: GenesisStaker<T>,
@@ -704,6 +1106,7 @@ pub mod pallet {
 
 			InflationConfig::<T>::put(self.inflation_config.clone());
 			MaxCollatorCandidateStake::<T>::put(self.max_candidate_stake);
+			StakingConfiguration::<T>::put(DefaultStakingConfig::<T>::get());
 
 			// Setup delegate & collators
 			for &(ref actor, ref opt_val, balance) in &self.stakers {
@@ -716,6 +1119,7 @@ pub mod pallet {
 						T::RuntimeOrigin::from(Some(actor.clone()).into()),
 						T::Lookup::unlookup(delegated_val.clone()),
 						balance,
+						None,
 					));
 				} else {
 					assert_ok!(<Pallet<T>>::join_candidates(
@@ -1045,7 +1449,7 @@ pub mod pallet {
 
 			// *** No Fail except during increase_lock beyond this point ***
 
-			Self::increase_lock(&sender, stake, BalanceOf::<T>::zero())?;
+			Self::increase_lock(&sender, stake, BalanceOf::<T>::zero(), None)?;
 
 			let candidate = Candidate::new(sender.clone(), stake);
 			let n = Self::update_top_candidates(
@@ -1109,7 +1513,7 @@ pub mod pallet {
 			);
 
 			let now = <Round<T>>::get().current;
-			let when = now.saturating_add(T::ExitQueueDelay::get());
+			let when = now.saturating_add(StakingConfiguration::<T>::get().exit_queue_delay);
 			state.leave_candidates(when);
 
 			// *** No Fail beyond this point ***
@@ -1254,41 +1658,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let collator = ensure_signed(origin)?;
 
-			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
-			let mut state =
-				CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
-			ensure!(!state.is_leaving(), Error::<T>::CannotStakeIfLeaving);
-
-			let CandidateOf::<T, _> { stake: before_stake, total: before_total, .. } = state;
-			state.stake_more(more);
-			let after_stake = state.stake;
-			ensure!(
-				state.stake <= MaxCollatorCandidateStake::<T>::get(),
-				Error::<T>::ValStakeAboveMax
-			);
-
-			// *** No Fail except during increase_lock beyond this point ***
-
-			let unstaking_len = Self::increase_lock(&collator, state.stake, more)?;
-
-			let n = if state.is_active() {
-				Self::update_top_candidates(
-					collator.clone(),
-					before_stake,
-					// safe because total >= stake
-					before_total - before_stake,
-					state.stake,
-					state.total - state.stake,
-				)
-			} else {
-				0u32
-			};
-			CandidatePool::<T>::insert(&collator, state);
-
-			// increment rewards for origin + their delegators and reset reward counter
-			Self::do_inc_collator_reward(&collator, before_stake);
-
-			Self::deposit_event(Event::CollatorStakedMore(collator, before_stake, after_stake));
+			let (n, unstaking_len) = Self::do_stake_more(&collator, None, more, None)?;
 			Ok(Some(<T as pallet::Config>::WeightInfo::candidate_stake_more(
 				n,
 				T::MaxDelegatorsPerCollator::get(),
@@ -1352,7 +1722,7 @@ pub mod pallet {
 			CandidatePool::<T>::insert(&collator, state);
 
 			// increment rewards for origin + their delegators and reset reward counter
-			Self::do_inc_collator_reward(&collator, before_stake);
+			Self::do_inc_collator_reward_and_compound(&collator, before_stake);
 
 			Self::deposit_event(Event::CollatorStakedLess(collator, before_stake, after));
 			Ok(Some(<T as pallet::Config>::WeightInfo::candidate_stake_less(
@@ -1368,7 +1738,7 @@ pub mod pallet {
 		/// candidates set as well.
 		///
 		/// The caller must _not_ have a delegation. If that is the case, they
-		/// are required to first remove the delegation.
+		/// are required to call `delegate_another_candidate` instead.
 		///
 		/// The amount staked must be larger than the minimum required to become
 		/// a delegator as set in the pallet's configuration.
@@ -1380,10 +1750,14 @@ pub mod pallet {
 		/// The collator's total stake as well as the pallet's total stake are
 		/// increased accordingly.
 		///
+		/// Optionally, the delegator can commit to lock their stake for one
+		/// of the `StakeLockPeriods`, see `delegator_stake_more`.
+		///
 		/// Emits `Delegation`.
 		/// Emits `DelegationReplaced` if the candidate has
 		/// `MaxDelegatorsPerCollator` many delegations but this delegator
 		/// staked more than one of the other delegators of this candidate.
+		/// Emits `StakeLocked` if a lock period is given.
 		#[pallet::call_index(12)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::join_delegators(
 			T::MaxTopCandidates::get(),
@@ -1393,6 +1767,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			collator: <T::Lookup as StaticLookup>::Source,
 			amount: BalanceOf<T>,
+			lock_period: Option<BlockNumberFor<T>>,
 		) -> DispatchResultWithPostInfo {
 			let acc = ensure_signed(origin)?;
 			let collator = T::Lookup::lookup(collator)?;
@@ -1405,7 +1780,10 @@ pub mod pallet {
 
 			// first delegation
 			ensure!(DelegatorState::<T>::get(&acc).is_none(), Error::<T>::AlreadyDelegating);
-			ensure!(amount >= T::MinDelegatorStake::get(), Error::<T>::DelegationBelowMin);
+			ensure!(
+				amount >= StakingConfiguration::<T>::get().min_delegator_stake,
+				Error::<T>::DelegationBelowMin
+			);
 
 			// cannot be a collator candidate and delegator with same AccountId
 			ensure!(Self::is_active_candidate(&acc).is_none(), Error::<T>::CandidateExists);
@@ -1414,70 +1792,13 @@ pub mod pallet {
 					< T::MaxUnstakeRequests::get(),
 				Error::<T>::CannotJoinBeforeUnlocking
 			);
-			// cannot delegate if number of delegations in this round exceeds
-			// MaxDelegationsPerRound
-			let delegation_counter = Self::get_delegation_counter(&acc)?;
-
-			// prepare update of collator state
-			let mut state =
-				CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
-			let num_delegations_pre_insertion: u32 = state.delegators.len().saturated_into();
-
-			ensure!(!state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let delegation = Stake { owner: acc.clone(), amount };
-
-			// attempt to insert delegator and check for uniqueness
-			// NOTE: excess is handled below because we support replacing a delegator with
-			// fewer stake
-			let insert_delegator = state
-				.delegators
-				// we handle TooManyDelegators error below in do_update_delegator
-				.try_insert(delegation.clone())
-				.unwrap_or(true);
-			// should never fail but let's be safe
-			ensure!(insert_delegator, Error::<T>::DelegatorExists);
-
-			let delegator_state = Delegator { amount, owner: Some(collator.clone()) };
-			let CandidateOf::<T, _> { stake: old_stake, total: old_total, .. } = state;
-
-			// update state and potentially prepare kicking a delegator with less staked
-			// amount (includes setting rewards for kicked delegator)
-			let state = if num_delegations_pre_insertion == T::MaxDelegatorsPerCollator::get() {
-				Self::do_update_delegator(delegation, state)?
-			} else {
-				state.total = state.total.saturating_add(amount);
-				state
-			};
-			let new_total = state.total;
-
-			// *** No Fail except during increase_lock beyond this point ***
-
-			// lock stake
-			Self::increase_lock(&acc, amount, BalanceOf::<T>::zero())?;
-
-			// update top candidates and total amount at stake
-			let n = if state.is_active() {
-				Self::update_top_candidates(
-					collator.clone(),
-					old_stake,
-					// safe because total >= stake
-					old_total - old_stake,
-					state.stake,
-					state.total - state.stake,
-				)
-			} else {
-				0u32
-			};
 
-			// update states
-			CandidatePool::<T>::insert(&collator, state);
-			DelegatorState::<T>::insert(&acc, delegator_state);
-			<LastDelegation<T>>::insert(&acc, delegation_counter);
+			if let Some(period) = lock_period {
+				Self::do_lock_stake(&acc, period)?;
+			}
 
-			// initiate reward counter to match the current state of the candidate
-			RewardCount::<T>::insert(&acc, RewardCount::<T>::get(&collator));
+			let n = Self::do_delegate(acc, collator, amount, Delegator::default())?;
 
-			Self::deposit_event(Event::Delegation(acc, amount, collator, new_total));
 			Ok(Some(<T as pallet::Config>::WeightInfo::join_delegators(
 				n,
 				T::MaxDelegatorsPerCollator::get(),
@@ -1485,8 +1806,8 @@ pub mod pallet {
 			.into())
 		}
 
-		/// Leave the set of delegators and, by implication, revoke the ongoing
-		/// delegation.
+		/// Leave the set of delegators and, by implication, revoke all ongoing
+		/// delegations.
 		///
 		/// All staked funds are not unlocked immediately, but they are added to
 		/// the queue of pending unstaking, and will effectively be released
@@ -1497,32 +1818,36 @@ pub mod pallet {
 		/// their chances to be included in the set of candidates in the next
 		/// rounds.
 		///
-		/// Automatically increments the accumulated rewards of the origin of
-		/// the current delegation.
+		/// Automatically increments the accumulated rewards of the origin for
+		/// all of their delegations.
 		///
+		/// Emits `DelegatorLeftCollator` for each delegation.
 		/// Emits `DelegatorLeft`.
 		#[pallet::call_index(13)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::leave_delegators(
 			T::MaxTopCandidates::get(),
 			T::MaxDelegatorsPerCollator::get()
-		))]
+		).saturating_mul(T::MaxCollatorsPerDelegator::get().into()))]
 		pub fn leave_delegators(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let acc = ensure_signed(origin)?;
 			let delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
-			// should never throw
-			let collator = delegator.owner.ok_or(Error::<T>::DelegationNotFound)?;
-			Self::delegator_leaves_collator(acc.clone(), collator)?;
+			let mut weight = Weight::zero();
+			for stake in delegator.delegations.into_iter() {
+				let n = Self::delegator_leaves_collator(acc.clone(), stake.owner)?;
+				weight =
+					weight.saturating_add(<T as pallet::Config>::WeightInfo::leave_delegators(
+						n,
+						T::MaxDelegatorsPerCollator::get(),
+					));
+			}
 
 			// *** No Fail beyond this point ***
 
 			DelegatorState::<T>::remove(&acc);
+			StakeLocks::<T>::remove(&acc);
 
-			Self::deposit_event(Event::DelegatorLeft(acc, delegator.amount));
-			Ok(Some(<T as pallet::Config>::WeightInfo::leave_delegators(
-				1,
-				T::MaxDelegatorsPerCollator::get(),
-			))
-			.into())
+			Self::deposit_event(Event::DelegatorLeft(acc, delegator.total));
+			Ok(Some(weight).into())
 		}
 
 		/// Increase the stake for delegating a collator candidate.
@@ -1530,66 +1855,45 @@ pub mod pallet {
 		/// If not in the set of candidates, staking enough funds allows the
 		/// collator candidate to be added to it.
 		///
+		/// Optionally, the delegator can commit to lock their stake for one
+		/// of the `StakeLockPeriods`. While the lock is active, the rewards of
+		/// all delegations of the delegator are increased by the bonus of the
+		/// lock period and unstaked funds cannot be unlocked before the lock
+		/// ends. Rewards are only increased if they are incremented while the
+		/// lock is active. An active lock can only be replaced by a lock which
+		/// does not end earlier.
+		///
 		/// Emits `DelegatorStakedMore`.
+		/// Emits `StakeLocked` if a lock period is given.
 		#[pallet::call_index(14)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegator_stake_more(
 			T::MaxTopCandidates::get(),
 			T::MaxDelegatorsPerCollator::get(),
 			T::MaxUnstakeRequests::get().saturated_into::<u32>())
+			.saturating_add(if lock_period.is_some() {
+				// rewards are incremented for all delegations before locking
+				<T as pallet::Config>::WeightInfo::increment_delegator_rewards()
+					.saturating_mul(T::MaxCollatorsPerDelegator::get().into())
+			} else {
+				Weight::zero()
+			})
 		)]
 		pub fn delegator_stake_more(
 			origin: OriginFor<T>,
 			candidate: <T::Lookup as StaticLookup>::Source,
 			more: BalanceOf<T>,
+			lock_period: Option<BlockNumberFor<T>>,
 		) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
 			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
 
 			let candidate = T::Lookup::lookup(candidate)?;
-			let mut delegation =
-				DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let mut collator =
-				CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
-			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let stake_after = delegation
-				.inc_delegation(candidate.clone(), more)
-				.map_err(|_| Error::<T>::DelegationNotFound)?;
-
-			// *** No Fail except during increase_lock beyond this point ***
-
-			// update lock
-			let unstaking_len = Self::increase_lock(&delegator, stake_after, more)?;
-
-			let CandidateOf::<T, _> { stake: before_stake, total: before_total, .. } = collator;
-			collator.inc_delegator(delegator.clone(), more);
-			let after = collator.total;
-
-			// update top candidates and total amount at stake
-			let n = if collator.is_active() {
-				Self::update_top_candidates(
-					candidate.clone(),
-					before_stake,
-					// safe because total >= stake
-					before_total - before_stake,
-					collator.stake,
-					collator.total - collator.stake,
-				)
-			} else {
-				0u32
-			};
-
-			// set rewards and reset reward counter
-			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_sub(more), &candidate);
-
-			CandidatePool::<T>::insert(&candidate, collator);
-			DelegatorState::<T>::insert(&delegator, delegation);
-
-			Self::deposit_event(Event::DelegatorStakedMore(
-				delegator,
-				candidate,
-				before_total,
-				after,
-			));
+			// needs to happen before reading the delegator state since rewards might be
+			// compounded
+			if let Some(period) = lock_period {
+				Self::do_lock_stake(&delegator, period)?;
+			}
+			let (n, unstaking_len) = Self::do_stake_more(&delegator, Some(candidate), more, None)?;
 			Ok(Some(<T as pallet::Config>::WeightInfo::delegator_stake_more(
 				n,
 				T::MaxDelegatorsPerCollator::get(),
@@ -1638,7 +1942,10 @@ pub mod pallet {
 				.map_err(|_| Error::<T>::DelegationNotFound)?
 				.ok_or(Error::<T>::Underflow)?;
 
-			ensure!(stake_after >= T::MinDelegatorStake::get(), Error::<T>::DelegationBelowMin);
+			ensure!(
+				stake_after >= StakingConfiguration::<T>::get().min_delegator_stake,
+				Error::<T>::DelegationBelowMin
+			);
 
 			// *** No Fail except during prep_unstake beyond this point ***
 
@@ -1662,14 +1969,26 @@ pub mod pallet {
 				0u32
 			};
 
-			// set rewards and reset reward counter
-			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_add(less), &candidate);
-
+			let commission = collator.commission;
 			CandidatePool::<T>::insert(&candidate, collator);
 			DelegatorState::<T>::insert(&delegator, delegations);
+			T::OnStakingUpdate::on_delegation_reduced(
+				&delegator,
+				&candidate,
+				stake_after.saturating_add(less),
+				stake_after,
+			);
 
-			Self::deposit_event(Event::DelegatorStakedLess(
-				delegator,
+			// set rewards and reset reward counter
+			Self::do_inc_delegator_reward_and_compound(
+				&delegator,
+				stake_after.saturating_add(less),
+				&candidate,
+				commission,
+			);
+
+			Self::deposit_event(Event::DelegatorStakedLess(
+				delegator,
 				candidate,
 				before_total,
 				after,
@@ -1707,7 +2026,8 @@ pub mod pallet {
 			Ok(Some(<T as pallet::Config>::WeightInfo::unlock_unstaked(unstaking_len)).into())
 		}
 
-		/// Claim block authoring rewards for the target address.
+		/// Claim block authoring rewards for the origin. The rewards are paid
+		/// to the origin's reward destination.
 		///
 		/// Requires `Rewards` to be set beforehand, which can by triggered by
 		/// any of the following options
@@ -1718,36 +2038,16 @@ pub mod pallet {
 		/// * Being a delegator whose collator left the network, altered their
 		///   stake or incremented rewards (passive)
 		///
-		/// The dispatch origin can be any signed one, e.g., anyone can claim
-		/// for anyone.
+		/// The dispatch origin must be signed. Use `claim_rewards_for` to claim
+		/// on behalf of another account.
 		///
-		/// Emits `Rewarded`.
+		/// Emits `Rewarded`, `RewardedTo` or `RewardsDonated` depending on the
+		/// reward destination.
 		#[pallet::call_index(17)]
 		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
 		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
 			let target = ensure_signed(origin)?;
-
-			// we could kill the storage entry but let's be safe in case the deposit fails
-			let rewards = Rewards::<T>::get(&target);
-			ensure!(!rewards.is_zero(), Error::<T>::RewardsNotFound);
-
-			let treasury_account_id = T::TreasuryAccount::get();
-			let treasury_balance = T::Currency::free_balance(&treasury_account_id);
-			ensure!(treasury_balance >= rewards, Error::<T>::TreasuryBalanceInsufficient);
-
-			T::Currency::transfer(
-				&treasury_account_id,
-				&target,
-				rewards,
-				frame_support::traits::ExistenceRequirement::KeepAlive,
-			)
-			.map_err(|_| Error::<T>::TreasuryTransferFailed)?;
-
-			Rewards::<T>::remove(&target);
-
-			Self::deposit_event(Event::Rewarded(target, rewards));
-
-			Ok(())
+			Self::do_claim_rewards(&target)
 		}
 
 		/// Actively increment the rewards of a collator and their delegators.
@@ -1760,13 +2060,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::increment_collator_rewards(T::MaxDelegatorsPerCollator::get()))]
 		pub fn increment_collator_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let collator = ensure_signed(origin)?;
-			let state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
-
-			// early exit
-			let reward_count = RewardCount::<T>::get(&collator);
-			ensure!(!reward_count.is_zero(), Error::<T>::RewardsNotFound);
-
-			let num_delegators = Self::do_inc_collator_reward(&collator, state.stake);
+			let num_delegators = Self::do_increment_collator_rewards(&collator)?;
 			Ok(Some(<T as Config>::WeightInfo::increment_collator_rewards(
 				num_delegators.saturated_into(),
 			))
@@ -1781,18 +2075,16 @@ pub mod pallet {
 		///
 		/// The dispatch origin must be a delegator.
 		#[pallet::call_index(19)]
-		#[pallet::weight(<T as Config>::WeightInfo::increment_delegator_rewards())]
-		pub fn increment_delegator_rewards(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::increment_delegator_rewards()
+			.saturating_mul(T::MaxCollatorsPerDelegator::get().into()))]
+		pub fn increment_delegator_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
-			let delegation =
-				DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			// should never throw
-			let collator = delegation.owner.ok_or(Error::<T>::DelegationNotFound)?;
-
-			Self::do_inc_delegator_reward(&delegator, delegation.amount, &collator);
-			ensure!(!Rewards::<T>::get(&delegator).is_zero(), Error::<T>::RewardsNotFound);
-
-			Ok(())
+			let num_delegations = Self::do_increment_delegator_rewards(&delegator)?;
+			Ok(Some(
+				<T as Config>::WeightInfo::increment_delegator_rewards()
+					.saturating_mul(num_delegations.into()),
+			)
+			.into())
 		}
 
 		/// Executes the annual reduction of the reward rates for collators and
@@ -1859,720 +2151,2734 @@ pub mod pallet {
 			))
 			.into())
 		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		/// Check whether an account is currently delegating.
-		pub fn is_delegator(acc: &T::AccountId) -> bool {
-			DelegatorState::<T>::get(acc).is_some()
-		}
 
-		/// Check whether an account is currently a collator candidate and
-		/// whether their state is CollatorStatus::Active.
+		/// Request to change the commission which the collator candidate
+		/// charges on the rewards of their delegators.
 		///
-		/// Returns Some(is_active) if the account is a candidate, else None.
-		pub fn is_active_candidate(acc: &T::AccountId) -> Option<bool> {
-			if let Some(state) = CandidatePool::<T>::get(acc) {
-				Some(state.status == CandidateStatus::Active)
-			} else {
-				None
-			}
-		}
-
-		/// Update the top candidates and total amount at stake after mutating
-		/// an active candidate's stake.
+		/// The change can be executed via `execute_commission_change` after
+		/// waiting at least `CommissionChangeDelay` many rounds. This gives
+		/// delegators the chance to react to the new commission. A previously
+		/// requested change is overwritten.
 		///
-		/// NOTE: It is assumed that the calling context checks whether the
-		/// collator candidate is currently active before calling this function.
-		fn update_top_candidates(
-			candidate: T::AccountId,
-			old_self: BalanceOf<T>,
-			old_delegators: BalanceOf<T>,
-			new_self: BalanceOf<T>,
-			new_delegators: BalanceOf<T>,
-		) -> u32 {
-			let mut top_candidates = TopCandidates::<T>::get();
-			let num_top_candidates: u32 = top_candidates.len().saturated_into();
-			let old_stake =
-				Stake { owner: candidate.clone(), amount: old_self.saturating_add(old_delegators) };
-			let new_stake =
-				Stake { owner: candidate.clone(), amount: new_self.saturating_add(new_delegators) };
-
-			// update TopCandidates set
-			let maybe_top_candidate_update = if let Ok(i) = top_candidates.linear_search(&old_stake)
-			{
-				// case 1: candidate is member of TopCandidates with old stake
-				top_candidates.mutate(|vec| {
-					if let Some(stake) = vec.get_mut(i) {
-						stake.amount = new_stake.amount;
-					}
-				});
-				Some((Some(i), top_candidates))
-			} else if top_candidates.try_insert_replace(new_stake.clone()).is_ok() {
-				// case 2: candidate ascends into TopCandidates with new stake
-				// and might replace another candidate if TopCandidates is full
-				Self::deposit_event(Event::EnteredTopCandidates(candidate));
-				Some((None, top_candidates))
-			} else {
-				// case 3: candidate neither was nor will be member of TopCandidates
-				None
-			};
-
-			// update storage for TotalCollatorStake and TopCandidates
-			if let Some((maybe_old_idx, top_candidates)) = maybe_top_candidate_update {
-				let max_selected_candidates =
-					MaxSelectedCandidates::<T>::get().saturated_into::<usize>();
-				let was_collating =
-					maybe_old_idx.map(|i| i < max_selected_candidates).unwrap_or(false);
-				let is_collating = top_candidates
-					.linear_search(&new_stake)
-					.map(|i| i < max_selected_candidates)
-					.unwrap_or(false);
-
-				// update TopCollatorStake storage iff candidate was or will be a collator
-				match (was_collating, is_collating) {
-					(true, true) => {
-						Self::update_total_stake_by(
-							new_self,
-							new_delegators,
-							old_self,
-							old_delegators,
-						);
-					},
-					(true, false) => {
-						// candidate left the collator set because they staked less and have been
-						// replaced by the next candidate in the queue at position
-						// min(max_selected_candidates, top_candidates) - 1 in TopCandidates
-						let new_col_idx =
-							max_selected_candidates.min(top_candidates.len()).saturating_sub(1);
-
-						// get displacer
-						let (add_collators, add_delegators) =
-							Self::get_top_candidate_stake_at(&top_candidates, new_col_idx)
-								// shouldn't be possible to fail, but we handle it gracefully
-								.unwrap_or((new_self, new_delegators));
-						Self::update_total_stake_by(
-							add_collators,
-							add_delegators,
-							old_self,
-							old_delegators,
-						);
-					},
-					(false, true) => {
-						// candidate pushed out the least staked collator which is now at position
-						// min(max_selected_top_candidates, top_candidates - 1) in TopCandidates
-						let old_col_idx =
-							max_selected_candidates.min(top_candidates.len().saturating_sub(1));
-
-						// get amount to subtract from TotalCollatorStake
-						let (drop_self, drop_delegators) =
-							Self::get_top_candidate_stake_at(&top_candidates, old_col_idx)
-								// default to zero if candidate DNE, e.g. TopCandidates is not full
-								.unwrap_or((BalanceOf::<T>::zero(), BalanceOf::<T>::zero()));
-						Self::update_total_stake_by(
-							new_self,
-							new_delegators,
-							drop_self,
-							drop_delegators,
-						);
-					},
-					_ => {},
-				}
+		/// The commission must not exceed `MaxCollatorCommission`.
+		///
+		/// The dispatch origin must be a collator candidate.
+		///
+		/// Emits `CommissionChangeRequested`.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::request_commission_change())]
+		pub fn request_commission_change(
+			origin: OriginFor<T>,
+			commission: Perquintill,
+		) -> DispatchResult {
+			let collator = ensure_signed(origin)?;
+			ensure!(commission <= T::MaxCollatorCommission::get(), Error::<T>::CommissionTooHigh);
+			let state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!state.is_leaving(), Error::<T>::CannotStakeIfLeaving);
 
-				// update TopCandidates storage
-				TopCandidates::<T>::put(top_candidates);
-			}
+			let effective_round =
+				<Round<T>>::get().current.saturating_add(T::CommissionChangeDelay::get());
 
-			num_top_candidates
-		}
+			// *** No Fail beyond this point ***
 
-		/// Retrieve the staked amounts (self, sum of delegators) of member of
-		/// [TopCandidates] at the given index, if it exists.
-		fn get_top_candidate_stake_at(
-			top_candidates: &OrderedSet<StakeOf<T>, T::MaxTopCandidates>,
-			index: usize,
-		) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
-			top_candidates
-				.get(index)
-				.and_then(|stake| CandidatePool::<T>::get(&stake.owner))
-				// SAFETY: the total is always more than the stake
-				.map(|state| (state.stake, state.total - state.stake))
-		}
+			CommissionChangeRequests::<T>::insert(
+				&collator,
+				CommissionChange { commission, effective_round },
+			);
 
-		/// Mutate the [TotalCollatorStake] by both incrementing and decreasing
-		/// it by the provided values.
-		fn update_total_stake_by(
-			add_collators: BalanceOf<T>,
-			add_delegators: BalanceOf<T>,
-			sub_collators: BalanceOf<T>,
-			sub_delegators: BalanceOf<T>,
-		) {
-			TotalCollatorStake::<T>::mutate(|total| {
-				total.collators =
-					total.collators.saturating_sub(sub_collators).saturating_add(add_collators);
-				total.delegators =
-					total.delegators.saturating_sub(sub_delegators).saturating_add(add_delegators);
-			});
+			Self::deposit_event(Event::CommissionChangeRequested(
+				collator,
+				state.commission,
+				commission,
+				effective_round,
+			));
+			Ok(())
 		}
 
-		/// Iterate over the top `MaxSelectedCandidates` many collators in terms
-		/// of cumulated stake (self + from delegators) from the [TopCandidates]
-		/// and recalculate the [TotalCollatorStake] from scratch.
+		/// Execute the commission change of a collator candidate who requested
+		/// it at least `CommissionChangeDelay` rounds ago.
 		///
-		/// NOTE: Should only be called in rare circumstances in which we cannot
-		/// guarantee a single candidate's stake has changed, e.g. on genesis or
-		/// when a collator leaves. Otherwise, please use
-		/// [update_total_stake_by].
-		fn update_total_stake() -> (u32, u32) {
-			let mut num_of_delegators = 0u32;
-			let mut collator_stake = BalanceOf::<T>::zero();
-			let mut delegator_stake = BalanceOf::<T>::zero();
-
-			let collators = Self::selected_candidates();
+		/// Increments the rewards of the collator and their delegators before
+		/// changing the commission such that the old commission applies to all
+		/// blocks authored so far.
+		///
+		/// The dispatch origin can be any signed one.
+		///
+		/// Emits `CommissionChanged`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_commission_change(
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn execute_commission_change(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let mut state =
+				CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			let request = CommissionChangeRequests::<T>::get(&collator)
+				.ok_or(Error::<T>::NoCommissionChangeRequested)?;
+			ensure!(
+				request.effective_round <= <Round<T>>::get().current,
+				Error::<T>::CannotChangeCommissionYet
+			);
 
-			// Snapshot exposure for round for weighting reward distribution
-			for account in collators.iter() {
-				let state = CandidatePool::<T>::get(account)
-					.expect("all members of TopCandidates must be candidates q.e.d");
-				num_of_delegators =
-					num_of_delegators.max(state.delegators.len().saturated_into::<u32>());
+			// *** No Fail beyond this point ***
 
-				// sum up total stake and amount of collators, delegators
-				let amount_collator = state.stake;
-				collator_stake = collator_stake.saturating_add(state.stake);
-				// safe to subtract because total >= stake
-				let amount_delegators = state.total - amount_collator;
-				delegator_stake = delegator_stake.saturating_add(amount_delegators);
-			}
+			// increment rewards with the old commission and reset reward counters
+			let num_delegators = Self::do_inc_collator_reward(&collator, state.stake);
 
-			TotalCollatorStake::<T>::mutate(|total| {
-				total.collators = collator_stake;
-				total.delegators = delegator_stake;
-			});
+			let old_commission = state.commission;
+			state.commission = request.commission;
+			CandidatePool::<T>::insert(&collator, state);
+			CommissionChangeRequests::<T>::remove(&collator);
 
-			// return number of selected candidates and the corresponding number of their
-			// delegators for post-weight correction
-			(collators.len().saturated_into(), num_of_delegators)
+			Self::deposit_event(Event::CommissionChanged(
+				collator,
+				old_commission,
+				request.commission,
+			));
+			Ok(Some(<T as Config>::WeightInfo::execute_commission_change(
+				num_delegators.saturated_into(),
+			))
+			.into())
 		}
 
-		/// Update the collator's state by removing the delegator's stake and
-		/// starting the process to unlock the delegator's staked funds as well
-		/// as incrementing their accumulated rewards.
+		/// Delegate another collator candidate in addition to the ones which
+		/// are already delegated by the origin.
 		///
-		/// This operation affects the pallet's total stake.
-		fn delegator_leaves_collator(
-			delegator: T::AccountId,
-			collator: T::AccountId,
-		) -> DispatchResult {
-			let mut state =
-				CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+		/// The account that wants to delegate cannot be part of the collator
+		/// candidates set as well.
+		///
+		/// The caller must already be a delegator. Each delegator can delegate
+		/// at most `MaxCollatorsPerDelegator` many collator candidates and
+		/// every candidate at most once.
+		///
+		/// The amount staked must be larger than the minimum required to become
+		/// a delegator as set in the pallet's configuration.
+		///
+		/// As only `MaxDelegatorsPerCollator` are allowed to delegate a given
+		/// collator, the amount staked must be larger than the lowest one in
+		/// the current set of delegator for the operation to be meaningful.
+		///
+		/// The collator's total stake as well as the pallet's total stake are
+		/// increased accordingly.
+		///
+		/// Emits `Delegation`.
+		/// Emits `DelegationReplaced` if the candidate has
+		/// `MaxDelegatorsPerCollator` many delegations but this delegator
+		/// staked more than one of the other delegators of this candidate.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate_another_candidate(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn delegate_another_candidate(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
 
-			let delegator_stake = state
-				.delegators
-				.remove(&Stake {
-					owner: delegator.clone(),
-					// amount is irrelevant for removal
-					amount: BalanceOf::<T>::one(),
-				})
-				.map(|nom| nom.amount)
-				.ok_or(Error::<T>::DelegatorNotFound)?;
+			let delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::NotYetDelegating)?;
 
-			let CandidateOf::<T, _> { stake: old_stake, total: old_total, .. } = state;
-			state.total = state.total.saturating_sub(delegator_stake);
-			let new_total = state.total;
+			// check balance
+			ensure!(
+				pallet_balances::Pallet::<T>::free_balance(acc.clone()) >=
+					delegator.total.saturating_add(amount).into(),
+				pallet_balances::Error::<T>::InsufficientBalance
+			);
 
-			// set rewards
-			Self::do_inc_delegator_reward(&delegator, delegator_stake, &collator);
+			// delegation after first
+			ensure!(
+				amount >= StakingConfiguration::<T>::get().min_delegator_stake,
+				Error::<T>::DelegationBelowMin
+			);
+			ensure!(
+				delegator.delegations.len().saturated_into::<u32>() <
+					T::MaxCollatorsPerDelegator::get(),
+				Error::<T>::MaxCollatorsPerDelegatorExceeded
+			);
+			ensure!(
+				delegator.delegation(collator.clone()).is_err(),
+				Error::<T>::AlreadyDelegatedCollator
+			);
 
-			// we don't unlock immediately
-			Self::prep_unstake(&delegator, delegator_stake, false)?;
+			let n = Self::do_delegate(acc, collator, amount, delegator)?;
 
-			// update top candidates and total amount at stake
-			if state.is_active() {
+			Ok(Some(<T as pallet::Config>::WeightInfo::delegate_another_candidate(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Revoke the delegation of a single collator candidate.
+		///
+		/// The staked funds are not unlocked immediately, but they are added to
+		/// the queue of pending unstaking, and will effectively be released
+		/// after `StakeDuration` blocks from the moment the delegation is
+		/// revoked.
+		///
+		/// If this was the last delegation of the origin, they leave the set of
+		/// delegators.
+		///
+		/// Automatically increments the accumulated rewards of the origin for
+		/// the revoked delegation.
+		///
+		/// Emits `DelegatorLeftCollator`.
+		/// Emits `DelegatorLeft` if it was the last delegation of the origin.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_delegation(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let mut delegator =
+				DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
+			let amount = delegator
+				.rm_delegation(collator.clone())
+				.map_err(|_| Error::<T>::DelegationNotFound)?;
+
+			let n = Self::delegator_leaves_collator(acc.clone(), collator)?;
+
+			// *** No Fail beyond this point ***
+
+			if delegator.delegations.is_empty() {
+				DelegatorState::<T>::remove(&acc);
+				StakeLocks::<T>::remove(&acc);
+				Self::deposit_event(Event::DelegatorLeft(acc, amount));
+			} else {
+				DelegatorState::<T>::insert(&acc, delegator);
+			}
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::revoke_delegation(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Move bonded stake of a delegation from one collator candidate to
+		/// another one without going through `StakeDuration` of unstaking.
+		///
+		/// If the origin already delegates the new candidate, the stake of
+		/// this delegation is increased. Otherwise, a new delegation is added
+		/// which requires at least `MinDelegatorStake` to be moved. Moving the
+		/// full stake revokes the delegation of the old candidate, any
+		/// remainder has to be at least `MinDelegatorStake`.
+		///
+		/// Counts towards `MaxDelegationsPerRound` to protect against reward
+		/// exploits via repeated redelegations.
+		///
+		/// Automatically increments the accumulated rewards of the origin for
+		/// both delegations.
+		///
+		/// Emits `Redelegated`.
+		/// Emits `DelegationReplaced` if the new candidate has
+		/// `MaxDelegatorsPerCollator` many delegations but this delegator
+		/// staked more than one of the other delegators of this candidate.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redelegate(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn redelegate(
+			origin: OriginFor<T>,
+			from: <T::Lookup as StaticLookup>::Source,
+			to: <T::Lookup as StaticLookup>::Source,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ValStakeZero);
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+			ensure!(from != to, Error::<T>::RedelegationToSameCollator);
+
+			let mut delegator =
+				DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
+			let mut from_state =
+				CandidatePool::<T>::get(&from).ok_or(Error::<T>::CandidateNotFound)?;
+			let mut to_state = CandidatePool::<T>::get(&to).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!to_state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+
+			// cannot redelegate if number of delegations in this round exceeds
+			// MaxDelegationsPerRound
+			let delegation_counter = Self::get_delegation_counter(&acc)?;
+
+			// remove stake from the old delegation
+			let from_stake =
+				delegator.delegation(from.clone()).map_err(|_| Error::<T>::DelegationNotFound)?;
+			let from_stake_after = from_stake.checked_sub(&amount).ok_or(Error::<T>::Underflow)?;
+			let CandidateOf::<T, _> { stake: from_old_stake, total: from_old_total, .. } =
+				from_state;
+			if from_stake_after.is_zero() {
+				delegator
+					.rm_delegation(from.clone())
+					.map_err(|_| Error::<T>::DelegationNotFound)?;
+				from_state
+					.delegators
+					.remove(&Stake { owner: acc.clone(), amount: from_stake })
+					.ok_or(Error::<T>::DelegatorNotFound)?;
+				from_state.total = from_state.total.saturating_sub(from_stake);
+			} else {
+				ensure!(
+					from_stake_after >= StakingConfiguration::<T>::get().min_delegator_stake,
+					Error::<T>::DelegationBelowMin
+				);
+				delegator
+					.dec_delegation(from.clone(), amount)
+					.map_err(|_| Error::<T>::DelegationNotFound)?;
+				from_state.dec_delegator(acc.clone(), amount);
+			}
+
+			// add stake to the new delegation
+			let CandidateOf::<T, _> { stake: to_old_stake, total: to_old_total, .. } = to_state;
+			let maybe_to_stake = delegator.delegation(to.clone()).ok();
+			if maybe_to_stake.is_some() {
+				delegator
+					.inc_delegation(to.clone(), amount)
+					.map_err(|_| Error::<T>::DelegationNotFound)?;
+				to_state.inc_delegator(acc.clone(), amount);
+			} else {
+				ensure!(
+					amount >= StakingConfiguration::<T>::get().min_delegator_stake,
+					Error::<T>::DelegationBelowMin
+				);
+				let num_delegations_pre_insertion: u32 = to_state.delegators.len().saturated_into();
+				let is_full = num_delegations_pre_insertion >=
+					StakingConfiguration::<T>::get().max_delegators_per_collator;
+				let delegation = Stake { owner: acc.clone(), amount };
+
+				// attempt to insert delegator and check for uniqueness
+				// NOTE: excess is handled below because we support replacing a delegator with
+				// fewer stake
+				let insert_delegator = is_full ||
+					to_state
+						.delegators
+						// we handle TooManyDelegators error below in do_update_delegator
+						.try_insert(delegation.clone())
+						.unwrap_or(true);
+				// should never fail but let's be safe
+				ensure!(insert_delegator, Error::<T>::DelegatorExists);
+
+				let insert_delegation = delegator
+					.add_delegation(Stake { owner: to.clone(), amount })
+					.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
+				// should never fail but let's be safe
+				ensure!(insert_delegation, Error::<T>::AlreadyDelegatedCollator);
+
+				// potentially kick a delegator with less staked amount
+				to_state = if is_full {
+					Self::do_update_delegator(delegation, to_state)?
+				} else {
+					to_state.total = to_state.total.saturating_add(amount);
+					to_state
+				};
+			}
+
+			// *** No Fail beyond this point ***
+
+			// set rewards for both delegations and reset their reward counters
+			Self::do_inc_delegator_reward(&acc, from_stake, &from, from_state.commission);
+			if from_stake_after.is_zero() {
+				DelegationRewardCount::<T>::remove(&acc, &from);
+				ExtraRewardCheckpoints::<T>::remove(&acc, &from);
+			}
+			if let Some(to_stake) = maybe_to_stake {
+				Self::do_inc_delegator_reward(&acc, to_stake, &to, to_state.commission);
+			} else {
+				DelegationRewardCount::<T>::insert(&acc, &to, RewardCount::<T>::get(&to));
+				ExtraRewardCheckpoints::<T>::insert(&acc, &to, ExtraRewardsPerStake::<T>::get(&to));
+			}
+
+			// update top candidates and total amount at stake of the old candidate before
+			// touching the new one because both might displace each other
+			let n_from = if from_state.is_active() {
 				Self::update_top_candidates(
-					collator.clone(),
-					old_stake,
+					from.clone(),
+					from_old_stake,
 					// safe because total >= stake
-					old_total - old_stake,
-					state.stake,
-					state.total - state.stake,
+					from_old_total - from_old_stake,
+					from_state.stake,
+					from_state.total - from_state.stake,
+				)
+			} else {
+				0u32
+			};
+			CandidatePool::<T>::insert(&from, from_state);
+
+			let n_to = if to_state.is_active() {
+				Self::update_top_candidates(
+					to.clone(),
+					to_old_stake,
+					// safe because total >= stake
+					to_old_total - to_old_stake,
+					to_state.stake,
+					to_state.total - to_state.stake,
+				)
+			} else {
+				0u32
+			};
+			CandidatePool::<T>::insert(&to, to_state);
+
+			DelegatorState::<T>::insert(&acc, delegator);
+			<LastDelegation<T>>::insert(&acc, delegation_counter);
+
+			if from_stake_after.is_zero() {
+				T::OnStakingUpdate::on_delegation_removed(&acc, &from, from_stake);
+			} else {
+				T::OnStakingUpdate::on_delegation_reduced(
+					&acc,
+					&from,
+					from_stake,
+					from_stake_after,
 				);
 			}
-			CandidatePool::<T>::insert(&collator, state);
+			if let Some(to_stake) = maybe_to_stake {
+				T::OnStakingUpdate::on_delegation_increased(
+					&acc,
+					&to,
+					to_stake,
+					to_stake.saturating_add(amount),
+				);
+			} else {
+				T::OnStakingUpdate::on_delegation_created(&acc, &to, amount);
+			}
 
-			Self::deposit_event(Event::DelegatorLeftCollator(
-				delegator,
-				collator,
-				delegator_stake,
-				new_total,
-			));
+			Self::deposit_event(Event::Redelegated(acc, from, to, amount));
+			Ok(Some(<T as pallet::Config>::WeightInfo::redelegate(
+				n_from.max(n_to),
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Set the share of newly accumulated rewards which is restaked
+		/// automatically for the origin.
+		///
+		/// Rewards of a collator candidate are added to their own stake,
+		/// rewards of a delegator to the delegation they accumulated from. The
+		/// restaked amount is capped such that the resulting stake does not
+		/// exceed `MaxCollatorCandidateStake`. Compounding happens whenever the
+		/// rewards are incremented, e.g. via `increment_collator_rewards`,
+		/// `increment_delegator_rewards` or when changing the stake.
+		///
+		/// A share of zero disables auto-compounding and can always be set.
+		/// Otherwise, the origin must be a collator candidate or a delegator.
+		///
+		/// Emits `AutoCompoundSet`.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(origin: OriginFor<T>, percentage: Perquintill) -> DispatchResult {
+			let acc = ensure_signed(origin)?;
+
+			if percentage.is_zero() {
+				AutoCompound::<T>::remove(&acc);
+			} else {
+				ensure!(
+					Self::is_delegator(&acc) || CandidatePool::<T>::contains_key(&acc),
+					Error::<T>::NotStaking
+				);
+				AutoCompound::<T>::insert(&acc, percentage);
+			}
+
+			Self::deposit_event(Event::AutoCompoundSet(acc, percentage));
 			Ok(())
 		}
 
-		/// Return the best `MaxSelectedCandidates` many candidates.
+		/// Set the liveness requirements of selected collators or disable the
+		/// liveness checks by passing `None`.
 		///
-		/// In case a collator from last round was replaced by a candidate with
-		/// the same total stake during sorting, we revert this swap to
-		/// prioritize collators over candidates.
-		pub fn selected_candidates() -> BoundedVec<T::AccountId, T::MaxTopCandidates> {
-			let candidates = TopCandidates::<T>::get();
+		/// At the end of each session, every collator is expected to author
+		/// at least `min_authored` of the average number of blocks authored
+		/// per collator. Collators which miss this in `max_missed_rounds`
+		/// rounds in a row are penalized.
+		///
+		/// Resets the number of missed rounds of all collators.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `LivenessConfigSet`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_liveness_config(
+			T::MaxTopCandidates::get()
+		))]
+		pub fn set_liveness_config(
+			origin: OriginFor<T>,
+			config: Option<LivenessConfig>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
 
-			// Should never fail since WASM usize are 32bits and native are either 32 or 64
-			let top_n = MaxSelectedCandidates::<T>::get().saturated_into::<usize>();
+			let cleared = MissedRounds::<T>::clear(u32::MAX, None).unique;
+			LivenessConfiguration::<T>::set(config);
 
-			log::trace!("{} Candidates for {} Collator seats", candidates.len(), top_n);
+			Self::deposit_event(Event::LivenessConfigSet(config));
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_liveness_config(cleared)).into())
+		}
 
-			// Choose the top MaxSelectedCandidates qualified candidates
-			let collators = candidates
-				.into_iter()
-				.take(top_n)
-				.filter(|x| x.amount >= T::MinCollatorStake::get())
-				.map(|x| x.owner)
-				.collect::<Vec<T::AccountId>>();
+		/// Go online again after having been marked as idle for missing too
+		/// many rounds. On success, adds back the candidate to the
+		/// TopCandidates and updates the collators.
+		///
+		/// The dispatch origin must be an idle collator candidate.
+		///
+		/// Emits `CollatorWentOnline`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::go_online(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get(),
+		))]
+		pub fn go_online(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let candidate = ensure_signed(origin)?;
+			let mut state =
+				CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(state.is_idle(), Error::<T>::NotIdle);
 
-			collators.try_into().expect("Did not extend Collators q.e.d.")
+			state.go_online();
+
+			// *** No Fail beyond this point ***
+
+			let n = Self::update_top_candidates(
+				candidate.clone(),
+				state.stake,
+				// safe because total >= stake
+				state.total - state.stake,
+				state.stake,
+				state.total - state.stake,
+			);
+
+			// update candidates for next round
+			CandidatePool::<T>::insert(&candidate, state);
+			MissedRounds::<T>::remove(&candidate);
+
+			Self::deposit_event(Event::CollatorWentOnline(candidate));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::go_online(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
 		}
 
-		/// Attempts to add the stake to the set of delegators of a collator
-		/// which already reached its maximum size by removing an already
-		/// existing delegator with less staked value. If the given staked
-		/// amount is at most the minimum staked value of the original delegator
-		/// set, an error is returned.
+		/// Set the staking parameters which can be changed by governance.
 		///
-		/// Sets rewards for the removed delegator.
+		/// Existing stakes which fall out of the new bounds are not changed:
+		/// * Candidates whose total stake is below the minimum collator stake
+		///   are not selected as collators.
+		/// * Delegations below the minimum delegator stake can be increased or
+		///   revoked but not reduced.
+		/// * Collators with more delegators than allowed keep them, but new
+		///   delegations replace the delegator with the lowest stake.
+		/// * Scheduled exits and unstaking requests keep their delay.
 		///
-		/// Returns a tuple which contains the updated candidate state as well
-		/// as the potentially replaced delegation which will be used later when
-		/// updating the storage of the replaced delegator.
+		/// The dispatch origin must be Root.
 		///
-		/// Emits `DelegationReplaced` if the stake exceeds one of the current
-		/// delegations.
-		#[allow(clippy::type_complexity)]
-		fn do_update_delegator(
-			stake: Stake<T::AccountId, BalanceOf<T>>,
-			mut state: Candidate<T::AccountId, BalanceOf<T>, T::MaxDelegatorsPerCollator>,
-		) -> Result<CandidateOf<T, T::MaxDelegatorsPerCollator>, DispatchError> {
-			// attempt to replace the last element of the set
-			let stake_to_remove =
-				state.delegators.try_insert_replace(stake.clone()).map_err(|err_too_many| {
-					if err_too_many {
-						Error::<T>::TooManyDelegators
-					} else {
-						// should never occur because we previously check this case, but let's be sure
-						Error::<T>::AlreadyDelegating
-					}
-				})?;
+		/// Emits `StakingConfigSet`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_staking_config())]
+		pub fn set_staking_config(
+			origin: OriginFor<T>,
+			config: StakingConfigOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				config.min_collator_stake >= T::MinCollatorCandidateStake::get(),
+				Error::<T>::CannotSetBelowMin
+			);
+			ensure!(
+				config.min_collator_stake <= MaxCollatorCandidateStake::<T>::get(),
+				Error::<T>::CannotSetAboveMax
+			);
+			ensure!(!config.min_delegator_stake.is_zero(), Error::<T>::CannotSetBelowMin);
+			ensure!(!config.max_delegators_per_collator.is_zero(), Error::<T>::CannotSetBelowMin);
+			ensure!(
+				config.max_delegators_per_collator <= T::MaxDelegatorsPerCollator::get(),
+				Error::<T>::CannotSetAboveMax
+			);
+			// collators have to keep collating until the session they are still selected
+			// for has ended
+			ensure!(!config.exit_queue_delay.is_zero(), Error::<T>::CannotSetBelowMin);
+			ensure!(
+				!config.stake_duration.is_zero() &&
+					config.stake_duration <= T::MaxStakeDuration::get(),
+				Error::<T>::InvalidStakeDuration
+			);
+			ensure!(
+				config.network_reward_rate <= T::MaxNetworkRewardRate::get(),
+				Error::<T>::NetworkRewardRateTooHigh
+			);
+
+			// *** No Fail beyond this point ***
+
+			StakingConfiguration::<T>::put(config);
+
+			Self::deposit_event(Event::StakingConfigSet(config));
+			Ok(())
+		}
+
+		/// Claim block authoring rewards on behalf of the target account.
+		///
+		/// Same as `claim_rewards` but for the target instead of the origin.
+		/// The rewards are always paid to the target's reward destination.
+		///
+		/// The dispatch origin can be any signed one.
+		///
+		/// Emits `Rewarded`, `RewardedTo` or `RewardsDonated` depending on the
+		/// reward destination.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
+		pub fn claim_rewards_for(
+			origin: OriginFor<T>,
+			target: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_claim_rewards(&target)
+		}
+
+		/// Actively increment the rewards of the target collator and their
+		/// delegators.
+		///
+		/// Same as `increment_collator_rewards` but for the target instead of
+		/// the origin.
+		///
+		/// The dispatch origin can be any signed one.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::increment_collator_rewards(T::MaxDelegatorsPerCollator::get()))]
+		pub fn increment_collator_rewards_for(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let num_delegators = Self::do_increment_collator_rewards(&collator)?;
+			Ok(Some(<T as Config>::WeightInfo::increment_collator_rewards(
+				num_delegators.saturated_into(),
+			))
+			.into())
+		}
+
+		/// Actively increment the rewards of the target delegator for all
+		/// their delegations.
+		///
+		/// Same as `increment_delegator_rewards` but for the target instead of
+		/// the origin.
+		///
+		/// The dispatch origin can be any signed one.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::increment_delegator_rewards()
+			.saturating_mul(T::MaxCollatorsPerDelegator::get().into()))]
+		pub fn increment_delegator_rewards_for(
+			origin: OriginFor<T>,
+			delegator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let delegator = T::Lookup::lookup(delegator)?;
+			let num_delegations = Self::do_increment_delegator_rewards(&delegator)?;
+			Ok(Some(
+				<T as Config>::WeightInfo::increment_delegator_rewards()
+					.saturating_mul(num_delegations.into()),
+			)
+			.into())
+		}
+
+		/// Increment and claim the rewards of a batch of collators and
+		/// delegators.
+		///
+		/// For each account, the rewards are incremented if the account is a
+		/// collator candidate or a delegator and all accumulated rewards are
+		/// paid to the account's reward destination afterwards. Accounts
+		/// without any rewards are skipped.
+		///
+		/// The dispatch origin can be any signed one.
+		///
+		/// Weight: O(N * (D + C)) where N is the number of accounts bounded
+		/// by `MaxPayoutStakers`, D the number of delegators of a collator and
+		/// C the number of delegations of a delegator.
+		///
+		/// Emits `Rewarded` for each account which received rewards.
+		/// Emits `PayoutFailed` for each account whose rewards could not be
+		/// claimed. These accounts are skipped without failing the batch.
+		#[pallet::call_index(33)]
+		#[pallet::weight(Pallet::<T>::payout_weight(
+			T::MaxDelegatorsPerCollator::get(),
+			T::MaxCollatorsPerDelegator::get(),
+			1,
+		).saturating_mul(accounts.len() as u64))]
+		pub fn payout_stakers(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<T::AccountId, T::MaxPayoutStakers>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let mut weight = Weight::zero();
+			for acc in accounts.iter() {
+				let mut num_delegators = 0u32;
+				if let Some(state) = CandidatePool::<T>::get(acc) {
+					if !RewardCount::<T>::get(acc).is_zero() {
+						num_delegators =
+							Self::do_inc_collator_reward_and_compound(acc, state.stake)
+								.saturated_into();
+					}
+				}
+				let num_delegations = match DelegatorState::<T>::get(acc) {
+					Some(state) => {
+						let num_delegations = state.delegations.len().saturated_into();
+						Self::do_inc_delegations_and_compound(acc, state.delegations.into_iter());
+						num_delegations
+					},
+					None => 0u32,
+				};
+				let num_claims = if Rewards::<T>::get(acc).is_zero() {
+					0u32
+				} else {
+					// a failing claim must not prevent the payout of the other accounts
+					if let Err(err) = with_storage_layer(|| Self::do_claim_rewards(acc)) {
+						Self::deposit_event(Event::PayoutFailed(acc.clone(), err));
+					}
+					1u32
+				};
+				weight = weight.saturating_add(Self::payout_weight(
+					num_delegators,
+					num_delegations,
+					num_claims,
+				));
+			}
+
+			Ok(Some(weight).into())
+		}
+
+		/// Set the destination to which the rewards of the origin are paid
+		/// when claiming them.
+		///
+		/// Choosing `Staked` restakes all newly accumulated rewards whenever
+		/// they are incremented, regardless of the auto-compounding share set
+		/// via `set_auto_compound`.
+		///
+		/// Paying the rewards into the own account can always be set.
+		/// Otherwise, the origin must be a collator candidate or a delegator.
+		///
+		/// Emits `RewardDestinationSet`.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_reward_destination())]
+		pub fn set_reward_destination(
+			origin: OriginFor<T>,
+			destination: RewardDestinationOf<T>,
+		) -> DispatchResult {
+			let acc = ensure_signed(origin)?;
+
+			if destination == RewardDestination::Own {
+				RewardDestinations::<T>::remove(&acc);
+			} else {
+				ensure!(
+					Self::is_delegator(&acc) || CandidatePool::<T>::contains_key(&acc),
+					Error::<T>::NotStaking
+				);
+				RewardDestinations::<T>::insert(&acc, destination.clone());
+			}
+
+			Self::deposit_event(Event::RewardDestinationSet(acc, destination));
+			Ok(())
+		}
+
+		/// Set the collators which are always selected for the upcoming
+		/// rounds regardless of their stake.
+		///
+		/// The remaining `MaxSelectedCandidates` seats are filled with the
+		/// candidates with the highest total stake. Invulnerables do not
+		/// receive block authoring rewards, neither do their delegators if
+		/// they are also collator candidates. They are not checked for
+		/// liveness.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `InvulnerablesSet`.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_invulnerables(
+			T::MinRequiredCollators::get()
+		))]
+		pub fn set_invulnerables(
+			origin: OriginFor<T>,
+			invulnerables: BoundedVec<T::AccountId, T::MinRequiredCollators>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let n: u32 = invulnerables.len().saturated_into();
+			for (i, acc) in invulnerables.iter().enumerate() {
+				ensure!(!invulnerables[..i].contains(acc), Error::<T>::DuplicateInvulnerable);
+			}
+
+			Invulnerables::<T>::put(&invulnerables);
+
+			Self::deposit_event(Event::InvulnerablesSet(invulnerables.into_inner()));
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_invulnerables(n)).into())
+		}
+
+		/// Set the amount of extra rewards in the given currency which is
+		/// distributed at the end of each round. Setting the amount to zero
+		/// stops the distribution in this currency.
+		///
+		/// The rewards are paid out of the pot which needs to be funded
+		/// separately. They are split among the collators which authored
+		/// blocks in the round pro rata to their total stake and are shared
+		/// with their delegators pro rata to their stake. Invulnerables and
+		/// their delegators do not receive extra rewards.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `ExtraRewardRateSet`.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_extra_reward_rate())]
+		pub fn set_extra_reward_rate(
+			origin: OriginFor<T>,
+			currency_id: ExtraRewardCurrencyIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ExtraRewardRates::<T>::try_mutate(|rates| -> DispatchResult {
+				let existing = rates.iter().position(|(id, _)| *id == currency_id);
+				match existing {
+					Some(i) if amount.is_zero() => {
+						rates.remove(i);
+					},
+					Some(i) => rates[i].1 = amount,
+					None if amount.is_zero() => (),
+					None => rates
+						.try_push((currency_id, amount))
+						.map_err(|_| Error::<T>::TooManyExtraRewardCurrencies)?,
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ExtraRewardRateSet(currency_id, amount));
+			Ok(())
+		}
+
+		/// Claim the extra rewards of the origin in the given currency.
+		///
+		/// Increments the extra rewards of all stakes of the origin before
+		/// paying them out of the pot into the reward destination of the
+		/// origin. Extra rewards are never restaked.
+		///
+		/// The dispatch origin must be a signed account.
+		///
+		/// Emits `ExtraRewardsClaimed`.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_extra_rewards(
+			T::MaxCollatorsPerDelegator::get()
+		))]
+		pub fn claim_extra_rewards(
+			origin: OriginFor<T>,
+			currency_id: ExtraRewardCurrencyIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+
+			let n = Self::do_inc_all_extra_rewards(&acc);
+			let rewards = ExtraRewards::<T>::get(&acc, currency_id);
+			ensure!(!rewards.is_zero(), Error::<T>::RewardsNotFound);
+
+			let destination = match RewardDestinations::<T>::get(&acc) {
+				RewardDestination::Account(destination) => destination,
+				RewardDestination::Treasury => T::TreasuryAccount::get(),
+				RewardDestination::Own | RewardDestination::Staked => acc.clone(),
+			};
+			T::ExtraRewardCurrency::transfer(
+				currency_id,
+				&Self::extra_rewards_pot(),
+				&destination,
+				rewards,
+			)
+			.map_err(|_| Error::<T>::ExtraRewardTransferFailed)?;
+
+			ExtraRewards::<T>::remove(&acc, currency_id);
+			ExtraRewardsAllocated::<T>::mutate(currency_id, |allocated| {
+				*allocated = allocated.saturating_sub(rewards);
+			});
+
+			Self::deposit_event(Event::ExtraRewardsClaimed(acc, destination, currency_id, rewards));
+			Ok(Some(<T as pallet::Config>::WeightInfo::claim_extra_rewards(n)).into())
+		}
+
+		/// Cancel (part of) the pending unstaking request which can be
+		/// unlocked at `block` and stake the funds again right away.
+		///
+		/// Collator candidates restake the funds into their own stake by
+		/// omitting `collator`. Delegators restake them into their delegation
+		/// of `collator`. The funds remain locked all along, thus the lock
+		/// does not change.
+		///
+		/// The dispatch origin must be a collator candidate or a delegator.
+		///
+		/// Emits `UnstakingCancelled`.
+		/// Emits `CollatorStakedMore` or `DelegatorStakedMore`.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::candidate_stake_more(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get(),
+			T::MaxUnstakeRequests::get().saturated_into::<u32>()
+		)
+		.max(<T as pallet::Config>::WeightInfo::delegator_stake_more(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get(),
+			T::MaxUnstakeRequests::get().saturated_into::<u32>()
+		)))]
+		pub fn cancel_unstaking(
+			origin: OriginFor<T>,
+			block: BlockNumberFor<T>,
+			amount: BalanceOf<T>,
+			collator: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let collator = collator.map(T::Lookup::lookup).transpose()?;
+			let is_delegation = collator.is_some();
+
+			let (n, unstaking_len) = Self::do_stake_more(&acc, collator, amount, Some(block))?;
+
+			Self::deposit_event(Event::UnstakingCancelled(acc, block, amount));
+			let weight = if is_delegation {
+				<T as pallet::Config>::WeightInfo::delegator_stake_more(
+					n,
+					T::MaxDelegatorsPerCollator::get(),
+					unstaking_len,
+				)
+			} else {
+				<T as pallet::Config>::WeightInfo::candidate_stake_more(
+					n,
+					T::MaxDelegatorsPerCollator::get(),
+					unstaking_len,
+				)
+			};
+			Ok(Some(weight).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check whether an account is currently delegating.
+		pub fn is_delegator(acc: &T::AccountId) -> bool {
+			DelegatorState::<T>::get(acc).is_some()
+		}
+
+		/// The account of the pot which holds the extra rewards.
+		pub fn extra_rewards_pot() -> T::AccountId {
+			T::ExtraRewardsPotId::get().into_account_truncating()
+		}
+
+		/// Check whether an account is currently a collator candidate and
+		/// whether their state is CollatorStatus::Active.
+		///
+		/// Returns Some(is_active) if the account is a candidate, else None.
+		pub fn is_active_candidate(acc: &T::AccountId) -> Option<bool> {
+			if let Some(state) = CandidatePool::<T>::get(acc) {
+				Some(state.status == CandidateStatus::Active)
+			} else {
+				None
+			}
+		}
+
+		/// Update the top candidates and total amount at stake after mutating
+		/// an active candidate's stake.
+		///
+		/// NOTE: It is assumed that the calling context checks whether the
+		/// collator candidate is currently active before calling this function.
+		fn update_top_candidates(
+			candidate: T::AccountId,
+			old_self: BalanceOf<T>,
+			old_delegators: BalanceOf<T>,
+			new_self: BalanceOf<T>,
+			new_delegators: BalanceOf<T>,
+		) -> u32 {
+			let mut top_candidates = TopCandidates::<T>::get();
+			let num_top_candidates: u32 = top_candidates.len().saturated_into();
+			let old_stake =
+				Stake { owner: candidate.clone(), amount: old_self.saturating_add(old_delegators) };
+			let new_stake =
+				Stake { owner: candidate.clone(), amount: new_self.saturating_add(new_delegators) };
+
+			// update TopCandidates set
+			let maybe_top_candidate_update = if let Ok(i) = top_candidates.linear_search(&old_stake)
+			{
+				// case 1: candidate is member of TopCandidates with old stake
+				top_candidates.mutate(|vec| {
+					if let Some(stake) = vec.get_mut(i) {
+						stake.amount = new_stake.amount;
+					}
+				});
+				Some((Some(i), top_candidates))
+			} else if top_candidates.try_insert_replace(new_stake.clone()).is_ok() {
+				// case 2: candidate ascends into TopCandidates with new stake
+				// and might replace another candidate if TopCandidates is full
+				Self::deposit_event(Event::EnteredTopCandidates(candidate));
+				Some((None, top_candidates))
+			} else {
+				// case 3: candidate neither was nor will be member of TopCandidates
+				None
+			};
+
+			// update storage for TotalCollatorStake and TopCandidates
+			if let Some((maybe_old_idx, top_candidates)) = maybe_top_candidate_update {
+				let max_selected_candidates =
+					MaxSelectedCandidates::<T>::get().saturated_into::<usize>();
+				let was_collating =
+					maybe_old_idx.map(|i| i < max_selected_candidates).unwrap_or(false);
+				let is_collating = top_candidates
+					.linear_search(&new_stake)
+					.map(|i| i < max_selected_candidates)
+					.unwrap_or(false);
+
+				// update TopCollatorStake storage iff candidate was or will be a collator
+				match (was_collating, is_collating) {
+					(true, true) => {
+						Self::update_total_stake_by(
+							new_self,
+							new_delegators,
+							old_self,
+							old_delegators,
+						);
+					},
+					(true, false) => {
+						// candidate left the collator set because they staked less and have been
+						// replaced by the next candidate in the queue at position
+						// min(max_selected_candidates, top_candidates) - 1 in TopCandidates
+						let new_col_idx =
+							max_selected_candidates.min(top_candidates.len()).saturating_sub(1);
+
+						// get displacer
+						let (add_collators, add_delegators) =
+							Self::get_top_candidate_stake_at(&top_candidates, new_col_idx)
+								// shouldn't be possible to fail, but we handle it gracefully
+								.unwrap_or((new_self, new_delegators));
+						Self::update_total_stake_by(
+							add_collators,
+							add_delegators,
+							old_self,
+							old_delegators,
+						);
+					},
+					(false, true) => {
+						// candidate pushed out the least staked collator which is now at position
+						// min(max_selected_top_candidates, top_candidates - 1) in TopCandidates
+						let old_col_idx =
+							max_selected_candidates.min(top_candidates.len().saturating_sub(1));
+
+						// get amount to subtract from TotalCollatorStake
+						let (drop_self, drop_delegators) =
+							Self::get_top_candidate_stake_at(&top_candidates, old_col_idx)
+								// default to zero if candidate DNE, e.g. TopCandidates is not full
+								.unwrap_or((BalanceOf::<T>::zero(), BalanceOf::<T>::zero()));
+						Self::update_total_stake_by(
+							new_self,
+							new_delegators,
+							drop_self,
+							drop_delegators,
+						);
+					},
+					_ => {},
+				}
+
+				// update TopCandidates storage
+				TopCandidates::<T>::put(top_candidates);
+			}
+
+			num_top_candidates
+		}
+
+		/// Retrieve the staked amounts (self, sum of delegators) of member of
+		/// [TopCandidates] at the given index, if it exists.
+		fn get_top_candidate_stake_at(
+			top_candidates: &OrderedSet<StakeOf<T>, T::MaxTopCandidates>,
+			index: usize,
+		) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+			top_candidates
+				.get(index)
+				.and_then(|stake| CandidatePool::<T>::get(&stake.owner))
+				// SAFETY: the total is always more than the stake
+				.map(|state| (state.stake, state.total - state.stake))
+		}
+
+		/// Mutate the [TotalCollatorStake] by both incrementing and decreasing
+		/// it by the provided values.
+		fn update_total_stake_by(
+			add_collators: BalanceOf<T>,
+			add_delegators: BalanceOf<T>,
+			sub_collators: BalanceOf<T>,
+			sub_delegators: BalanceOf<T>,
+		) {
+			TotalCollatorStake::<T>::mutate(|total| {
+				total.collators =
+					total.collators.saturating_sub(sub_collators).saturating_add(add_collators);
+				total.delegators =
+					total.delegators.saturating_sub(sub_delegators).saturating_add(add_delegators);
+			});
+		}
+
+		/// Iterate over the top `MaxSelectedCandidates` many collators in terms
+		/// of cumulated stake (self + from delegators) from the [TopCandidates]
+		/// and recalculate the [TotalCollatorStake] from scratch.
+		///
+		/// NOTE: Should only be called in rare circumstances in which we cannot
+		/// guarantee a single candidate's stake has changed, e.g. on genesis or
+		/// when a collator leaves. Otherwise, please use
+		/// [update_total_stake_by].
+		fn update_total_stake() -> (u32, u32) {
+			let mut num_of_delegators = 0u32;
+			let mut collator_stake = BalanceOf::<T>::zero();
+			let mut delegator_stake = BalanceOf::<T>::zero();
+
+			let collators = Self::selected_candidates();
+
+			// Snapshot exposure for round for weighting reward distribution
+			for account in collators.iter() {
+				let state = CandidatePool::<T>::get(account)
+					.expect("all members of TopCandidates must be candidates q.e.d");
+				num_of_delegators =
+					num_of_delegators.max(state.delegators.len().saturated_into::<u32>());
+
+				// sum up total stake and amount of collators, delegators
+				let amount_collator = state.stake;
+				collator_stake = collator_stake.saturating_add(state.stake);
+				// safe to subtract because total >= stake
+				let amount_delegators = state.total - amount_collator;
+				delegator_stake = delegator_stake.saturating_add(amount_delegators);
+			}
+
+			TotalCollatorStake::<T>::mutate(|total| {
+				total.collators = collator_stake;
+				total.delegators = delegator_stake;
+			});
+
+			// return number of selected candidates and the corresponding number of their
+			// delegators for post-weight correction
+			(collators.len().saturated_into(), num_of_delegators)
+		}
+
+		/// Add a delegation of the given amount from the delegator to the
+		/// collator candidate and lock the delegated funds.
+		///
+		/// The delegator state is expected to not yet include the new
+		/// delegation.
+		///
+		/// Returns the number of top candidates which were iterated over for
+		/// post-weight correction.
+		///
+		/// Emits `Delegation`.
+		/// Emits `DelegationReplaced` if the candidate has
+		/// `MaxDelegatorsPerCollator` many delegations but this delegator
+		/// staked more than one of the other delegators of this candidate.
+		fn do_delegate(
+			acc: T::AccountId,
+			collator: T::AccountId,
+			amount: BalanceOf<T>,
+			mut delegator: Delegator<T::AccountId, BalanceOf<T>, T::MaxCollatorsPerDelegator>,
+		) -> Result<u32, DispatchError> {
+			// cannot delegate if number of delegations in this round exceeds
+			// MaxDelegationsPerRound
+			let delegation_counter = Self::get_delegation_counter(&acc)?;
+
+			// prepare update of collator state
+			let mut state =
+				CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			let num_delegations_pre_insertion: u32 = state.delegators.len().saturated_into();
+			let is_full = num_delegations_pre_insertion >=
+				StakingConfiguration::<T>::get().max_delegators_per_collator;
+
+			ensure!(!state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			let delegation = Stake { owner: acc.clone(), amount };
+
+			// attempt to insert delegator and check for uniqueness
+			// NOTE: excess is handled below because we support replacing a delegator with
+			// fewer stake
+			let insert_delegator = is_full ||
+				state
+					.delegators
+					// we handle TooManyDelegators error below in do_update_delegator
+					.try_insert(delegation.clone())
+					.unwrap_or(true);
+			// should never fail but let's be safe
+			ensure!(insert_delegator, Error::<T>::DelegatorExists);
+
+			// attempt to insert the delegation into the delegator state
+			let insert_delegation = delegator
+				.add_delegation(Stake { owner: collator.clone(), amount })
+				.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
+			// should never fail but let's be safe
+			ensure!(insert_delegation, Error::<T>::AlreadyDelegatedCollator);
+
+			let CandidateOf::<T, _> { stake: old_stake, total: old_total, .. } = state;
+
+			// update state and potentially prepare kicking a delegator with less staked
+			// amount (includes setting rewards for kicked delegator)
+			let state = if is_full {
+				Self::do_update_delegator(delegation, state)?
+			} else {
+				state.total = state.total.saturating_add(amount);
+				state
+			};
+			let new_total = state.total;
+
+			// *** No Fail except during increase_lock beyond this point ***
+
+			// lock stake
+			Self::increase_lock(&acc, delegator.total, amount, None)?;
+
+			// update top candidates and total amount at stake
+			let n = if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					state.stake,
+					state.total - state.stake,
+				)
+			} else {
+				0u32
+			};
+
+			// update states
+			CandidatePool::<T>::insert(&collator, state);
+			DelegatorState::<T>::insert(&acc, delegator);
+			<LastDelegation<T>>::insert(&acc, delegation_counter);
+
+			// initiate reward counter to match the current state of the candidate
+			DelegationRewardCount::<T>::insert(&acc, &collator, RewardCount::<T>::get(&collator));
+			ExtraRewardCheckpoints::<T>::insert(
+				&acc,
+				&collator,
+				ExtraRewardsPerStake::<T>::get(&collator),
+			);
+
+			T::OnStakingUpdate::on_delegation_created(&acc, &collator, amount);
+			Self::deposit_event(Event::Delegation(acc, amount, collator, new_total));
+			Ok(n)
+		}
+
+		/// Update the collator's state by removing the delegator's stake and
+		/// starting the process to unlock the delegator's staked funds as well
+		/// as incrementing their accumulated rewards.
+		///
+		/// This operation affects the pallet's total stake.
+		///
+		/// Returns the number of top candidates which were iterated over for
+		/// post-weight correction.
+		fn delegator_leaves_collator(
+			delegator: T::AccountId,
+			collator: T::AccountId,
+		) -> Result<u32, DispatchError> {
+			let mut state =
+				CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+
+			let delegator_stake = state
+				.delegators
+				.remove(&Stake {
+					owner: delegator.clone(),
+					// amount is irrelevant for removal
+					amount: BalanceOf::<T>::one(),
+				})
+				.map(|nom| nom.amount)
+				.ok_or(Error::<T>::DelegatorNotFound)?;
+
+			let CandidateOf::<T, _> { stake: old_stake, total: old_total, .. } = state;
+			state.total = state.total.saturating_sub(delegator_stake);
+			let new_total = state.total;
+
+			// set rewards
+			Self::do_inc_delegator_reward(&delegator, delegator_stake, &collator, state.commission);
+			DelegationRewardCount::<T>::remove(&delegator, &collator);
+			ExtraRewardCheckpoints::<T>::remove(&delegator, &collator);
+
+			// we don't unlock immediately
+			Self::prep_unstake(&delegator, delegator_stake, false)?;
+
+			// update top candidates and total amount at stake
+			let n = if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					state.stake,
+					state.total - state.stake,
+				)
+			} else {
+				0u32
+			};
+			CandidatePool::<T>::insert(&collator, state);
+
+			T::OnStakingUpdate::on_delegation_removed(&delegator, &collator, delegator_stake);
+			Self::deposit_event(Event::DelegatorLeftCollator(
+				delegator,
+				collator,
+				delegator_stake,
+				new_total,
+			));
+			Ok(n)
+		}
+
+		/// Return the invulnerables and the best candidates for the remaining
+		/// of the `MaxSelectedCandidates` many seats.
+		///
+		/// In case a collator from last round was replaced by a candidate with
+		/// the same total stake during sorting, we revert this swap to
+		/// prioritize collators over candidates.
+		pub fn selected_candidates() -> BoundedVec<T::AccountId, T::MaxTopCandidates> {
+			let candidates = TopCandidates::<T>::get();
+			let invulnerables = Invulnerables::<T>::get();
+
+			// Should never fail since WASM usize are 32bits and native are either 32 or 64
+			let top_n = MaxSelectedCandidates::<T>::get().saturated_into::<usize>();
+			let min_collator_stake = StakingConfiguration::<T>::get().min_collator_stake;
+
+			log::trace!(
+				"{} Candidates and {} Invulnerables for {} Collator seats",
+				candidates.len(),
+				invulnerables.len(),
+				top_n
+			);
+
+			// Choose the top qualified candidates for the seats left by the invulnerables
+			let collators = invulnerables
+				.iter()
+				.cloned()
+				.chain(
+					candidates
+						.into_iter()
+						.filter(|x| !invulnerables.contains(&x.owner))
+						.take(top_n.saturating_sub(invulnerables.len()))
+						.filter(|x| x.amount >= min_collator_stake)
+						.map(|x| x.owner),
+				)
+				.collect::<Vec<T::AccountId>>();
+
+			collators.try_into().expect("Did not extend Collators q.e.d.")
+		}
+
+		/// Attempts to add the stake to the set of delegators of a collator
+		/// which already reached its maximum size by removing an already
+		/// existing delegator with less staked value. If the given staked
+		/// amount is at most the minimum staked value of the original delegator
+		/// set, an error is returned.
+		///
+		/// Sets rewards for the removed delegator.
+		///
+		/// Returns a tuple which contains the updated candidate state as well
+		/// as the potentially replaced delegation which will be used later when
+		/// updating the storage of the replaced delegator.
+		///
+		/// Emits `DelegationReplaced` if the stake exceeds one of the current
+		/// delegations.
+		#[allow(clippy::type_complexity)]
+		fn do_update_delegator(
+			stake: Stake<T::AccountId, BalanceOf<T>>,
+			mut state: Candidate<T::AccountId, BalanceOf<T>, T::MaxDelegatorsPerCollator>,
+		) -> Result<CandidateOf<T, T::MaxDelegatorsPerCollator>, DispatchError> {
+			// attempt to replace the last element of the set
+			let stake_to_remove = state
+				.delegators
+				.try_insert_replace_with_limit(
+					stake.clone(),
+					StakingConfiguration::<T>::get().max_delegators_per_collator,
+				)
+				.map_err(|err_too_many| {
+					if err_too_many {
+						Error::<T>::TooManyDelegators
+					} else {
+						// should never occur because we previously check this case, but let's be sure
+						Error::<T>::AlreadyDelegating
+					}
+				})?;
+
+			state.total = state.total.saturating_add(stake.amount);
+
+			if let Some(stake_to_remove) = stake_to_remove {
+				// update total stake
+				state.total = state.total.saturating_sub(stake_to_remove.amount);
+
+				// set rewards for kicked delegator
+				Self::do_inc_delegator_reward(
+					&stake_to_remove.owner,
+					stake_to_remove.amount,
+					&state.id,
+					state.commission,
+				);
+				// prepare unstaking for kicked delegator
+				Self::prep_unstake(&stake_to_remove.owner, stake_to_remove.amount, true)?;
+				// remove delegation from the state of the kicked delegator
+				DelegatorState::<T>::mutate_exists(&stake_to_remove.owner, |maybe_delegator| {
+					if let Some(delegator) = maybe_delegator {
+						let _ = delegator.rm_delegation(state.id.clone());
+						if delegator.delegations.is_empty() {
+							*maybe_delegator = None;
+							StakeLocks::<T>::remove(&stake_to_remove.owner);
+						}
+					}
+				});
+				DelegationRewardCount::<T>::remove(&stake_to_remove.owner, &state.id);
+				ExtraRewardCheckpoints::<T>::remove(&stake_to_remove.owner, &state.id);
+				Self::record_displaced_delegation(
+					&stake_to_remove.owner,
+					&state.id,
+					stake_to_remove.amount,
+				);
+				T::OnStakingUpdate::on_delegation_removed(
+					&stake_to_remove.owner,
+					&state.id,
+					stake_to_remove.amount,
+				);
+
+				Self::deposit_event(Event::DelegationReplaced(
+					stake.owner,
+					stake.amount,
+					stake_to_remove.owner,
+					stake_to_remove.amount,
+					state.id.clone(),
+					state.total,
+				));
+			}
+
+			Ok(state)
+		}
+
+		/// Stake `more` funds for the candidacy of `who` if `collator` is
+		/// `None` or for the delegation of `who` to `collator` otherwise.
+		///
+		/// If `from_unstaking` is set, the funds are taken from the pending
+		/// unstaking request which can be unlocked at this block only.
+		/// Otherwise pending unstaking requests are consumed in the order of
+		/// their blocks.
+		///
+		/// Returns the number of top candidates and the number of unstaking
+		/// requests for weight calculation.
+		fn do_stake_more(
+			who: &T::AccountId,
+			collator: Option<T::AccountId>,
+			more: BalanceOf<T>,
+			from_unstaking: Option<BlockNumberFor<T>>,
+		) -> Result<(u32, u32), DispatchError> {
+			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
+
+			match collator {
+				None => {
+					let mut state =
+						CandidatePool::<T>::get(who).ok_or(Error::<T>::CandidateNotFound)?;
+					ensure!(!state.is_leaving(), Error::<T>::CannotStakeIfLeaving);
+
+					let CandidateOf::<T, _> { stake: before_stake, total: before_total, .. } =
+						state;
+					state.stake_more(more);
+					let after_stake = state.stake;
+					ensure!(
+						state.stake <= MaxCollatorCandidateStake::<T>::get(),
+						Error::<T>::ValStakeAboveMax
+					);
+
+					// *** No Fail except during increase_lock beyond this point ***
+
+					let unstaking_len =
+						Self::increase_lock(who, state.stake, more, from_unstaking)?;
+
+					let n = if state.is_active() {
+						Self::update_top_candidates(
+							who.clone(),
+							before_stake,
+							// safe because total >= stake
+							before_total - before_stake,
+							state.stake,
+							state.total - state.stake,
+						)
+					} else {
+						0u32
+					};
+					CandidatePool::<T>::insert(who, state);
+
+					// increment rewards for origin + their delegators and reset reward counter
+					Self::do_inc_collator_reward_and_compound(who, before_stake);
+
+					Self::deposit_event(Event::CollatorStakedMore(
+						who.clone(),
+						before_stake,
+						after_stake,
+					));
+					Ok((n, unstaking_len))
+				},
+				Some(candidate) => {
+					let mut delegation =
+						DelegatorState::<T>::get(who).ok_or(Error::<T>::DelegatorNotFound)?;
+					let mut collator =
+						CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
+					ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+					let stake_after = delegation
+						.inc_delegation(candidate.clone(), more)
+						.map_err(|_| Error::<T>::DelegationNotFound)?;
+
+					// *** No Fail except during increase_lock beyond this point ***
+
+					// update lock
+					let unstaking_len =
+						Self::increase_lock(who, delegation.total, more, from_unstaking)?;
+
+					let CandidateOf::<T, _> { stake: before_stake, total: before_total, .. } =
+						collator;
+					collator.inc_delegator(who.clone(), more);
+					let after = collator.total;
+
+					// update top candidates and total amount at stake
+					let n = if collator.is_active() {
+						Self::update_top_candidates(
+							candidate.clone(),
+							before_stake,
+							// safe because total >= stake
+							before_total - before_stake,
+							collator.stake,
+							collator.total - collator.stake,
+						)
+					} else {
+						0u32
+					};
+
+					let commission = collator.commission;
+					CandidatePool::<T>::insert(&candidate, collator);
+					DelegatorState::<T>::insert(who, delegation);
+					T::OnStakingUpdate::on_delegation_increased(
+						who,
+						&candidate,
+						stake_after.saturating_sub(more),
+						stake_after,
+					);
+
+					// set rewards and reset reward counter
+					Self::do_inc_delegator_reward_and_compound(
+						who,
+						stake_after.saturating_sub(more),
+						&candidate,
+						commission,
+					);
+
+					Self::deposit_event(Event::DelegatorStakedMore(
+						who.clone(),
+						candidate,
+						before_total,
+						after,
+					));
+					Ok((n, unstaking_len))
+				},
+			}
+		}
+
+		/// Either set or increase the BalanceLock of target account to
+		/// amount.
+		///
+		/// Consumes unstaked balance which can be unlocked in the future up to
+		/// amount and updates `Unstaking` storage accordingly. If
+		/// `from_unstaking` is set, only the unstaking request which can be
+		/// unlocked at this block is consumed.
+		fn increase_lock(
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			more: BalanceOf<T>,
+			from_unstaking: Option<BlockNumberFor<T>>,
+		) -> Result<u32, DispatchError> {
+			ensure!(
+				pallet_balances::Pallet::<T>::free_balance(who) >= amount.into(),
+				pallet_balances::Error::<T>::InsufficientBalance
+			);
+
+			let mut unstaking_len = 0u32;
+
+			// *** No Fail except during Unstaking mutation beyond this point ***
+
+			// update Unstaking by consuming up to {amount | more}
+			<Unstaking<T>>::try_mutate(who, |unstaking| -> DispatchResult {
+				// reduce {amount | more} by unstaking until either {amount | more} is zero or
+				// no unstaking is left
+				// if more is set, we only want to reduce by more to achieve 100 - 40 + 30 = 90
+				// locked
+				let mut amt_consuming_unstaking = if more.is_zero() { amount } else { more };
+				unstaking_len = unstaking.len().saturated_into();
+
+				// only consume the chosen unstaking request if there is one
+				if let Some(block) = from_unstaking {
+					let pending =
+						unstaking.get(&block).copied().ok_or(Error::<T>::UnstakingNotFound)?;
+					let remaining = pending
+						.checked_sub(&amt_consuming_unstaking)
+						.ok_or(Error::<T>::Underflow)?;
+					if remaining.is_zero() {
+						unstaking.remove(&block);
+					} else {
+						unstaking
+							.try_insert(block, remaining)
+							.map_err(|_| Error::<T>::NoMoreUnstaking)?;
+					}
+					return Ok(());
+				}
+
+				for (block_number, locked_balance) in unstaking.clone() {
+					if amt_consuming_unstaking.is_zero() {
+						break;
+					} else if locked_balance > amt_consuming_unstaking {
+						// amount is only reducible by locked_balance - amt_consuming_unstaking
+						let delta = locked_balance.saturating_sub(amt_consuming_unstaking);
+						// replace old entry with delta
+						unstaking
+							.try_insert(block_number, delta)
+							.map_err(|_| Error::<T>::NoMoreUnstaking)?;
+						amt_consuming_unstaking = Zero::zero();
+					} else {
+						// amount is either still reducible or reached
+						amt_consuming_unstaking =
+							amt_consuming_unstaking.saturating_sub(locked_balance);
+						unstaking.remove(&block_number);
+					}
+				}
+				Ok(())
+			})?;
+
+			// Either set a new lock or potentially extend the existing one if amount
+			// exceeds the currently locked amount
+			T::Currency::extend_lock(STAKING_ID, who, amount, WithdrawReasons::all());
+
+			Ok(unstaking_len)
+		}
+
+		/// Set the unlocking block for the account and corresponding amount
+		/// which can be unlocked via `unlock_unstaked` after waiting at
+		/// least for `StakeDuration` many blocks.
+		///
+		/// Throws if the amount is zero (unlikely) or if active unlocking
+		/// requests exceed limit. The latter defends against stake reduction
+		/// spamming.
+		fn prep_unstake(
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			is_removal: bool,
+		) -> DispatchResult {
+			// should never occur but let's be safe
+			ensure!(!amount.is_zero(), Error::<T>::StakeNotFound);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut unlock_block =
+				now.saturating_add(StakingConfiguration::<T>::get().stake_duration);
+			// delegators cannot unlock before their committed lock period ends unless they
+			// were forcedly removed, e.g., kicked or their collator left
+			if !is_removal {
+				if let Some(lock) = StakeLocks::<T>::get(who) {
+					unlock_block = unlock_block.max(lock.until);
+				}
+			}
+			let mut unstaking = <Unstaking<T>>::get(who);
+
+			let allowed_unstakings = if is_removal {
+				// the account was forcedly removed and we allow to fill all unstake requests
+				T::MaxUnstakeRequests::get()
+			} else {
+				// we need to reserve a free slot for a forced removal of the account
+				T::MaxUnstakeRequests::get().saturating_sub(1)
+			};
+			ensure!(
+				unstaking.len().saturated_into::<u32>() < allowed_unstakings,
+				Error::<T>::NoMoreUnstaking,
+			);
+
+			// if existent, we have to add the current amount of same unlock_block, because
+			// insert overwrites the current value
+			let amount = amount
+				.saturating_add(*unstaking.get(&unlock_block).unwrap_or(&BalanceOf::<T>::zero()));
+			unstaking
+				.try_insert(unlock_block, amount)
+				.map_err(|_| Error::<T>::NoMoreUnstaking)?;
+			<Unstaking<T>>::insert(who, unstaking);
+			Ok(())
+		}
+
+		/// Clear the CandidatePool of the candidate and remove all delegations
+		/// to the candidate. Moreover, prepare unstaking for the candidate and
+		/// their former delegations.
+		fn remove_candidate(
+			collator: &T::AccountId,
+			state: &CandidateOf<T, T::MaxDelegatorsPerCollator>,
+		) -> DispatchResult {
+			// iterate over delegators
+			for stake in &state.delegators[..] {
+				// prepare unstaking of delegator
+				Self::prep_unstake(&stake.owner, stake.amount, true)?;
+				// remove delegation from delegator state
+				if let Some(mut delegator) = DelegatorState::<T>::get(&stake.owner) {
+					delegator
+						.rm_delegation(collator.clone())
+						.map_err(|_| Error::<T>::DelegationNotFound)?;
+					if delegator.delegations.is_empty() {
+						DelegatorState::<T>::remove(&stake.owner);
+						StakeLocks::<T>::remove(&stake.owner);
+					} else {
+						DelegatorState::<T>::insert(&stake.owner, delegator);
+					}
+				}
+			}
+			// prepare unstaking of collator candidate
+			Self::prep_unstake(&state.id, state.stake, true)?;
+
+			// *** No Fail beyond this point ***
+
+			// increment rewards of collator and their delegators
+			Self::do_inc_collator_reward(collator, state.stake);
+			for stake in &state.delegators[..] {
+				DelegationRewardCount::<T>::remove(&stake.owner, collator);
+				ExtraRewardCheckpoints::<T>::remove(&stake.owner, collator);
+				T::OnStakingUpdate::on_delegation_removed(&stake.owner, collator, stake.amount);
+			}
+			ExtraRewardCheckpoints::<T>::remove(collator, collator);
+			ExtraRewardsPerStake::<T>::remove(collator);
+
+			// disable validator for next session if they were in the set of validators
+			pallet_session::Pallet::<T>::validators()
+				.into_iter()
+				.enumerate()
+				.find_map(|(i, id)| {
+					if <T as pallet_session::Config>::ValidatorIdOf::convert(collator.clone())
+						== Some(id)
+					{
+						Some(i)
+					} else {
+						None
+					}
+				})
+				.map(u32::saturated_from::<usize>)
+				// FIXME: Does not prevent the collator from being able to author a block in this (or potentially the next) session. See https://github.com/paritytech/substrate/issues/8004
+				.map(pallet_session::Pallet::<T>::disable_index);
+
+			CandidatePool::<T>::remove(collator);
+			CommissionChangeRequests::<T>::remove(collator);
+			MissedRounds::<T>::remove(collator);
+			Ok(())
+		}
+
+		/// Withdraw all staked currency which was unstaked at least
+		/// `StakeDuration` blocks ago.
+		fn do_unlock(who: &T::AccountId) -> Result<u32, DispatchError> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut unstaking = <Unstaking<T>>::get(who);
+			let unstaking_len = unstaking.len().saturated_into::<u32>();
+			ensure!(!unstaking.is_empty(), Error::<T>::UnstakingIsEmpty);
+
+			let mut total_unlocked: BalanceOf<T> = Zero::zero();
+			let mut total_locked: BalanceOf<T> = Zero::zero();
+			let mut expired = Vec::new();
+
+			// check potential unlocks
+			for (block_number, locked_balance) in unstaking.clone().into_iter() {
+				if block_number <= now {
+					expired.push(block_number);
+					total_unlocked = total_unlocked.saturating_add(locked_balance);
+				} else {
+					total_locked = total_locked.saturating_add(locked_balance);
+				}
+			}
+			for block_number in expired {
+				unstaking.remove(&block_number);
+			}
+
+			// iterate balance locks to retrieve amount of locked balance
+			let locks = Locks::<T>::get(who);
+			total_locked = if let Some(BalanceLock { amount, .. }) =
+				locks.iter().find(|l| l.id == STAKING_ID)
+			{
+				amount.saturating_sub(total_unlocked.into()).into()
+			} else {
+				// should never fail to find the lock since we checked whether unstaking is not
+				// empty but let's be safe
+				Zero::zero()
+			};
+
+			if total_locked.is_zero() {
+				T::Currency::remove_lock(STAKING_ID, who);
+				<Unstaking<T>>::remove(who);
+			} else {
+				T::Currency::set_lock(STAKING_ID, who, total_locked, WithdrawReasons::all());
+				<Unstaking<T>>::insert(who, unstaking);
+			}
+
+			Ok(unstaking_len)
+		}
+
+		/// Stores a record of the replaced delegation for the kicked delegator.
+		///
+		/// If `DisplacedDelegatorGracePeriod` is enabled, the delegator gets
+		/// back one delegation for the current round.
+		fn record_displaced_delegation(
+			delegator: &T::AccountId,
+			collator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) {
+			let round = <Round<T>>::get().current;
+			DisplacedDelegations::<T>::mutate(delegator, |records| {
+				if records.is_full() {
+					records.remove(0);
+				}
+				// cannot fail because we just made room
+				let _ = records.try_push(DisplacedDelegation {
+					collator: collator.clone(),
+					round,
+					amount,
+				});
+			});
+
+			if T::DisplacedDelegatorGracePeriod::get() {
+				<LastDelegation<T>>::mutate(delegator, |last_delegation| {
+					if last_delegation.round == round {
+						last_delegation.counter = last_delegation.counter.saturating_sub(1);
+					}
+				});
+			}
+		}
+
+		/// Checks whether a delegator can still delegate in this round, e.g.,
+		/// if they have not delegated MaxDelegationsPerRound many times
+		/// already in this round.
+		fn get_delegation_counter(
+			delegator: &T::AccountId,
+		) -> Result<DelegationCounter, DispatchError> {
+			let last_delegation = <LastDelegation<T>>::get(delegator);
+			let round = <Round<T>>::get();
+
+			// reset counter if the round advanced since last delegation
+			let counter =
+				if last_delegation.round < round.current { 0u32 } else { last_delegation.counter };
+
+			ensure!(
+				counter < T::MaxDelegationsPerRound::get(),
+				Error::<T>::DelegationsPerRoundExceeded
+			);
+
+			Ok(DelegationCounter { round: round.current, counter: counter.saturating_add(1) })
+		}
+
+		/// Calculates the network rewards per block with the current data and
+		/// issues these rewards to the network. The imbalance will be handled
+		/// in `on_initialize` by adding it to the free balance of
+		/// `NetworkRewardBeneficiary`.
+		///
+		/// Over the course of an entire year, the network rewards equal the
+		/// maximum annual collator staking rewards multiplied with the
+		/// NetworkRewardRate. E.g., assuming 10% annual collator reward rate,
+		/// 10% max staking rate, 200k KILT max collator stake and 30 collators:
+		/// NetworkRewards = NetworkRewardRate * 10% * 10% * 200_000 KILT * 30
+		///
+		/// The expected rewards are the product of
+		///  * the current total maximum collator rewards
+		///  * and the configured NetworkRewardRate
+		///
+		/// `col_reward_rate_per_block * col_max_stake * max_num_of_collators *
+		/// NetworkRewardRate`
+		fn issue_network_reward() -> NegativeImbalanceOf<T> {
+			// Multiplication with Perquintill cannot overflow
+			let max_col_rewards = InflationConfig::<T>::get().collator.reward_rate.per_block *
+				MaxCollatorCandidateStake::<T>::get() *
+				MaxSelectedCandidates::<T>::get().into();
+			let network_reward =
+				StakingConfiguration::<T>::get().network_reward_rate * max_col_rewards;
+
+			T::Currency::issue(network_reward)
+		}
+
+		/// Calculates the collator staking rewards for authoring `multiplier`
+		/// many blocks based on the given stake.
+		///
+		/// Depends on the current total issuance and staking reward
+		/// configuration for collators.
+		fn calc_block_rewards_collator(
+			stake: BalanceOf<T>,
+			multiplier: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			let total_issuance = T::Currency::total_issuance();
+			let TotalStake { collators: total_collators, .. } = <TotalCollatorStake<T>>::get();
+			let staking_rate = Perquintill::from_rational(total_collators, total_issuance);
+
+			InflationConfig::<T>::get().collator.compute_reward::<T>(
+				stake,
+				staking_rate,
+				multiplier,
+				Perquintill::zero(),
+			)
+		}
+
+		/// Calculates the delegator staking rewards for `multiplier` many
+		/// blocks based on the given stake, increased by the given bonus.
+		///
+		/// Depends on the current total issuance and staking reward
+		/// configuration for delegators.
+		fn calc_block_rewards_delegator(
+			stake: BalanceOf<T>,
+			multiplier: BalanceOf<T>,
+			bonus: Perquintill,
+		) -> BalanceOf<T> {
+			let total_issuance = T::Currency::total_issuance();
+			let TotalStake { delegators: total_delegators, .. } = <TotalCollatorStake<T>>::get();
+			let staking_rate = Perquintill::from_rational(total_delegators, total_issuance);
+
+			InflationConfig::<T>::get().delegator.compute_reward::<T>(
+				stake,
+				staking_rate,
+				multiplier,
+				bonus,
+			)
+		}
+
+		/// The bonus on the rewards of a delegator if they have an active
+		/// stake lock.
+		fn reward_bonus(delegator: &T::AccountId) -> Perquintill {
+			let now = frame_system::Pallet::<T>::block_number();
+			StakeLocks::<T>::get(delegator)
+				.filter(|lock| lock.until > now)
+				.map_or_else(Perquintill::zero, |lock| lock.bonus)
+		}
+
+		/// Lock the stake of a delegator for the given period which has to be
+		/// one of the `StakeLockPeriods`.
+		///
+		/// The rewards of all existing delegations are incremented first such
+		/// that the new bonus only applies to rewards accumulated from now on.
+		///
+		/// NOTE: It is assumed that the calling context reads the state of the
+		/// delegator and their collator candidates afterwards.
+		///
+		/// Emits `StakeLocked`.
+		fn do_lock_stake(delegator: &T::AccountId, period: BlockNumberFor<T>) -> DispatchResult {
+			let bonus = T::StakeLockPeriods::get()
+				.into_iter()
+				.find(|(p, _)| *p == period)
+				.map(|(_, bonus)| bonus)
+				.ok_or(Error::<T>::InvalidLockPeriod)?;
+			let until = frame_system::Pallet::<T>::block_number().saturating_add(period);
+			if let Some(lock) = StakeLocks::<T>::get(delegator) {
+				ensure!(until >= lock.until, Error::<T>::LockCannotBeShortened);
+			}
+
+			if let Some(state) = DelegatorState::<T>::get(delegator) {
+				Self::do_inc_delegations_and_compound(delegator, state.delegations.into_iter());
+			}
+
+			StakeLocks::<T>::insert(delegator, StakeLock { until, bonus });
+
+			Self::deposit_event(Event::StakeLocked(delegator.clone(), until, bonus));
+			Ok(())
+		}
+
+		/// Calculates the staking rewards for a given account address.
+		///
+		/// At least used in Runtime API.
+		pub fn get_unclaimed_staking_rewards(acc: &T::AccountId) -> BalanceOf<T> {
+			let reward_count = RewardCount::<T>::get(acc);
+			let rewards = Rewards::<T>::get(acc);
+
+			// delegators and collators need to be handled differently
+			if let Some(delegator_state) = DelegatorState::<T>::get(acc) {
+				// delegator reward counts do not automatically increment in order to be
+				// scalable, see [increment_delegator_rewards] for details
+				// therefore, we need to query the counter of each delegated collator
+				delegator_state.delegations.into_iter().fold(rewards, |rewards, delegation| {
+					let diff = RewardCount::<T>::get(&delegation.owner)
+						.saturating_sub(DelegationRewardCount::<T>::get(acc, &delegation.owner));
+					let commission = CandidatePool::<T>::get(&delegation.owner)
+						.map(|state| state.commission)
+						.unwrap_or_else(Perquintill::zero);
+					// rewards += stake * (collator_count - delegation_count) * delegator_reward_rate
+					let reward = Self::calc_block_rewards_delegator(
+						delegation.amount,
+						diff.into(),
+						Self::reward_bonus(acc),
+					);
+					// rewards -= commission
+					rewards.saturating_add(reward.saturating_sub(commission * reward))
+				})
+			} else if let Some(state) = CandidatePool::<T>::get(acc) {
+				// rewards += stake * self_count * collator_reward_rate
+				let rewards = rewards.saturating_add(Self::calc_block_rewards_collator(
+					state.stake,
+					reward_count.into(),
+				));
+				// rewards += commission of delegators' rewards which have not been incremented yet
+				state.delegators.into_iter().fold(rewards, |rewards, delegation| {
+					let diff = reward_count
+						.saturating_sub(DelegationRewardCount::<T>::get(&delegation.owner, acc));
+					let reward = Self::calc_block_rewards_delegator(
+						delegation.amount,
+						diff.into(),
+						Self::reward_bonus(&delegation.owner),
+					);
+					rewards.saturating_add(state.commission * reward)
+				})
+			} else {
+				BalanceOf::<T>::zero()
+			}
+		}
+
+		/// Increment the accumulated rewards of a collator by consuming their
+		/// current rewards counter.
+		///
+		/// Moreover, automatically increments all associated delegators since
+		/// their reward increment is dependend on the collator reward counter.
+		///
+		/// Resets all reward counters of the collator and their delegators to
+		/// zero.
+		fn do_inc_collator_reward(collator: &T::AccountId, stake: BalanceOf<T>) -> usize {
+			Self::do_inc_extra_rewards(collator, collator, stake);
+
+			// get reward counters
+			let col_reward_count = RewardCount::<T>::get(collator);
+
+			// set reward data for collator
+			let mut collator_rewards =
+				Self::calc_block_rewards_collator(stake, col_reward_count.into());
+			Rewards::<T>::mutate(collator, |reward| {
+				*reward = reward.saturating_add(collator_rewards);
+			});
+
+			// set reward data for delegators
+			let mut delegator_rewards = BalanceOf::<T>::zero();
+			let num_delegators = if let Some(state) = CandidatePool::<T>::get(collator.clone()) {
+				let num_delegators = state.delegators.len();
+				for Stake { owner, amount } in state.delegators {
+					let (_, reward, commission) =
+						Self::credit_delegator_reward(&owner, amount, collator, state.commission);
+					delegator_rewards = delegator_rewards.saturating_add(reward);
+					collator_rewards = collator_rewards.saturating_add(commission);
+					// Reset delegation counter since collator counter will be reset
+					DelegationRewardCount::<T>::insert(owner, collator, 0);
+				}
+				num_delegators
+			} else {
+				0usize
+			};
+
+			// Reset collator reward count
+			RewardCount::<T>::insert(collator, 0);
+
+			Self::note_credited_rewards(collator, collator_rewards, delegator_rewards);
+
+			num_delegators
+		}
+
+		/// Increment the accumulated rewards of a delegator for their delegation
+		/// of the given collator by consuming the current rewards counter of
+		/// the delegation. The counter will be reset to the collator counter.
+		///
+		/// The collator's commission is deducted from the delegator's rewards
+		/// and added to the accumulated rewards of the collator.
+		fn do_inc_delegator_reward(
+			acc: &T::AccountId,
+			stake: BalanceOf<T>,
+			col: &T::AccountId,
+			commission: Perquintill,
+		) -> Weight {
+			let (weight, reward, commission) =
+				Self::credit_delegator_reward(acc, stake, col, commission);
+			if reward.is_zero() && commission.is_zero() {
+				weight
+			} else {
+				Self::note_credited_rewards(col, commission, reward);
+				weight.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+			}
+		}
+
+		/// Credit the rewards of a delegator for their delegation of the given
+		/// collator like [Self::do_inc_delegator_reward] without adding them
+		/// to the snapshot of the current round.
+		///
+		/// Returns the weight, the credited rewards of the delegator and the
+		/// commission credited to the collator.
+		fn credit_delegator_reward(
+			acc: &T::AccountId,
+			stake: BalanceOf<T>,
+			col: &T::AccountId,
+			commission: Perquintill,
+		) -> (Weight, BalanceOf<T>, BalanceOf<T>) {
+			Self::do_inc_extra_rewards(acc, col, stake);
+
+			// get reward counters
+			let del_reward_count = DelegationRewardCount::<T>::get(acc, col);
+			let col_reward_count = RewardCount::<T>::get(col);
+			let diff = col_reward_count.saturating_sub(del_reward_count);
+
+			// only update if collator has higher reward count
+			if diff > 0 {
+				let reward =
+					Self::calc_block_rewards_delegator(stake, diff.into(), Self::reward_bonus(acc));
+				let commission = commission * reward;
+				Rewards::<T>::mutate(acc, |r| {
+					*r = r.saturating_add(reward.saturating_sub(commission));
+				});
+				if !commission.is_zero() {
+					Rewards::<T>::mutate(col, |r| {
+						*r = r.saturating_add(commission);
+					});
+				}
+				// align with collator counter such that incrementing would
+				// lead to 0 rewards until the collator counter increases
+				DelegationRewardCount::<T>::insert(acc, col, col_reward_count);
+
+				// 4 reads from reward calc
+				(
+					T::DbWeight::get().reads_writes(7, 3),
+					reward.saturating_sub(commission),
+					commission,
+				)
+			} else {
+				(T::DbWeight::get().reads(2), Zero::zero(), Zero::zero())
+			}
+		}
+
+		/// Add rewards which have just been credited to a collator and their
+		/// delegators to the snapshot of the current round.
+		///
+		/// Nothing is recorded if the collator is not part of the snapshot.
+		fn note_credited_rewards(
+			collator: &T::AccountId,
+			collator_rewards: BalanceOf<T>,
+			delegator_rewards: BalanceOf<T>,
+		) {
+			if collator_rewards.is_zero() && delegator_rewards.is_zero() {
+				return;
+			}
+			let round = <Round<T>>::get().current;
+			RoundSnapshots::<T>::mutate_exists(round, |maybe_snapshot| {
+				if let Some(entry) = maybe_snapshot
+					.as_mut()
+					.and_then(|snapshot| snapshot.iter_mut().find(|e| &e.collator == collator))
+				{
+					entry.collator_rewards =
+						entry.collator_rewards.saturating_add(collator_rewards);
+					entry.delegator_rewards =
+						entry.delegator_rewards.saturating_add(delegator_rewards);
+				}
+			});
+		}
+
+		/// Increment the accumulated extra rewards of a staker for their stake
+		/// at the given collator by the extra rewards per stake which the
+		/// collator received since the last increment.
+		fn do_inc_extra_rewards(acc: &T::AccountId, col: &T::AccountId, stake: BalanceOf<T>) {
+			let rewards_per_stake = ExtraRewardsPerStake::<T>::get(col);
+			if rewards_per_stake.is_empty() {
+				return;
+			}
+
+			let checkpoint = ExtraRewardCheckpoints::<T>::get(acc, col);
+			let stake: u128 = stake.saturated_into();
+			for (currency_id, per_stake) in rewards_per_stake.iter() {
+				let credited = checkpoint
+					.iter()
+					.find(|(id, _)| id == currency_id)
+					.map(|(_, credited)| *credited)
+					.unwrap_or_default();
+				let reward: BalanceOf<T> =
+					per_stake.saturating_sub(credited).saturating_mul_int(stake).into();
+				if !reward.is_zero() {
+					ExtraRewards::<T>::mutate(acc, currency_id, |r| {
+						*r = r.saturating_add(reward);
+					});
+				}
+			}
+			ExtraRewardCheckpoints::<T>::insert(acc, col, rewards_per_stake);
+		}
+
+		/// Increment the accumulated extra rewards of an account for their own
+		/// stake as a collator candidate or for all their delegations.
+		///
+		/// Returns the number of incremented stakes.
+		fn do_inc_all_extra_rewards(acc: &T::AccountId) -> u32 {
+			if let Some(state) = CandidatePool::<T>::get(acc) {
+				Self::do_inc_extra_rewards(acc, acc, state.stake);
+				1
+			} else if let Some(delegator) = DelegatorState::<T>::get(acc) {
+				for Stake { owner, amount } in delegator.delegations.iter() {
+					Self::do_inc_extra_rewards(acc, owner, *amount);
+				}
+				delegator.delegations.len().saturated_into()
+			} else {
+				0
+			}
+		}
 
-			state.total = state.total.saturating_add(stake.amount);
+		/// Distribute the extra rewards of the ending round `round` among the
+		/// collators which authored blocks in it, pro rata to their total
+		/// stake. Invulnerables are skipped.
+		///
+		/// The share of each collator is added to their extra rewards per
+		/// stake, from which the collator and their delegators are credited
+		/// lazily whenever their rewards are incremented.
+		///
+		/// Returns the number of rewarded collators.
+		fn distribute_extra_rewards(
+			round: SessionIndex,
+			authored: &BTreeMap<T::AccountId, u32>,
+		) -> u32 {
+			let rates = ExtraRewardRates::<T>::get();
+			if rates.is_empty() {
+				return 0;
+			}
 
-			if let Some(stake_to_remove) = stake_to_remove {
-				// update total stake
-				state.total = state.total.saturating_sub(stake_to_remove.amount);
+			let invulnerables = Invulnerables::<T>::get();
+			let collators: Vec<(T::AccountId, u128)> = authored
+				.keys()
+				.filter(|collator| !invulnerables.contains(*collator))
+				.filter_map(|collator| {
+					CandidatePool::<T>::get(collator)
+						.map(|state| (collator.clone(), state.total.saturated_into::<u128>()))
+				})
+				.filter(|(_, total)| !total.is_zero())
+				.collect();
+			let total_stake =
+				collators.iter().fold(0u128, |acc, (_, total)| acc.saturating_add(*total));
+			if total_stake.is_zero() {
+				return 0;
+			}
 
-				// set rewards for kicked delegator
-				Self::do_inc_delegator_reward(
-					&stake_to_remove.owner,
-					stake_to_remove.amount,
-					&state.id,
-				);
-				// prepare unstaking for kicked delegator
-				Self::prep_unstake(&stake_to_remove.owner, stake_to_remove.amount, true)?;
-				// remove Delegator state for kicked delegator
-				DelegatorState::<T>::remove(&stake_to_remove.owner);
+			let pot = Self::extra_rewards_pot();
+			for (currency_id, rate) in rates {
+				let allocated = ExtraRewardsAllocated::<T>::get(currency_id);
+				let available = T::ExtraRewardCurrency::free_balance(currency_id, &pot)
+					.saturating_sub(allocated);
+				let amount: u128 = rate.min(available).saturated_into();
+				if amount.is_zero() {
+					continue;
+				}
 
-				Self::deposit_event(Event::DelegationReplaced(
-					stake.owner,
-					stake.amount,
-					stake_to_remove.owner,
-					stake_to_remove.amount,
-					state.id.clone(),
-					state.total,
+				let mut distributed = 0u128;
+				for (collator, total) in collators.iter() {
+					let share = Perquintill::from_rational(*total, total_stake).mul_floor(amount);
+					if share.is_zero() {
+						continue;
+					}
+					let added = FixedU128::saturating_from_rational(share, *total);
+					ExtraRewardsPerStake::<T>::mutate(collator, |rewards_per_stake| {
+						if let Some((_, per_stake)) =
+							rewards_per_stake.iter_mut().find(|(id, _)| *id == currency_id)
+						{
+							*per_stake = per_stake.saturating_add(added);
+						} else if rewards_per_stake.try_push((currency_id, added)).is_err() {
+							// the collator still tracks too many removed currencies
+							return;
+						}
+						distributed = distributed.saturating_add(share);
+					});
+				}
+
+				let distributed: BalanceOf<T> = distributed.into();
+				ExtraRewardsAllocated::<T>::insert(
+					currency_id,
+					allocated.saturating_add(distributed),
+				);
+				Self::deposit_event(Event::ExtraRewardsDistributed(
+					round,
+					currency_id,
+					distributed,
 				));
 			}
 
-			Ok(state)
+			collators.len().saturated_into()
 		}
 
-		/// Either set or increase the BalanceLock of target account to
-		/// amount.
+		/// Pay the accumulated rewards of the target from the treasury to the
+		/// reward destination of the target.
 		///
-		/// Consumes unstaked balance which can be unlocked in the future up to
-		/// amount and updates `Unstaking` storage accordingly.
-		fn increase_lock(
-			who: &T::AccountId,
-			amount: BalanceOf<T>,
-			more: BalanceOf<T>,
-		) -> Result<u32, DispatchError> {
-			ensure!(
-				pallet_balances::Pallet::<T>::free_balance(who) >= amount.into(),
-				pallet_balances::Error::<T>::InsufficientBalance
-			);
+		/// Emits `Rewarded`, `RewardedTo` or `RewardsDonated` depending on the
+		/// reward destination.
+		fn do_claim_rewards(target: &T::AccountId) -> DispatchResult {
+			// we could kill the storage entry but let's be safe in case the deposit fails
+			let rewards = Rewards::<T>::get(target);
+			ensure!(!rewards.is_zero(), Error::<T>::RewardsNotFound);
 
-			let mut unstaking_len = 0u32;
+			let destination = match RewardDestinations::<T>::get(target) {
+				RewardDestination::Treasury => {
+					// the rewards are paid out of the treasury, thus there is nothing to transfer
+					Rewards::<T>::remove(target);
+					Self::deposit_event(Event::RewardsDonated(target.clone(), rewards));
+					return Ok(());
+				},
+				RewardDestination::Account(destination) => Some(destination),
+				RewardDestination::Own | RewardDestination::Staked => None,
+			};
 
-			// *** No Fail except during Unstaking mutation beyond this point ***
+			let treasury_account_id = T::TreasuryAccount::get();
+			let treasury_balance = T::Currency::free_balance(&treasury_account_id);
+			ensure!(treasury_balance >= rewards, Error::<T>::TreasuryBalanceInsufficient);
 
-			// update Unstaking by consuming up to {amount | more}
-			<Unstaking<T>>::try_mutate(who, |unstaking| -> DispatchResult {
-				// reduce {amount | more} by unstaking until either {amount | more} is zero or
-				// no unstaking is left
-				// if more is set, we only want to reduce by more to achieve 100 - 40 + 30 = 90
-				// locked
-				let mut amt_consuming_unstaking = if more.is_zero() { amount } else { more };
-				unstaking_len = unstaking.len().saturated_into();
-				for (block_number, locked_balance) in unstaking.clone() {
-					if amt_consuming_unstaking.is_zero() {
-						break;
-					} else if locked_balance > amt_consuming_unstaking {
-						// amount is only reducible by locked_balance - amt_consuming_unstaking
-						let delta = locked_balance.saturating_sub(amt_consuming_unstaking);
-						// replace old entry with delta
-						unstaking
-							.try_insert(block_number, delta)
-							.map_err(|_| Error::<T>::NoMoreUnstaking)?;
-						amt_consuming_unstaking = Zero::zero();
-					} else {
-						// amount is either still reducible or reached
-						amt_consuming_unstaking =
-							amt_consuming_unstaking.saturating_sub(locked_balance);
-						unstaking.remove(&block_number);
-					}
-				}
-				Ok(())
-			})?;
+			T::Currency::transfer(
+				&treasury_account_id,
+				destination.as_ref().unwrap_or(target),
+				rewards,
+				frame_support::traits::ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|_| Error::<T>::TreasuryTransferFailed)?;
 
-			// Either set a new lock or potentially extend the existing one if amount
-			// exceeds the currently locked amount
-			T::Currency::extend_lock(STAKING_ID, who, amount, WithdrawReasons::all());
+			Rewards::<T>::remove(target);
 
-			Ok(unstaking_len)
+			match destination {
+				Some(destination) =>
+					Self::deposit_event(Event::RewardedTo(target.clone(), destination, rewards)),
+				None => {
+					T::OnStakingUpdate::on_rewards_paid(target, rewards);
+					Self::deposit_event(Event::Rewarded(target.clone(), rewards))
+				},
+			}
+
+			Ok(())
 		}
 
-		/// Set the unlocking block for the account and corresponding amount
-		/// which can be unlocked via `unlock_unstaked` after waiting at
-		/// least for `StakeDuration` many blocks.
+		/// Increment the rewards of a collator and their delegators and
+		/// restake the auto-compounding shares.
 		///
-		/// Throws if the amount is zero (unlikely) or if active unlocking
-		/// requests exceed limit. The latter defends against stake reduction
-		/// spamming.
-		fn prep_unstake(
-			who: &T::AccountId,
-			amount: BalanceOf<T>,
-			is_removal: bool,
-		) -> DispatchResult {
-			// should never occur but let's be safe
-			ensure!(!amount.is_zero(), Error::<T>::StakeNotFound);
+		/// Returns the number of delegators of the collator.
+		fn do_increment_collator_rewards(collator: &T::AccountId) -> Result<usize, DispatchError> {
+			let state = CandidatePool::<T>::get(collator).ok_or(Error::<T>::CandidateNotFound)?;
 
-			let now = <frame_system::Pallet<T>>::block_number();
-			let unlock_block = now.saturating_add(T::StakeDuration::get());
-			let mut unstaking = <Unstaking<T>>::get(who);
+			// early exit
+			let reward_count = RewardCount::<T>::get(collator);
+			ensure!(!reward_count.is_zero(), Error::<T>::RewardsNotFound);
 
-			let allowed_unstakings = if is_removal {
-				// the account was forcedly removed and we allow to fill all unstake requests
-				T::MaxUnstakeRequests::get()
-			} else {
-				// we need to reserve a free slot for a forced removal of the account
-				T::MaxUnstakeRequests::get().saturating_sub(1)
-			};
+			Ok(Self::do_inc_collator_reward_and_compound(collator, state.stake))
+		}
+
+		/// Increment the rewards of a delegator for all their delegations and
+		/// restake the auto-compounding shares.
+		///
+		/// Returns the number of delegations of the delegator.
+		fn do_increment_delegator_rewards(delegator: &T::AccountId) -> Result<u32, DispatchError> {
+			let state = DelegatorState::<T>::get(delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			let num_delegations: u32 = state.delegations.len().saturated_into();
+
+			let rewarded =
+				Self::do_inc_delegations_and_compound(delegator, state.delegations.into_iter());
+			// rewards might have been restaked completely
 			ensure!(
-				unstaking.len().saturated_into::<u32>() < allowed_unstakings,
-				Error::<T>::NoMoreUnstaking,
+				!rewarded.is_zero() || !Rewards::<T>::get(delegator).is_zero(),
+				Error::<T>::RewardsNotFound
 			);
 
-			// if existent, we have to add the current amount of same unlock_block, because
-			// insert overwrites the current value
-			let amount = amount
-				.saturating_add(*unstaking.get(&unlock_block).unwrap_or(&BalanceOf::<T>::zero()));
-			unstaking
-				.try_insert(unlock_block, amount)
-				.map_err(|_| Error::<T>::NoMoreUnstaking)?;
-			<Unstaking<T>>::insert(who, unstaking);
-			Ok(())
+			Ok(num_delegations)
 		}
 
-		/// Clear the CandidatePool of the candidate and remove all delegations
-		/// to the candidate. Moreover, prepare unstaking for the candidate and
-		/// their former delegations.
-		fn remove_candidate(
-			collator: &T::AccountId,
-			state: &CandidateOf<T, T::MaxDelegatorsPerCollator>,
-		) -> DispatchResult {
-			// iterate over delegators
-			for stake in &state.delegators[..] {
-				// prepare unstaking of delegator
-				Self::prep_unstake(&stake.owner, stake.amount, true)?;
-				// remove delegation from delegator state
-				if let Some(mut delegator) = DelegatorState::<T>::get(&stake.owner) {
-					delegator
-						.rm_delegation(collator.clone())
-						.map_err(|_| Error::<T>::DelegationNotFound)?;
-					DelegatorState::<T>::remove(&stake.owner);
-				}
+		/// Increment the rewards of a delegator for the given delegations like
+		/// [do_inc_delegator_reward_and_compound].
+		///
+		/// Returns the newly accumulated rewards of the delegator.
+		fn do_inc_delegations_and_compound(
+			delegator: &T::AccountId,
+			delegations: impl Iterator<Item = StakeOf<T>>,
+		) -> BalanceOf<T> {
+			let mut rewarded = BalanceOf::<T>::zero();
+			for delegation in delegations {
+				let commission = CandidatePool::<T>::get(&delegation.owner)
+					.map(|state| state.commission)
+					.unwrap_or_else(Perquintill::zero);
+				rewarded = rewarded.saturating_add(Self::do_inc_delegator_reward_and_compound(
+					delegator,
+					delegation.amount,
+					&delegation.owner,
+					commission,
+				));
 			}
-			// prepare unstaking of collator candidate
-			Self::prep_unstake(&state.id, state.stake, true)?;
+			rewarded
+		}
 
-			// *** No Fail beyond this point ***
+		/// The weight of paying out the rewards of a single account in
+		/// `payout_stakers`.
+		fn payout_weight(num_delegators: u32, num_delegations: u32, num_claims: u32) -> Weight {
+			let mut weight = T::DbWeight::get().reads(2);
+			if !num_delegators.is_zero() {
+				weight = weight.saturating_add(
+					<T as Config>::WeightInfo::increment_collator_rewards(num_delegators),
+				);
+			}
+			weight
+				.saturating_add(
+					<T as Config>::WeightInfo::increment_delegator_rewards()
+						.saturating_mul(num_delegations.into()),
+				)
+				.saturating_add(
+					<T as Config>::WeightInfo::claim_rewards().saturating_mul(num_claims.into()),
+				)
+		}
 
-			// increment rewards of collator and their delegators
-			Self::do_inc_collator_reward(collator, state.stake);
+		/// Increment the rewards of a collator and their delegators like
+		/// [do_inc_collator_reward] and restake the auto-compounding share of
+		/// everyone's newly accumulated rewards.
+		///
+		/// NOTE: It is assumed that the calling context does not write back a
+		/// stale state of the collator candidate afterwards.
+		fn do_inc_collator_reward_and_compound(
+			collator: &T::AccountId,
+			stake: BalanceOf<T>,
+		) -> usize {
+			let accounts: Vec<T::AccountId> = sp_std::iter::once(collator.clone())
+				.chain(
+					CandidatePool::<T>::get(collator)
+						.into_iter()
+						.flat_map(|state| state.delegators.into_iter().map(|stake| stake.owner)),
+				)
+				.collect();
+			let rewards_before: Vec<BalanceOf<T>> =
+				accounts.iter().map(Rewards::<T>::get).collect();
 
-			// disable validator for next session if they were in the set of validators
-			pallet_session::Pallet::<T>::validators()
-				.into_iter()
-				.enumerate()
-				.find_map(|(i, id)| {
-					if <T as pallet_session::Config>::ValidatorIdOf::convert(collator.clone())
-						== Some(id)
-					{
-						Some(i)
-					} else {
-						None
-					}
-				})
-				.map(u32::saturated_from::<usize>)
-				// FIXME: Does not prevent the collator from being able to author a block in this (or potentially the next) session. See https://github.com/paritytech/substrate/issues/8004
-				.map(pallet_session::Pallet::<T>::disable_index);
+			let num_delegators = Self::do_inc_collator_reward(collator, stake);
 
-			CandidatePool::<T>::remove(collator);
-			Ok(())
+			for (acc, before) in accounts.iter().zip(rewards_before) {
+				Self::do_compound(acc, collator, Rewards::<T>::get(acc).saturating_sub(before));
+			}
+
+			num_delegators
 		}
 
-		/// Withdraw all staked currency which was unstaked at least
-		/// `StakeDuration` blocks ago.
-		fn do_unlock(who: &T::AccountId) -> Result<u32, DispatchError> {
-			let now = <frame_system::Pallet<T>>::block_number();
-			let mut unstaking = <Unstaking<T>>::get(who);
-			let unstaking_len = unstaking.len().saturated_into::<u32>();
-			ensure!(!unstaking.is_empty(), Error::<T>::UnstakingIsEmpty);
+		/// Increment the rewards of a delegator for their delegation like
+		/// [do_inc_delegator_reward] and restake the auto-compounding share of
+		/// the newly accumulated rewards of the delegator.
+		///
+		/// The collator's commission is only credited. Restaking it would
+		/// increase the stake of the collator before their own rewards have
+		/// been incremented and thus pay the larger stake for past blocks.
+		///
+		/// Returns the newly accumulated rewards of the delegator.
+		///
+		/// NOTE: It is assumed that the calling context does not write back a
+		/// stale state of the delegator or the collator candidate afterwards.
+		fn do_inc_delegator_reward_and_compound(
+			acc: &T::AccountId,
+			stake: BalanceOf<T>,
+			col: &T::AccountId,
+			commission: Perquintill,
+		) -> BalanceOf<T> {
+			let before = Rewards::<T>::get(acc);
 
-			let mut total_unlocked: BalanceOf<T> = Zero::zero();
-			let mut total_locked: BalanceOf<T> = Zero::zero();
-			let mut expired = Vec::new();
+			Self::do_inc_delegator_reward(acc, stake, col, commission);
 
-			// check potential unlocks
-			for (block_number, locked_balance) in unstaking.clone().into_iter() {
-				if block_number <= now {
-					expired.push(block_number);
-					total_unlocked = total_unlocked.saturating_add(locked_balance);
-				} else {
-					total_locked = total_locked.saturating_add(locked_balance);
-				}
+			let reward = Rewards::<T>::get(acc).saturating_sub(before);
+			Self::do_compound(acc, col, reward);
+
+			reward
+		}
+
+		/// Restake the auto-compounding share of the given, newly accumulated
+		/// rewards of an account into their stake for the collator candidate.
+		/// Accounts whose reward destination is `Staked` restake everything.
+		///
+		/// The restaked amount is paid out of the treasury and locked right
+		/// away. It is capped such that the resulting stake does not exceed
+		/// `MaxCollatorCandidateStake`. Nothing is restaked if the candidate is
+		/// leaving or the account does not stake for them (anymore).
+		///
+		/// NOTE: The rewards for the stake which is increased must have been
+		/// incremented before.
+		///
+		/// Emits `RewardsCompounded`.
+		fn do_compound(acc: &T::AccountId, collator: &T::AccountId, reward: BalanceOf<T>) {
+			let percentage = if RewardDestinations::<T>::get(acc) == RewardDestination::Staked {
+				Perquintill::one()
+			} else {
+				AutoCompound::<T>::get(acc)
+			};
+			if percentage.is_zero() || reward.is_zero() {
+				return;
 			}
-			for block_number in expired {
-				unstaking.remove(&block_number);
+
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) if !state.is_leaving() => state,
+				_ => return,
+			};
+			let (maybe_delegator, stake, locked) = if acc == collator {
+				(None, state.stake, state.stake)
+			} else {
+				match DelegatorState::<T>::get(acc) {
+					Some(delegator) => match delegator.delegation(collator.clone()) {
+						Ok(stake) => {
+							let total = delegator.total;
+							(Some(delegator), stake, total)
+						},
+						Err(_) => return,
+					},
+					None => return,
+				}
+			};
+
+			let amount = (percentage * reward)
+				.min(MaxCollatorCandidateStake::<T>::get().saturating_sub(stake));
+			if amount.is_zero() {
+				return;
 			}
 
-			// iterate balance locks to retrieve amount of locked balance
-			let locks = Locks::<T>::get(who);
-			total_locked = if let Some(BalanceLock { amount, .. }) =
-				locks.iter().find(|l| l.id == STAKING_ID)
+			// pay out the restaked rewards
+			if T::Currency::transfer(
+				&T::TreasuryAccount::get(),
+				acc,
+				amount,
+				frame_support::traits::ExistenceRequirement::KeepAlive,
+			)
+			.is_err()
 			{
-				amount.saturating_sub(total_unlocked.into()).into()
+				return;
+			}
+			Rewards::<T>::mutate(acc, |r| *r = r.saturating_sub(amount));
+			T::OnStakingUpdate::on_rewards_paid(acc, amount);
+
+			// the rewards have already been paid out, thus failing to lock them
+			// equals claiming them
+			if Self::increase_lock(acc, locked.saturating_add(amount), amount, None).is_err() {
+				return;
+			}
+
+			let CandidateOf::<T, _> { stake: old_stake, total: old_total, .. } = state;
+			if let Some(mut delegator) = maybe_delegator {
+				let _ = delegator.inc_delegation(collator.clone(), amount);
+				state.inc_delegator(acc.clone(), amount);
+				DelegatorState::<T>::insert(acc, delegator);
+				T::OnStakingUpdate::on_delegation_increased(
+					acc,
+					collator,
+					stake,
+					stake.saturating_add(amount),
+				);
 			} else {
-				// should never fail to find the lock since we checked whether unstaking is not
-				// empty but let's be safe
-				Zero::zero()
+				state.stake_more(amount);
+			}
+
+			// update top candidates and total amount at stake
+			if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					state.stake,
+					state.total - state.stake,
+				);
+			}
+			CandidatePool::<T>::insert(collator, state);
+
+			Self::deposit_event(Event::RewardsCompounded(acc.clone(), collator.clone(), amount));
+		}
+
+		/// Compare the number of blocks authored by each collator of the
+		/// ending session with their expected share and apply the configured
+		/// penalty to collators which missed `max_missed_rounds` rounds in a
+		/// row.
+		///
+		/// Penalties only affect the selection of collators for upcoming
+		/// sessions since the collators of the next session have already been
+		/// chosen.
+		///
+		/// Returns the number of collators which were checked.
+		fn check_liveness(
+			authored: &BTreeMap<T::AccountId, u32>,
+			validators: &[<T as pallet_session::Config>::ValidatorId],
+		) -> u32 {
+			let config = match LivenessConfiguration::<T>::get() {
+				Some(config) => config,
+				None => return 0,
 			};
 
-			if total_locked.is_zero() {
-				T::Currency::remove_lock(STAKING_ID, who);
-				<Unstaking<T>>::remove(who);
-			} else {
-				T::Currency::set_lock(STAKING_ID, who, total_locked, WithdrawReasons::all());
-				<Unstaking<T>>::insert(who, unstaking);
+			let total_authored = authored.values().fold(0u32, |acc, n| acc.saturating_add(*n));
+			if validators.is_empty() || total_authored.is_zero() {
+				return 0;
 			}
 
-			Ok(unstaking_len)
-		}
+			// candidates which are leaving or have been removed and invulnerables are not
+			// penalized
+			let invulnerables = Invulnerables::<T>::get();
+			let collators: Vec<T::AccountId> = TopCandidates::<T>::get()
+				.into_iter()
+				.map(|stake| stake.owner)
+				.filter(|acc| !invulnerables.contains(acc))
+				.filter(|acc| {
+					<T as pallet_session::Config>::ValidatorIdOf::convert(acc.clone())
+						.map_or(false, |id| validators.contains(&id))
+				})
+				.collect();
 
-		/// Checks whether a delegator can still delegate in this round, e.g.,
-		/// if they have not delegated MaxDelegationsPerRound many times
-		/// already in this round.
-		fn get_delegation_counter(
-			delegator: &T::AccountId,
-		) -> Result<DelegationCounter, DispatchError> {
-			let last_delegation = <LastDelegation<T>>::get(delegator);
-			let round = <Round<T>>::get();
+			// every collator is expected to author the same number of blocks
+			let expected = total_authored / validators.len().saturated_into::<u32>();
+			let min_authored = config.min_authored * expected;
 
-			// reset counter if the round advanced since last delegation
-			let counter =
-				if last_delegation.round < round.current { 0u32 } else { last_delegation.counter };
+			for collator in collators.iter() {
+				if authored.get(collator).copied().unwrap_or_default() >= min_authored {
+					MissedRounds::<T>::remove(collator);
+					continue;
+				}
 
-			ensure!(
-				counter < T::MaxDelegationsPerRound::get(),
-				Error::<T>::DelegationsPerRoundExceeded
-			);
+				let missed = MissedRounds::<T>::mutate(collator, |missed| {
+					*missed = missed.saturating_add(1);
+					*missed
+				});
+				if missed >= config.max_missed_rounds {
+					match config.penalty {
+						LivenessPenalty::Idle => Self::do_idle_candidate(collator, missed),
+						LivenessPenalty::Slash(share) => Self::do_slash_candidate(collator, share),
+					}
+					MissedRounds::<T>::remove(collator);
+				}
+			}
 
-			Ok(DelegationCounter { round: round.current, counter: counter.saturating_add(1) })
+			collators.len().saturated_into()
 		}
 
-		/// Calculates the network rewards per block with the current data and
-		/// issues these rewards to the network. The imbalance will be handled
-		/// in `on_initialize` by adding it to the free balance of
-		/// `NetworkRewardBeneficiary`.
-		///
-		/// Over the course of an entire year, the network rewards equal the
-		/// maximum annual collator staking rewards multiplied with the
-		/// NetworkRewardRate. E.g., assuming 10% annual collator reward rate,
-		/// 10% max staking rate, 200k KILT max collator stake and 30 collators:
-		/// NetworkRewards = NetworkRewardRate * 10% * 10% * 200_000 KILT * 30
+		/// Store the snapshot of the collators selected for the round
+		/// `round` and remove the snapshot which exceeds the retention of
+		/// `MaxRoundSnapshots` rounds.
 		///
-		/// The expected rewards are the product of
-		///  * the current total maximum collator rewards
-		///  * and the configured NetworkRewardRate
+		/// The number of authored blocks and the rewards are added once the
+		/// round ends, see [Self::complete_round_snapshot].
 		///
-		/// `col_reward_rate_per_block * col_max_stake * max_num_of_collators *
-		/// NetworkRewardRate`
-		fn issue_network_reward() -> NegativeImbalanceOf<T> {
-			// Multiplication with Perquintill cannot overflow
-			let max_col_rewards = InflationConfig::<T>::get().collator.reward_rate.per_block
-				* MaxCollatorCandidateStake::<T>::get()
-				* MaxSelectedCandidates::<T>::get().into();
-			let network_reward = T::NetworkRewardRate::get() * max_col_rewards;
+		/// Does nothing if `MaxRoundSnapshots` is zero.
+		fn record_round_snapshot(round: SessionIndex, collators: &[T::AccountId]) {
+			let max_snapshots = T::MaxRoundSnapshots::get();
+			if max_snapshots.is_zero() {
+				return;
+			}
 
-			T::Currency::issue(network_reward)
+			let top_candidates = TopCandidates::<T>::get();
+			let snapshot: BoundedVec<_, T::MaxTopCandidates> = collators
+				.iter()
+				.map(|collator| CollatorSnapshot {
+					collator: collator.clone(),
+					// invulnerables are not necessarily collator candidates
+					total: top_candidates
+						.iter()
+						.find(|stake| &stake.owner == collator)
+						.map(|stake| stake.amount)
+						.unwrap_or_default(),
+					blocks_authored: 0,
+					collator_rewards: BalanceOf::<T>::zero(),
+					delegator_rewards: BalanceOf::<T>::zero(),
+				})
+				.collect::<Vec<_>>()
+				.try_into()
+				// the number of selected collators is bounded by MaxTopCandidates
+				.unwrap_or_default();
+
+			RoundSnapshots::<T>::insert(round, snapshot);
+			if let Some(outdated) = round.checked_sub(max_snapshots) {
+				RoundSnapshots::<T>::remove(outdated);
+			}
 		}
 
-		/// Calculates the collator staking rewards for authoring `multiplier`
-		/// many blocks based on the given stake.
+		/// Add the number of authored blocks of the ending round `round` to
+		/// its snapshot.
 		///
-		/// Depends on the current total issuance and staking reward
-		/// configuration for collators.
-		fn calc_block_rewards_collator(
-			stake: BalanceOf<T>,
-			multiplier: BalanceOf<T>,
-		) -> BalanceOf<T> {
-			let total_issuance = T::Currency::total_issuance();
-			let TotalStake { collators: total_collators, .. } = <TotalCollatorStake<T>>::get();
-			let staking_rate = Perquintill::from_rational(total_collators, total_issuance);
-
-			InflationConfig::<T>::get().collator.compute_reward::<T>(
-				stake,
-				staking_rate,
-				multiplier,
-			)
-		}
-
-		/// Calculates the delegator staking rewards for `multiplier` many
-		/// blocks based on the given stake.
+		/// The rewards are added whenever they are credited, see
+		/// [Self::note_credited_rewards].
 		///
-		/// Depends on the current total issuance and staking reward
-		/// configuration for delegators.
-		fn calc_block_rewards_delegator(
-			stake: BalanceOf<T>,
-			multiplier: BalanceOf<T>,
-		) -> BalanceOf<T> {
-			let total_issuance = T::Currency::total_issuance();
-			let TotalStake { delegators: total_delegators, .. } = <TotalCollatorStake<T>>::get();
-			let staking_rate = Perquintill::from_rational(total_delegators, total_issuance);
-
-			InflationConfig::<T>::get().delegator.compute_reward::<T>(
-				stake,
-				staking_rate,
-				multiplier,
-			)
+		/// Returns the number of collators in the snapshot.
+		fn complete_round_snapshot(
+			round: SessionIndex,
+			authored: &BTreeMap<T::AccountId, u32>,
+		) -> u32 {
+			RoundSnapshots::<T>::mutate(round, |snapshot| {
+				for entry in snapshot.iter_mut() {
+					entry.blocks_authored =
+						authored.get(&entry.collator).copied().unwrap_or_default();
+				}
+				snapshot.len().saturated_into()
+			})
 		}
 
-		/// Calculates the staking rewards for a given account address.
+		/// Mark an active collator candidate as idle and remove them from the
+		/// TopCandidates such that they are not selected anymore.
 		///
-		/// At least used in Runtime API.
-		pub fn get_unclaimed_staking_rewards(acc: &T::AccountId) -> BalanceOf<T> {
-			let mut reward_count = RewardCount::<T>::get(acc);
-			let rewards = Rewards::<T>::get(acc);
+		/// Keeps the candidate if the TopCandidates would fall below
+		/// `MinRequiredCollators`.
+		///
+		/// Emits `CollatorWentIdle`.
+		fn do_idle_candidate(collator: &T::AccountId, missed: u32) {
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) if state.is_active() => state,
+				_ => return,
+			};
+			let mut candidates = TopCandidates::<T>::get();
+			if candidates.len().saturated_into::<u32>() <= T::MinRequiredCollators::get() {
+				log::warn!("keeping idle collator {:?} because of too few candidates", collator);
+				return;
+			}
 
-			// delegators and collators need to be handled differently
-			if let Some(delegator_state) = DelegatorState::<T>::get(acc) {
-				// delegator reward counts do not automatically increment in order to be
-				// scalable, see [increment_delegator_rewards] for details
-				// therefore, we need to query the counter of the collator
-				// (`delegator_stare.owner`)
-				reward_count = reward_count.saturating_add(
-					delegator_state.owner.map(RewardCount::<T>::get).unwrap_or(0u32),
-				);
-				let stake = delegator_state.amount;
-				// rewards += stake * (self_count + collator_count) * delegator_reward_rate
-				rewards
-					.saturating_add(Self::calc_block_rewards_delegator(stake, reward_count.into()))
-			} else if Self::is_active_candidate(acc).is_some() {
-				let stake = CandidatePool::<T>::get(acc)
-					.map(|state| state.stake)
-					.unwrap_or_else(BalanceOf::<T>::zero);
-				// rewards += stake * self_count * collator_reward_rate
-				rewards
-					.saturating_add(Self::calc_block_rewards_collator(stake, reward_count.into()))
-			} else {
-				BalanceOf::<T>::zero()
+			state.go_idle();
+			if candidates
+				.remove(&Stake { owner: collator.clone(), amount: state.total })
+				.is_some()
+			{
+				// update top candidates
+				TopCandidates::<T>::put(candidates);
+				Self::deposit_event(Event::LeftTopCandidates(collator.clone()));
+				// update total amount at stake from scratch
+				Self::update_total_stake();
 			}
+			CandidatePool::<T>::insert(collator, state);
+
+			Self::deposit_event(Event::CollatorWentIdle(collator.clone(), missed));
 		}
 
-		/// Increment the accumulated rewards of a collator by consuming their
-		/// current rewards counter.
+		/// Slash the given share of the collator candidate's own stake and
+		/// deposit it into the treasury.
 		///
-		/// Moreover, automatically increments all associated delegators since
-		/// their reward increment is dependend on the collator reward counter.
+		/// The remaining stake never falls below the minimum collator stake of
+		/// the staking configuration. The rewards which the collator and their
+		/// delegators earned before are incremented beforehand.
 		///
-		/// Resets all reward counters of the collator and their delegators to
-		/// zero.
-		fn do_inc_collator_reward(collator: &T::AccountId, stake: BalanceOf<T>) -> usize {
-			// get reward counters
-			let col_reward_count = RewardCount::<T>::get(collator);
-
-			// set reward data for collator
-			Rewards::<T>::mutate(collator, |reward| {
-				*reward = reward.saturating_add(Self::calc_block_rewards_collator(
-					stake,
-					col_reward_count.into(),
-				));
-			});
-
-			// set reward data for delegators
-			let num_delegators = if let Some(state) = CandidatePool::<T>::get(collator.clone()) {
-				let num_delegators = state.delegators.len();
-				for Stake { owner, amount } in state.delegators {
-					Self::do_inc_delegator_reward(&owner, amount, collator);
-					// Reset delegator counter since collator counter will be reset
-					RewardCount::<T>::insert(owner, 0);
-				}
-				num_delegators
-			} else {
-				0usize
+		/// Emits `CollatorSlashed`.
+		fn do_slash_candidate(collator: &T::AccountId, share: Perquintill) {
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) => state,
+				None => return,
 			};
+			let min_collator_stake = StakingConfiguration::<T>::get().min_collator_stake;
+			let amount = (share * state.stake).min(state.stake.saturating_sub(min_collator_stake));
+			if amount.is_zero() {
+				return;
+			}
 
-			// Reset collator reward count
-			RewardCount::<T>::insert(collator, 0);
+			// the rewards earned so far are based on the stake before the slash
+			Self::do_inc_collator_reward(collator, state.stake);
 
-			num_delegators
-		}
+			let (imbalance, _) = T::Currency::slash(collator, amount);
+			let slashed = imbalance.peek();
+			T::Currency::resolve_creating(&T::TreasuryAccount::get(), imbalance);
 
-		/// Increment the accumulated rewards of a delegator by consuming their
-		/// current rewards counter. The counter will be reset to the collator
-		/// counter.
-		fn do_inc_delegator_reward(
-			acc: &T::AccountId,
-			stake: BalanceOf<T>,
-			col: &T::AccountId,
-		) -> Weight {
-			// get reward counters
-			let del_reward_count = RewardCount::<T>::get(acc);
-			let col_reward_count = RewardCount::<T>::get(col);
-			let diff = col_reward_count.saturating_sub(del_reward_count);
+			// reduce the lock by the slashed amount
+			let locks = Locks::<T>::get(collator);
+			if let Some(BalanceLock { amount: locked, .. }) =
+				locks.iter().find(|l| l.id == STAKING_ID)
+			{
+				T::Currency::set_lock(
+					STAKING_ID,
+					collator,
+					locked.saturating_sub(slashed.into()).into(),
+					WithdrawReasons::all(),
+				);
+			}
 
-			// only update if collator has higher reward count
-			if diff > 0 {
-				Rewards::<T>::mutate(acc, |reward| {
-					*reward = reward
-						.saturating_add(Self::calc_block_rewards_delegator(stake, diff.into()));
-				});
-				// align with collator counter such that incrementing would
-				// lead to 0 rewards until the collator counter increases
-				RewardCount::<T>::insert(acc, col_reward_count);
+			let CandidateOf::<T, _> { stake: old_stake, total: old_total, .. } = state;
+			state.stake = state.stake.saturating_sub(slashed);
+			state.total = state.total.saturating_sub(slashed);
 
-				// 4 reads from reward calc
-				T::DbWeight::get().reads_writes(6, 2)
-			} else {
-				T::DbWeight::get().reads(2)
+			// update top candidates and total amount at stake
+			if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					state.stake,
+					state.total - state.stake,
+				);
 			}
+			CandidatePool::<T>::insert(collator, state);
+
+			Self::deposit_event(Event::CollatorSlashed(collator.clone(), slashed));
 		}
 
 		/// Calculates the current staking and reward rates for collators and
@@ -2603,6 +4909,155 @@ pub mod pallet {
 				delegator_reward_rate,
 			}
 		}
+
+		/// Returns the snapshot of the collators selected for the given round.
+		///
+		/// The snapshot is empty if the round is older than
+		/// `MaxRoundSnapshots` rounds or has not started yet.
+		///
+		/// At least used in Runtime API.
+		pub fn get_round_snapshot(round: SessionIndex) -> Vec<CollatorSnapshotOf<T>> {
+			RoundSnapshots::<T>::get(round).into_inner()
+		}
+
+		/// Returns the current commission of a collator candidate as well as
+		/// their pending commission change, if any.
+		///
+		/// At least used in Runtime API.
+		pub fn get_collator_commission(acc: &T::AccountId) -> Option<CollatorCommission> {
+			CandidatePool::<T>::get(acc).map(|state| CollatorCommission {
+				commission: state.commission,
+				pending: CommissionChangeRequests::<T>::get(acc)
+					.map(|request| (request.commission, request.effective_round)),
+			})
+		}
+
+		/// Returns all collator candidates with their status and total
+		/// backing.
+		///
+		/// At least used in Runtime API.
+		pub fn get_candidates() -> Vec<CandidateInfo<T::AccountId, BalanceOf<T>>> {
+			let selected = Self::selected_candidates();
+			CandidatePool::<T>::iter()
+				.map(|(id, state)| CandidateInfo {
+					is_selected: selected.contains(&id),
+					id,
+					status: state.status.into(),
+					stake: state.stake,
+					total: state.total,
+					delegators: state.delegators.len().saturated_into(),
+					commission: state.commission,
+				})
+				.collect()
+		}
+
+		/// Returns the delegations of an account.
+		///
+		/// At least used in Runtime API.
+		pub fn get_delegations(
+			acc: &T::AccountId,
+		) -> Vec<DelegationInfo<T::AccountId, BalanceOf<T>>> {
+			DelegatorState::<T>::get(acc)
+				.map(|state| {
+					state
+						.delegations
+						.into_iter()
+						.map(|stake| DelegationInfo { collator: stake.owner, amount: stake.amount })
+						.collect()
+				})
+				.unwrap_or_default()
+		}
+
+		/// Returns the unstaked funds of an account together with the block
+		/// from which on they can be unlocked.
+		///
+		/// At least used in Runtime API.
+		pub fn get_unstaking(
+			acc: &T::AccountId,
+		) -> Vec<UnstakingInfo<BlockNumberFor<T>, BalanceOf<T>>> {
+			Unstaking::<T>::get(acc)
+				.into_iter()
+				.map(|(unlock_block, amount)| UnstakingInfo { unlock_block, amount })
+				.collect()
+		}
+
+		/// Estimates the annual rewards of a collator candidate and their
+		/// delegators relative to their stake based on the current staking
+		/// rates and commission.
+		///
+		/// Candidates which would not be selected in the next round do not
+		/// receive any rewards.
+		///
+		/// At least used in Runtime API.
+		pub fn get_collator_apy(acc: &T::AccountId) -> Option<CollatorApy> {
+			let state = CandidatePool::<T>::get(acc)?;
+			if !Self::selected_candidates().contains(acc) {
+				return Some(CollatorApy {
+					collator: Perquintill::zero(),
+					delegators: Perquintill::zero(),
+				});
+			}
+
+			let rates = Self::get_staking_rates();
+			// the commission is paid from the rewards of the delegators
+			let delegator_rewards =
+				rates.delegator_reward_rate * state.total.saturating_sub(state.stake);
+			let collator_rewards = (rates.collator_reward_rate * state.stake)
+				.saturating_add(state.commission * delegator_rewards);
+
+			Some(CollatorApy {
+				collator: Perquintill::from_rational(collator_rewards, state.stake),
+				delegators: rates.delegator_reward_rate *
+					Perquintill::one().saturating_sub(state.commission),
+			})
+		}
+
+		/// Returns the first block of the next round.
+		///
+		/// At least used in Runtime API.
+		pub fn get_next_round_start() -> BlockNumberFor<T> {
+			let round = Round::<T>::get();
+			round.first.saturating_add(round.length)
+		}
+
+		/// Returns whether delegating `amount` to the collator candidate would
+		/// place the delegator among the top delegators of the candidate.
+		///
+		/// If the account already delegates to the candidate, `amount` replaces
+		/// their current stake.
+		///
+		/// At least used in Runtime API.
+		pub fn would_enter_top_delegators(
+			acc: &T::AccountId,
+			collator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> bool {
+			let config = StakingConfiguration::<T>::get();
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) if !state.is_leaving() => state,
+				_ => return false,
+			};
+			if amount < config.min_delegator_stake {
+				return false;
+			}
+
+			state.delegators.remove(&Stake { owner: acc.clone(), amount });
+			state
+				.delegators
+				.try_insert_replace_with_limit(
+					Stake { owner: acc.clone(), amount },
+					config.max_delegators_per_collator,
+				)
+				.is_ok()
+		}
+
+		/// Returns the latest delegations of an account which have been
+		/// replaced by higher delegations.
+		///
+		/// At least used in Runtime API.
+		pub fn get_displaced_delegations(acc: &T::AccountId) -> Vec<DisplacedDelegationOf<T>> {
+			DisplacedDelegations::<T>::get(acc).into_inner()
+		}
 	}
 
 	impl<T> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T>
@@ -2611,19 +5066,27 @@ pub mod pallet {
 	{
 		/// Increments the reward counter of the block author by the current
 		/// number of collators in the session.
+		///
+		/// Invulnerables are not rewarded.
 		fn note_author(author: T::AccountId) {
 			// should always include state except if the collator has been forcedly removed
 			// via `force_remove_candidate` in the current or previous round
-			if CandidatePool::<T>::get(&author).is_some() {
-				// necessary to compensate for a potentially fluctuating number of collators
-				let authors = pallet_session::Pallet::<T>::validators();
-				RewardCount::<T>::mutate(&author, |count| {
-					*count = count.saturating_add(authors.len().saturated_into::<u32>());
+			let is_invulnerable = Invulnerables::<T>::get().contains(&author);
+			if is_invulnerable || CandidatePool::<T>::contains_key(&author) {
+				if !is_invulnerable {
+					// necessary to compensate for a potentially fluctuating number of collators
+					let authors = pallet_session::Pallet::<T>::validators();
+					RewardCount::<T>::mutate(&author, |count| {
+						*count = count.saturating_add(authors.len().saturated_into::<u32>());
+					});
+				}
+				BlocksAuthored::<T>::mutate(&author, |count| {
+					*count = count.saturating_add(1);
 				});
 			}
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::DbWeight::get().reads_writes(2, 1),
+				T::DbWeight::get().reads_writes(4, 2),
 				DispatchClass::Mandatory,
 			);
 		}
@@ -2655,12 +5118,46 @@ pub mod pallet {
 				log::error!("💥 keeping old session because of empty collator set!");
 				None
 			} else {
+				Pallet::<T>::record_round_snapshot(new_index, &collators);
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(
+					T::DbWeight::get().reads_writes(1, 2),
+					DispatchClass::Mandatory,
+				);
 				Some(collators)
 			}
 		}
 
-		fn end_session(_end_index: SessionIndex) {
-			// we too are not caring.
+		fn end_session(end_index: SessionIndex) {
+			let authored: BTreeMap<T::AccountId, u32> = BlocksAuthored::<T>::drain().collect();
+			let validators = pallet_session::Pallet::<T>::validators();
+
+			// add authored blocks to the snapshot of the ending session
+			let s: u64 = Pallet::<T>::complete_round_snapshot(end_index, &authored).into();
+			// distribute the extra rewards of the ending session
+			let e: u64 = Pallet::<T>::distribute_extra_rewards(end_index, &authored).into();
+			let c: u64 = ExtraRewardRates::<T>::decode_len().unwrap_or_default().saturated_into();
+			// penalize collators of the ending session which authored too few blocks
+			let n: u64 = Pallet::<T>::check_liveness(&authored, &validators).into();
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				// worst case includes applying the penalty to every collator
+				T::DbWeight::get()
+					.reads_writes(
+						n.saturating_mul(5).saturating_add(s).saturating_add(7),
+						n.saturating_mul(6).saturating_add(2),
+					)
+					.saturating_add(T::DbWeight::get().reads_writes(
+						e.saturating_mul(c.saturating_add(1)).saturating_add(c.saturating_mul(3)),
+						e.saturating_mul(c).saturating_add(c),
+					))
+					// incrementing the rewards of slashed collators and their delegators
+					.saturating_add(T::DbWeight::get().reads_writes(7, 4).saturating_mul(
+						n.saturating_mul(
+							u64::from(T::MaxDelegatorsPerCollator::get()).saturating_add(1),
+						),
+					)),
+				DispatchClass::Mandatory,
+			);
 		}
 
 		fn start_session(_start_index: SessionIndex) {
@@ -2688,6 +5185,7 @@ pub mod pallet {
 				<ForceNewRound<T>>::put(false);
 				round.update(now);
 				<Round<T>>::put(round);
+				T::OnStakingUpdate::on_new_round(round.current);
 				Self::deposit_event(Event::NewRound(round.first, round.current));
 				true
 			} else {
@@ -2724,4 +5222,16 @@ pub mod pallet {
 			)
 		}
 	}
+
+	impl<T: Config> VotingPowerProvider<T::AccountId, BalanceOf<T>> for Pallet<T> {
+		/// The own stake of a collator candidate or the total stake of a
+		/// delegator. Unstaked funds which are still locked do not count.
+		fn voting_power(who: &T::AccountId) -> BalanceOf<T> {
+			if let Some(state) = CandidatePool::<T>::get(who) {
+				state.stake
+			} else {
+				DelegatorState::<T>::get(who).map(|state| state.total).unwrap_or_default()
+			}
+		}
+	}
 }
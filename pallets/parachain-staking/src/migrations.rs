@@ -0,0 +1,304 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2022 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Storage migrations for the parachain staking pallet.
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{Config, Pallet};
+
+/// Adds the commission to all collator candidates.
+pub mod v8 {
+	use super::*;
+	use parity_scale_codec::Decode;
+	use sp_runtime::Perquintill;
+
+	use crate::{
+		set::OrderedSet,
+		types::{BalanceOf, Candidate, CandidateStatus, Stake},
+		CandidatePool,
+	};
+
+	/// The candidate layout before the commission was introduced.
+	#[derive(Decode)]
+	struct OldCandidate<AccountId, Balance, MaxDelegatorsPerCandidate>
+	where
+		AccountId: Eq + Ord,
+		Balance: Eq + Ord,
+		MaxDelegatorsPerCandidate: Get<u32>,
+	{
+		id: AccountId,
+		stake: Balance,
+		delegators: OrderedSet<Stake<AccountId, Balance>, MaxDelegatorsPerCandidate>,
+		total: Balance,
+		status: CandidateStatus,
+	}
+
+	pub struct MigrateToV8<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(7) {
+				log::info!("parachain-staking: skipping migration to v8");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut num_candidates = 0u64;
+			CandidatePool::<T>::translate::<
+				OldCandidate<T::AccountId, BalanceOf<T>, T::MaxDelegatorsPerCollator>,
+				_,
+			>(|_, old| {
+				num_candidates = num_candidates.saturating_add(1);
+				Some(Candidate {
+					id: old.id,
+					stake: old.stake,
+					delegators: old.delegators,
+					total: old.total,
+					status: old.status,
+					commission: Perquintill::zero(),
+				})
+			});
+			StorageVersion::new(8).put::<Pallet<T>>();
+
+			log::info!("parachain-staking: migrated {} candidates to v8", num_candidates);
+			T::DbWeight::get()
+				.reads_writes(num_candidates.saturating_add(1), num_candidates.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			use parity_scale_codec::Encode;
+
+			Ok(CandidatePool::<T>::count().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let old_count: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "parachain-staking: failed to decode candidate count")?;
+			frame_support::ensure!(
+				CandidatePool::<T>::iter_values().count() as u32 == old_count,
+				"parachain-staking: candidates got lost during the migration"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == StorageVersion::new(8),
+				"parachain-staking: storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Allows delegators to delegate multiple collator candidates and moves the
+/// reward counters of delegators to their delegations.
+///
+/// Delegator entries without a collator cannot be represented in the new
+/// layout. They are removed and their stake is unlocked, except for the part
+/// which is still waiting in `Unstaking`.
+pub mod v9 {
+	use super::*;
+	use frame_support::traits::{LockableCurrency, WithdrawReasons};
+	use sp_runtime::traits::{Saturating, Zero};
+	use sp_std::vec::Vec;
+
+	use crate::{
+		types::{BalanceOf, Delegator, Stake},
+		DelegationRewardCount, DelegatorState, RewardCount, Unstaking, STAKING_ID,
+	};
+
+	/// The delegator layout before multiple delegations were introduced.
+	type OldDelegator<AccountId, Balance> = Stake<Option<AccountId>, Balance>;
+
+	pub struct MigrateToV9<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToV9<T> {
+		/// Removes the staking lock of a delegator without collator, keeping
+		/// only the amount which is still pending in `Unstaking`.
+		fn unlock_orphaned_stake(who: &T::AccountId) {
+			let unstaking: BalanceOf<T> = Unstaking::<T>::get(who)
+				.into_iter()
+				.fold(Zero::zero(), |acc, (_, amount)| acc.saturating_add(amount));
+			if unstaking.is_zero() {
+				T::Currency::remove_lock(STAKING_ID, who);
+			} else {
+				T::Currency::set_lock(STAKING_ID, who, unstaking, WithdrawReasons::all());
+			}
+			RewardCount::<T>::remove(who);
+		}
+	}
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(8) {
+				log::info!("parachain-staking: skipping migration to v9");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut num_delegators = 0u64;
+			let mut orphaned = Vec::new();
+			DelegatorState::<T>::translate::<OldDelegator<T::AccountId, BalanceOf<T>>, _>(
+				|delegator, old| {
+					num_delegators = num_delegators.saturating_add(1);
+					let Some(collator) = old.owner else {
+						orphaned.push(delegator);
+						return None;
+					};
+
+					let mut state = Delegator::default();
+					let stake = Stake { owner: collator, amount: old.amount };
+					if state.add_delegation(stake).is_err() {
+						orphaned.push(delegator);
+						return None;
+					}
+					Some(state)
+				},
+			);
+
+			// the reward counter now belongs to the delegation
+			let mut num_moved = 0u64;
+			for (delegator, state) in DelegatorState::<T>::iter() {
+				if let Some(stake) = state.delegations[..].first() {
+					DelegationRewardCount::<T>::insert(
+						&delegator,
+						&stake.owner,
+						RewardCount::<T>::take(&delegator),
+					);
+					num_moved = num_moved.saturating_add(1);
+				}
+			}
+
+			let num_orphaned = orphaned.len() as u64;
+			for delegator in orphaned.iter() {
+				log::warn!(
+					"parachain-staking: removing delegator {:?} without collator and unlocking their stake",
+					delegator
+				);
+				Self::unlock_orphaned_stake(delegator);
+			}
+			StorageVersion::new(9).put::<Pallet<T>>();
+
+			log::info!(
+				"parachain-staking: migrated {} delegators to v9, removed {} without collator",
+				num_delegators.saturating_sub(num_orphaned),
+				num_orphaned
+			);
+			T::DbWeight::get().reads_writes(
+				num_delegators
+					.saturating_add(num_moved.saturating_mul(2))
+					.saturating_add(num_orphaned.saturating_mul(3))
+					.saturating_add(1),
+				num_delegators
+					.saturating_add(num_moved.saturating_mul(2))
+					.saturating_add(num_orphaned.saturating_mul(2))
+					.saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			use parity_scale_codec::Encode;
+
+			let mut with_collator = 0u32;
+			let mut orphaned = Vec::<T::AccountId>::new();
+			for delegator in DelegatorState::<T>::iter_keys() {
+				let old = frame_support::storage::unhashed::get::<
+					OldDelegator<T::AccountId, BalanceOf<T>>,
+				>(&DelegatorState::<T>::hashed_key_for(&delegator));
+				if matches!(old, Some(Stake { owner: Some(_), .. })) {
+					with_collator = with_collator.saturating_add(1);
+				} else {
+					orphaned.push(delegator);
+				}
+			}
+
+			Ok((with_collator, orphaned).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			use parity_scale_codec::Decode;
+
+			let (with_collator, orphaned): (u32, Vec<T::AccountId>) =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "parachain-staking: failed to decode delegator count")?;
+			frame_support::ensure!(
+				DelegatorState::<T>::iter_values().count() as u32 == with_collator,
+				"parachain-staking: delegators got lost during the migration"
+			);
+			frame_support::ensure!(
+				orphaned.iter().all(|d| {
+					!DelegatorState::<T>::contains_key(d) && RewardCount::<T>::get(d).is_zero()
+				}),
+				"parachain-staking: delegators without collator were not removed"
+			);
+			frame_support::ensure!(
+				DelegatorState::<T>::iter_keys().all(|d| RewardCount::<T>::get(&d).is_zero()),
+				"parachain-staking: reward counters of delegators were not moved"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == StorageVersion::new(9),
+				"parachain-staking: storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Moves the staking parameters from the runtime constants into storage.
+pub mod v10 {
+	use super::*;
+
+	use crate::{DefaultStakingConfig, StakingConfiguration};
+
+	pub struct MigrateToV10<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV10<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(9) {
+				log::info!("parachain-staking: skipping migration to v10");
+				return T::DbWeight::get().reads(1);
+			}
+
+			// seed the staking configuration with the current constants
+			StakingConfiguration::<T>::put(DefaultStakingConfig::<T>::get());
+			StorageVersion::new(10).put::<Pallet<T>>();
+
+			log::info!("parachain-staking: migrated staking configuration to v10");
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			frame_support::ensure!(
+				StakingConfiguration::<T>::get() == DefaultStakingConfig::<T>::get(),
+				"parachain-staking: staking configuration does not match the constants"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == StorageVersion::new(10),
+				"parachain-staking: storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
@@ -23,8 +23,10 @@ use super::*;
 use crate::{self as stake, types::NegativeImbalanceOf};
 use frame_support::{
 	assert_ok, construct_runtime, parameter_types,
-	traits::{Currency, OnFinalize, OnInitialize, OnUnbalanced},
+	traits::{Currency, Everything, OnFinalize, OnInitialize, OnUnbalanced},
+	PalletId,
 };
+use orml_traits::parameter_type_with_key;
 use pallet_authorship::EventHandler;
 use sp_consensus_aura::sr25519::AuthorityId;
 use sp_core::H256;
@@ -34,12 +36,14 @@ use sp_runtime::{
 	traits::{BlakeTwo256, ConstU32, ConvertInto, IdentityLookup, OpaqueKeys},
 	BuildStorage, Perbill, Perquintill,
 };
-use sp_std::fmt::Debug;
+use sp_staking::SessionIndex;
+use sp_std::{cell::RefCell, fmt::Debug};
 
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
 pub(crate) type Balance = u128;
 pub(crate) type AccountId = u64;
 pub(crate) type BlockNumber = u64;
+pub(crate) type CurrencyId = u32;
 
 pub(crate) const MILLI_KILT: Balance = 10u128.pow(12);
 pub(crate) const MAX_COLLATOR_STAKE: Balance = 200_000 * 1000 * MILLI_KILT;
@@ -55,6 +59,7 @@ construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Tokens: orml_tokens,
 		Aura: pallet_aura,
 		Session: pallet_session,
 		StakePallet: stake,
@@ -116,6 +121,41 @@ impl pallet_balances::Config for Test {
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
 
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		0
+	};
+}
+
+pub struct CurrencyHooks<T>(sp_std::marker::PhantomData<T>);
+impl<T: orml_tokens::Config>
+	orml_traits::currency::MutationHooks<T::AccountId, T::CurrencyId, T::Balance>
+	for CurrencyHooks<T>
+{
+	type OnDust = orml_tokens::BurnDust<T>;
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = CurrencyHooks<Self>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<0>;
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
 impl pallet_aura::Config for Test {
 	type AuthorityId = AuthorityId;
 	type DisabledValidators = ();
@@ -131,18 +171,22 @@ impl pallet_authorship::Config for Test {
 parameter_types! {
 	pub const MinBlocksPerRound: BlockNumber = 3;
 	pub const StakeDuration: u32 = 2;
+	pub const MaxStakeDuration: u32 = 10;
 	pub const ExitQueueDelay: u32 = 2;
 	pub const DefaultBlocksPerRound: BlockNumber = BLOCKS_PER_ROUND;
 	pub const MinCollators: u32 = 2;
 	pub const MaxDelegationsPerRound: u32 = 2;
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxDelegatorsPerCollator: u32 = 4;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorsPerDelegator: u32 = 4;
 	pub const MinCollatorStake: Balance = 10;
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxCollatorCandidates: u32 = 10;
 	pub const MinDelegatorStake: Balance = 5;
 	pub const MaxUnstakeRequests: u32 = 6;
 	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
+	pub const MaxNetworkRewardRate: Perquintill = Perquintill::from_percent(20);
 	pub const NetworkRewardStart: BlockNumber = 5 * 5 * 60 * 24 * 36525 / 100;
 	pub const CollatorRewardRateDecay: Perquintill = Perquintill::from_percent(98);
 }
@@ -157,8 +201,102 @@ impl OnUnbalanced<NegativeImbalanceOf<Test>> for ToBeneficiary {
 
 parameter_types! {
 	pub const TreasuryAccount: AccountId = TREASURY_ACC;
+	pub const MaxCollatorCommission: Perquintill = Perquintill::from_percent(20);
+	pub const CommissionChangeDelay: u32 = 2;
+	pub const MaxRoundSnapshots: u32 = 3;
+	pub const MaxPayoutStakers: u32 = 4;
+	pub StakeLockPeriods: Vec<(BlockNumber, Perquintill)> = vec![
+		(10, Perquintill::from_percent(10)),
+		(20, Perquintill::from_percent(50)),
+	];
+	pub const ExtraRewardsPotId: PalletId = PalletId(*b"kilt/rwd");
+	pub const MaxExtraRewardCurrencies: u32 = 2;
+	pub static DisplacedDelegatorGracePeriod: bool = false;
+}
+
+/// A change which has been reported via the `OnStakingUpdate` hooks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum StakingUpdate {
+	Created(AccountId, AccountId, Balance),
+	Increased(AccountId, AccountId, Balance, Balance),
+	Reduced(AccountId, AccountId, Balance, Balance),
+	Removed(AccountId, AccountId, Balance),
+	RewardsPaid(AccountId, Balance),
+	NewRound(SessionIndex),
+}
+
+thread_local! {
+	static STAKING_UPDATES: RefCell<Vec<StakingUpdate>> = const { RefCell::new(Vec::new()) };
+	static NEW_ROUNDS: RefCell<u32> = const { RefCell::new(0) };
 }
 
+/// Records all staking updates.
+pub struct RecordStakingUpdates;
+impl OnStakingUpdate<AccountId, Balance> for RecordStakingUpdates {
+	fn on_delegation_created(delegator: &AccountId, collator: &AccountId, amount: Balance) {
+		STAKING_UPDATES.with(|updates| {
+			updates.borrow_mut().push(StakingUpdate::Created(*delegator, *collator, amount))
+		});
+	}
+
+	fn on_delegation_increased(
+		delegator: &AccountId,
+		collator: &AccountId,
+		old: Balance,
+		new: Balance,
+	) {
+		STAKING_UPDATES.with(|updates| {
+			updates
+				.borrow_mut()
+				.push(StakingUpdate::Increased(*delegator, *collator, old, new))
+		});
+	}
+
+	fn on_delegation_reduced(
+		delegator: &AccountId,
+		collator: &AccountId,
+		old: Balance,
+		new: Balance,
+	) {
+		STAKING_UPDATES.with(|updates| {
+			updates
+				.borrow_mut()
+				.push(StakingUpdate::Reduced(*delegator, *collator, old, new))
+		});
+	}
+
+	fn on_delegation_removed(delegator: &AccountId, collator: &AccountId, amount: Balance) {
+		STAKING_UPDATES.with(|updates| {
+			updates.borrow_mut().push(StakingUpdate::Removed(*delegator, *collator, amount))
+		});
+	}
+
+	fn on_rewards_paid(who: &AccountId, amount: Balance) {
+		STAKING_UPDATES
+			.with(|updates| updates.borrow_mut().push(StakingUpdate::RewardsPaid(*who, amount)));
+	}
+
+	fn on_new_round(round: SessionIndex) {
+		STAKING_UPDATES.with(|updates| updates.borrow_mut().push(StakingUpdate::NewRound(round)));
+	}
+}
+
+/// Only counts the started rounds.
+pub struct CountNewRounds;
+impl OnStakingUpdate<AccountId, Balance> for CountNewRounds {
+	fn on_new_round(_round: SessionIndex) {
+		NEW_ROUNDS.with(|rounds| *rounds.borrow_mut() += 1);
+	}
+}
+
+/// Returns and clears the staking updates which have been recorded so far.
+pub(crate) fn staking_updates() -> Vec<StakingUpdate> {
+	STAKING_UPDATES.with(|updates| updates.borrow_mut().drain(..).collect())
+}
+
+pub(crate) fn new_rounds() -> u32 {
+	NEW_ROUNDS.with(|rounds| *rounds.borrow())
+}
 
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -172,18 +310,33 @@ impl Config for Test {
 	type MinRequiredCollators = MinCollators;
 	type MaxDelegationsPerRound = MaxDelegationsPerRound;
 	type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
 	type MinCollatorStake = MinCollatorStake;
 	type MinCollatorCandidateStake = MinCollatorStake;
 	type MaxTopCandidates = MaxCollatorCandidates;
 	type MinDelegatorStake = MinDelegatorStake;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type NetworkRewardRate = NetworkRewardRate;
+	type MaxNetworkRewardRate = MaxNetworkRewardRate;
+	type MaxStakeDuration = MaxStakeDuration;
 	type NetworkRewardStart = NetworkRewardStart;
 	type NetworkRewardBeneficiary = ToBeneficiary;
 	type CollatorRewardRateDecay = CollatorRewardRateDecay;
 	type WeightInfo = ();
 	const BLOCKS_PER_YEAR: BlockNumber = 5 * 60 * 24 * 36525 / 100;
 	type TreasuryAccount = TreasuryAccount;
+	type MaxCollatorCommission = MaxCollatorCommission;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type MaxRoundSnapshots = MaxRoundSnapshots;
+	type MaxPayoutStakers = MaxPayoutStakers;
+	type StakeLockPeriods = StakeLockPeriods;
+	type OnStakingUpdate = (RecordStakingUpdates, CountNewRounds);
+	type DisplacedDelegatorGracePeriod = DisplacedDelegatorGracePeriod;
+	type ExtraRewardCurrency = Tokens;
+	type ExtraRewardsPotId = ExtraRewardsPotId;
+	type MaxExtraRewardCurrencies = MaxExtraRewardCurrencies;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ExtraRewardBenchmarkCurrencyId = ConstU32<1>;
 }
 
 impl_opaque_keys! {
@@ -114,13 +114,34 @@ impl<T: Ord + Clone, S: Get<u32>> OrderedSet<T, S> {
 	///   the lowest element in the set.
 	/// * Err(false) if the element is already in the set.
 	pub fn try_insert_replace(&mut self, value: T) -> Result<Option<T>, bool> {
-		// the highest allowed index
-		let highest_index: usize = S::get().saturating_sub(1).saturated_into();
-		if S::get().is_zero() {
+		self.try_insert_replace_with_limit(value, S::get())
+	}
+
+	/// Inserts an element, if no equal item exist in the set. If the set
+	/// already contains `limit` or more elements, but an element with a lower
+	/// rank is in the set, the element with the lowest rank will be removed
+	/// and the new element will be added. The limit is capped by the maximum
+	/// size of the bounded vec.
+	///
+	/// Returns the same as [OrderedSet::try_insert_replace].
+	pub fn try_insert_replace_with_limit(
+		&mut self,
+		value: T,
+		limit: u32,
+	) -> Result<Option<T>, bool> {
+		let limit: usize = limit.min(S::get()).saturated_into();
+		if limit.is_zero() {
 			return Err(true);
 		}
-		match self.try_insert(value.clone()) {
-			Err(loc) if loc <= highest_index => {
+		if self.len() < limit {
+			return match self.try_insert(value) {
+				Ok(true) => Ok(None),
+				Ok(false) => Err(false),
+				Err(_) => Err(true),
+			};
+		}
+		match self.linear_search(&value) {
+			Err(loc) if loc < self.len() => {
 				// always replace the last element
 				let last_idx = self.len().saturating_sub(1);
 				// accessing by index wont panic since we checked the index, inserting the item
@@ -131,8 +152,7 @@ impl<T: Ord + Clone, S: Get<u32>> OrderedSet<T, S> {
 				Ok(Some(old))
 			},
 			Err(_) => Err(true),
-			Ok(false) => Err(false),
-			Ok(_) => Ok(None),
+			Ok(_) => Err(false),
 		}
 	}
 
@@ -427,6 +447,29 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn try_insert_replace_with_limit() {
+		let mut set: OrderedSet<i32, Five> = OrderedSet::from(vec![].try_into().unwrap());
+		assert_eq!(set.try_insert_replace_with_limit(10, 0), Err(true));
+		assert_eq!(set.try_insert_replace_with_limit(10, 2), Ok(None));
+		assert_eq!(set.try_insert_replace_with_limit(8, 2), Ok(None));
+		assert_eq!(set.try_insert_replace_with_limit(7, 2), Err(true));
+		assert_eq!(set.try_insert_replace_with_limit(8, 2), Err(false));
+		assert_eq!(set.try_insert_replace_with_limit(9, 2), Ok(Some(8)));
+		assert_eq!(set.clone().into_bounded_vec().into_inner(), vec![10, 9]);
+
+		// sets exceeding the limit do not grow anymore
+		assert_eq!(set.try_insert_replace_with_limit(8, 3), Ok(None));
+		assert_eq!(set.try_insert_replace_with_limit(11, 1), Ok(Some(8)));
+		assert_eq!(set.clone().into_bounded_vec().into_inner(), vec![11, 10, 9]);
+
+		// the limit is capped by the maximum size
+		assert_eq!(set.try_insert_replace_with_limit(8, 10), Ok(None));
+		assert_eq!(set.try_insert_replace_with_limit(7, 10), Ok(None));
+		assert_eq!(set.try_insert_replace_with_limit(12, 10), Ok(Some(7)));
+		assert_eq!(set.into_bounded_vec().into_inner(), vec![12, 11, 10, 9, 8]);
+	}
+
 	#[test]
 	fn exceeding_max_size_should_fail() {
 		let mut set: OrderedSet<i32, Five> =
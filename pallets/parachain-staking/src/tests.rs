@@ -22,22 +22,32 @@ use std::{convert::TryInto, iter};
 
 use crate::{
 	mock::{
-		almost_equal, events, last_event, roll_to, roll_to_claim_rewards, AccountId, Balance,
-		Balances, BlockNumber, ExtBuilder, RuntimeEvent as MetaEvent, RuntimeOrigin as Origin,
-		Session, StakePallet, System, Test, BLOCKS_PER_ROUND, DECIMALS, TREASURY_ACC, TREASURY_INITIAL_BALANCE_UNITS,
+		almost_equal, events, last_event, new_rounds, roll_to, roll_to_claim_rewards,
+		staking_updates, AccountId, Balance, Balances, BlockNumber, DisplacedDelegatorGracePeriod,
+		ExtBuilder, RuntimeEvent as MetaEvent, RuntimeOrigin as Origin, Session, StakePallet,
+		StakingUpdate, System, Test, Tokens, BLOCKS_PER_ROUND, DECIMALS, TREASURY_ACC,
+		TREASURY_INITIAL_BALANCE_UNITS,
 	},
 	set::OrderedSet,
 	types::{
-		BalanceOf, Candidate, CandidateStatus, DelegationCounter, Delegator, RoundInfo, Stake,
-		StakeOf, TotalStake,
+		BalanceOf, Candidate, CandidateStatus, DelegationCounter, Delegator, LivenessConfig,
+		LivenessPenalty, RewardDestination, RoundInfo, Stake, StakeLock, StakeOf, StakingConfig,
+		TotalStake,
 	},
-	CandidatePool, Config, Error, Event, InflationInfo, RewardRate, StakingInfo, STAKING_ID,
+	CandidatePool, Config, Error, Event, InflationInfo, RewardRate, Rewards, StakeVotingCurrency,
+	StakingInfo, VotingPowerProvider, STAKING_ID,
 };
 use frame_support::{
-	assert_noop, assert_ok, storage::bounded_btree_map::BoundedBTreeMap,
-	traits::EstimateNextSessionRotation, BoundedVec,
+	assert_noop, assert_ok,
+	storage::bounded_btree_map::BoundedBTreeMap,
+	traits::{Currency, EstimateNextSessionRotation, LockableCurrency, WithdrawReasons},
+	BoundedVec,
 };
-use module_pallet_staking_rpc_runtime_api::StakingRates;
+use module_pallet_staking_rpc_runtime_api::{
+	CandidateInfo, CandidateState, CollatorApy, CollatorCommission, CollatorSnapshot,
+	DelegationInfo, DisplacedDelegation, StakingRates, UnstakingInfo,
+};
+use orml_traits::MultiCurrency;
 use pallet_authorship::EventHandler;
 use pallet_balances::{BalanceLock, Error as BalancesError, Reasons};
 use pallet_session::{SessionManager, ShouldEndSession};
@@ -131,6 +141,7 @@ fn genesis() {
 					),
 					total: 700,
 					status: CandidateStatus::Active,
+					commission: Perquintill::zero(),
 				})
 			);
 			// 2
@@ -152,6 +163,7 @@ fn genesis() {
 					),
 					total: 400,
 					status: CandidateStatus::Active,
+					commission: Perquintill::zero(),
 				})
 			);
 			// Delegators
@@ -277,7 +289,7 @@ fn join_collator_candidates() {
 				Error::<Test>::CandidateExists
 			);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(1), 1, 11u128,),
+				StakePallet::join_delegators(Origin::signed(1), 1, 11u128, None),
 				Error::<Test>::CandidateExists
 			);
 			assert_noop!(
@@ -365,7 +377,7 @@ fn collator_exit_executes_after_delay() {
 			// Still three, candidate didn't leave yet
 			assert_eq!(CandidatePool::<Test>::count(), 3);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(10), 2, 10),
+				StakePallet::join_delegators(Origin::signed(10), 2, 10, None),
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 7]);
@@ -624,7 +636,8 @@ fn execute_leave_candidates_with_delay() {
 						.unwrap()
 					),
 					total: 240,
-					status: CandidateStatus::Leaving(3)
+					status: CandidateStatus::Leaving(3),
+					commission: Perquintill::zero(),
 				})
 			);
 			assert_eq!(
@@ -641,7 +654,8 @@ fn execute_leave_candidates_with_delay() {
 						.unwrap()
 					),
 					total: 290,
-					status: CandidateStatus::Leaving(3)
+					status: CandidateStatus::Leaving(3),
+					commission: Perquintill::zero(),
 				})
 			);
 			for collator in 5u64..=10u64 {
@@ -656,7 +670,8 @@ fn execute_leave_candidates_with_delay() {
 						stake: collator as u128 * 10u128,
 						delegators: OrderedSet::from(BoundedVec::default()),
 						total: collator as u128 * 10u128,
-						status: CandidateStatus::Leaving(3)
+						status: CandidateStatus::Leaving(3),
+						commission: Perquintill::zero(),
 					})
 				);
 				assert!(StakePallet::is_active_candidate(&collator).is_some());
@@ -664,19 +679,39 @@ fn execute_leave_candidates_with_delay() {
 			}
 			assert_eq!(
 				StakePallet::delegator_state(11),
-				Some(Delegator::<AccountId, Balance> { owner: Some(1), amount: 110 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 1, amount: 110 }].try_into().unwrap()
+					),
+					total: 110
+				})
 			);
 			assert_eq!(
 				StakePallet::delegator_state(12),
-				Some(Delegator::<AccountId, Balance> { owner: Some(1), amount: 120 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 1, amount: 120 }].try_into().unwrap()
+					),
+					total: 120
+				})
 			);
 			assert_eq!(
 				StakePallet::delegator_state(13),
-				Some(Delegator::<AccountId, Balance> { owner: Some(2), amount: 130 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 2, amount: 130 }].try_into().unwrap()
+					),
+					total: 130
+				})
 			);
 			assert_eq!(
 				StakePallet::delegator_state(14),
-				Some(Delegator::<AccountId, Balance> { owner: Some(2), amount: 140 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 2, amount: 140 }].try_into().unwrap()
+					),
+					total: 140
+				})
 			);
 			for delegator in 11u64..=14u64 {
 				assert!(StakePallet::is_delegator(&delegator));
@@ -711,7 +746,8 @@ fn execute_leave_candidates_with_delay() {
 						.unwrap()
 					),
 					total: 240,
-					status: CandidateStatus::Leaving(3)
+					status: CandidateStatus::Leaving(3),
+					commission: Perquintill::zero(),
 				})
 			);
 			assert_eq!(
@@ -728,7 +764,8 @@ fn execute_leave_candidates_with_delay() {
 						.unwrap()
 					),
 					total: 290,
-					status: CandidateStatus::Leaving(3)
+					status: CandidateStatus::Leaving(3),
+					commission: Perquintill::zero(),
 				})
 			);
 			for collator in 5u64..=10u64 {
@@ -743,7 +780,8 @@ fn execute_leave_candidates_with_delay() {
 						stake: collator as u128 * 10u128,
 						delegators: OrderedSet::from(BoundedVec::default()),
 						total: collator as u128 * 10u128,
-						status: CandidateStatus::Leaving(3)
+						status: CandidateStatus::Leaving(3),
+						commission: Perquintill::zero(),
 					})
 				);
 				assert!(StakePallet::is_active_candidate(&collator).is_some());
@@ -751,19 +789,39 @@ fn execute_leave_candidates_with_delay() {
 			}
 			assert_eq!(
 				StakePallet::delegator_state(11),
-				Some(Delegator::<AccountId, Balance> { owner: Some(1), amount: 110 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 1, amount: 110 }].try_into().unwrap()
+					),
+					total: 110
+				})
 			);
 			assert_eq!(
 				StakePallet::delegator_state(12),
-				Some(Delegator::<AccountId, Balance> { owner: Some(1), amount: 120 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 1, amount: 120 }].try_into().unwrap()
+					),
+					total: 120
+				})
 			);
 			assert_eq!(
 				StakePallet::delegator_state(13),
-				Some(Delegator::<AccountId, Balance> { owner: Some(2), amount: 130 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 2, amount: 130 }].try_into().unwrap()
+					),
+					total: 130
+				})
 			);
 			assert_eq!(
 				StakePallet::delegator_state(14),
-				Some(Delegator::<AccountId, Balance> { owner: Some(2), amount: 140 })
+				Some(Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator> {
+					delegations: OrderedSet::from(
+						vec![StakeOf::<Test> { owner: 2, amount: 140 }].try_into().unwrap()
+					),
+					total: 140
+				})
 			);
 			for delegator in 11u64..=14u64 {
 				assert!(StakePallet::is_delegator(&delegator));
@@ -847,15 +905,15 @@ fn multiple_delegations() {
 			let mut expected = vec![Event::MaxSelectedCandidatesSet(2, 5), Event::NewRound(5, 1)];
 			assert_eq!(events(), expected);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(13), 2, 2),
+				StakePallet::join_delegators(Origin::signed(13), 2, 2, None),
 				Error::<Test>::DelegationBelowMin,
 			);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(13), 2, 10));
-			assert_ok!(StakePallet::join_delegators(Origin::signed(14), 4, 10));
-			assert_ok!(StakePallet::join_delegators(Origin::signed(15), 3, 10));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(13), 2, 10, None));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(14), 4, 10, None));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(15), 3, 10, None));
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2, 4, 3, 5]);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(6), 5, 10),
+				StakePallet::join_delegators(Origin::signed(6), 5, 10, None),
 				Error::<Test>::AlreadyDelegating,
 			);
 
@@ -875,26 +933,26 @@ fn multiple_delegations() {
 			assert_eq!(events(), expected);
 
 			roll_to(21, vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(16), 2, 80));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(16), 2, 80, None));
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(99), 3, 11),
+				StakePallet::join_delegators(Origin::signed(99), 3, 11, None),
 				BalancesError::<Test>::InsufficientBalance
 			);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(17), 2, 10),
+				StakePallet::join_delegators(Origin::signed(17), 2, 10, None),
 				Error::<Test>::TooManyDelegators
 			);
 			// kick 13 by staking 1 more (11 > 10)
 			assert!(StakePallet::unstaking(13).is_empty());
-			assert_ok!(StakePallet::join_delegators(Origin::signed(17), 2, 11));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(17), 2, 11, None));
 			assert!(StakePallet::delegator_state(13).is_none());
 			assert_eq!(StakePallet::unstaking(13).get(&23), Some(&10u128));
 			// kick 9 by staking 1 more (11 > 10)
 			assert!(StakePallet::unstaking(9).is_empty());
 			assert!(StakePallet::rewards(9).is_zero());
-			assert_ok!(StakePallet::join_delegators(Origin::signed(11), 2, 11));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(11), 2, 11, None));
 			// 11 should be initiated with the same reward counter as their collator 2
-			assert_eq!(StakePallet::reward_count(2), StakePallet::reward_count(11));
+			assert_eq!(StakePallet::reward_count(2), StakePallet::delegation_reward_count(11, 2));
 
 			assert!(StakePallet::delegator_state(9).is_none());
 			assert_eq!(StakePallet::unstaking(9).get(&23), Some(&10u128));
@@ -930,12 +988,12 @@ fn multiple_delegations() {
 			assert_eq!(events(), expected);
 
 			// test join_delegator errors
-			assert_ok!(StakePallet::join_delegators(Origin::signed(18), 1, 10));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(18), 1, 10, None));
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(12), 1, 10),
+				StakePallet::join_delegators(Origin::signed(12), 1, 10, None),
 				Error::<Test>::TooManyDelegators
 			);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(12), 1, 11));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(12), 1, 11, None));
 
 			// verify that delegations are removed after collator leaves, not before
 			assert!(StakePallet::candidate_pool(2)
@@ -946,8 +1004,8 @@ fn multiple_delegations() {
 				.unwrap()
 				.delegators
 				.contains(&StakeOf::<Test> { owner: 17, amount: 11 }));
-			assert_eq!(StakePallet::delegator_state(8).unwrap().amount, 10);
-			assert_eq!(StakePallet::delegator_state(17).unwrap().amount, 11);
+			assert_eq!(StakePallet::delegator_state(8).unwrap().total, 10);
+			assert_eq!(StakePallet::delegator_state(17).unwrap().total, 11);
 			assert_eq!(Balances::usable_balance(&8), 90);
 			assert_eq!(Balances::usable_balance(&17), 89);
 			assert_eq!(Balances::free_balance(&8), 100);
@@ -1027,9 +1085,9 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(7), 1, 50));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(7), 1, 50, None));
 			assert_noop!(
-				StakePallet::delegator_stake_more(Origin::signed(7), 1, 0),
+				StakePallet::delegator_stake_more(Origin::signed(7), 1, 0, None),
 				Error::<Test>::ValStakeZero
 			);
 			assert_noop!(
@@ -1049,14 +1107,14 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_ok!(StakePallet::join_delegators(Origin::signed(11), 1, 200));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(11), 1, 200, None));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake { delegators: old_stake.delegators + 200, ..old_stake }
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_eq!(StakePallet::delegator_state(11).unwrap().amount, 200);
+			assert_eq!(StakePallet::delegator_state(11).unwrap().total, 200);
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(11)));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
@@ -1064,7 +1122,7 @@ fn should_update_total_stake() {
 			);
 
 			let old_stake = StakePallet::total_collator_stake();
-			assert_eq!(StakePallet::delegator_state(8).unwrap().amount, 10);
+			assert_eq!(StakePallet::delegator_state(8).unwrap().total, 10);
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(8)));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
@@ -1209,11 +1267,11 @@ fn delegators_bond() {
 		.execute_with(|| {
 			roll_to(4, vec![]);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(6), 2, 50),
+				StakePallet::join_delegators(Origin::signed(6), 2, 50, None),
 				Error::<Test>::AlreadyDelegating
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(Origin::signed(1), 2, 50),
+				StakePallet::delegator_stake_more(Origin::signed(1), 2, 50, None),
 				Error::<Test>::DelegatorNotFound
 			);
 			assert_noop!(
@@ -1221,11 +1279,11 @@ fn delegators_bond() {
 				Error::<Test>::DelegatorNotFound
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(Origin::signed(6), 2, 50),
+				StakePallet::delegator_stake_more(Origin::signed(6), 2, 50, None),
 				Error::<Test>::DelegationNotFound
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(Origin::signed(7), 6, 50),
+				StakePallet::delegator_stake_more(Origin::signed(7), 6, 50, None),
 				Error::<Test>::CandidateNotFound
 			);
 			assert_noop!(
@@ -1240,17 +1298,17 @@ fn delegators_bond() {
 				StakePallet::delegator_stake_less(Origin::signed(6), 1, 8),
 				Error::<Test>::DelegationBelowMin
 			);
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(6), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(6), 1, 10, None));
 			assert_noop!(
 				StakePallet::delegator_stake_less(Origin::signed(6), 2, 5),
 				Error::<Test>::DelegationNotFound
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(Origin::signed(6), 1, 81),
+				StakePallet::delegator_stake_more(Origin::signed(6), 1, 81, None),
 				BalancesError::<Test>::InsufficientBalance
 			);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(10), 1, 4),
+				StakePallet::join_delegators(Origin::signed(10), 1, 4, None),
 				Error::<Test>::DelegationBelowMin
 			);
 
@@ -2092,7 +2150,7 @@ fn unlock_unstaked() {
 
 			// join delegators and revoke again --> consume unstaking at block 3
 			roll_to(2, vec![]);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100, None));
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
 			unstaking.remove(&3);
 			assert_ok!(unstaking.try_insert(4, 100));
@@ -2149,7 +2207,7 @@ fn unlock_unstaked() {
 
 			// join delegators and revoke again
 			roll_to(2, vec![]);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100, None));
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
 			unstaking.remove(&3);
 			assert_ok!(unstaking.try_insert(4, 100));
@@ -2208,7 +2266,7 @@ fn unlock_unstaked() {
 
 			// join delegators and revoke again
 			roll_to(2, vec![]);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 10));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 10, None));
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
 			assert_ok!(unstaking.try_insert(3, 90));
 			assert_ok!(unstaking.try_insert(4, 10));
@@ -2363,7 +2421,7 @@ fn unlock_unstaked() {
 			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(2), 1, 40));
 			assert_ok!(unstaking.try_insert(9, 40));
 			assert_ok!(StakePallet::candidate_stake_more(Origin::signed(1), 30));
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(2), 1, 30));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(2), 1, 30, None));
 			unstaking.remove(&8);
 			assert_ok!(unstaking.try_insert(9, 20));
 			assert_eq!(StakePallet::unstaking(1), unstaking);
@@ -2434,17 +2492,17 @@ fn kick_delegator_with_full_unstaking() {
 			);
 
 			// Fill last unstake request by replacing delegator
-			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 1, 200));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 1, 200, None));
 			assert_eq!(StakePallet::unstaking(5).into_inner().len(), max_unstake_reqs + 1);
 			assert!(!StakePallet::is_delegator(&5));
 
 			// Cannot join with full unstaking
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(5), 1, 100),
+				StakePallet::join_delegators(Origin::signed(5), 1, 100, None),
 				Error::<Test>::CannotJoinBeforeUnlocking
 			);
 			assert_ok!(StakePallet::unlock_unstaked(Origin::signed(5), 5));
-			assert_ok!(StakePallet::join_delegators(Origin::signed(5), 1, 220));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(5), 1, 220, None));
 		});
 }
 
@@ -2484,11 +2542,11 @@ fn candidate_leaves() {
 				(2u64..11u64).collect::<Vec<u64>>()
 			);
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(15), 1, 10),
+				StakePallet::join_delegators(Origin::signed(15), 1, 10, None),
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(Origin::signed(12), 1, 1),
+				StakePallet::delegator_stake_more(Origin::signed(12), 1, 1, None),
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
@@ -2763,30 +2821,30 @@ fn exceed_delegations_per_round() {
 		.execute_with(|| {
 			// leave and re-join to set counter to 2 (= MaxDelegationsPerRound)
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
-			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100, None));
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
 			// reached max delegations in this round
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(2), 1, 100),
+				StakePallet::join_delegators(Origin::signed(2), 1, 100, None),
 				Error::<Test>::DelegationsPerRoundExceeded
 			);
 
 			// roll to next round to clear DelegationCounter
 			roll_to(5, vec![]);
 			assert_eq!(StakePallet::last_delegation(2), DelegationCounter { round: 0, counter: 2 });
-			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100, None));
 			// counter should be reset because the round changed
 			assert_eq!(StakePallet::last_delegation(2), DelegationCounter { round: 1, counter: 1 });
 			// leave and re-join to set counter to 2 (= MaxDelegationsPerRound))
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
-			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100, None));
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(2), 1, 100),
+				StakePallet::join_delegators(Origin::signed(2), 1, 100, None),
 				Error::<Test>::AlreadyDelegating
 			);
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
 			assert_noop!(
-				StakePallet::join_delegators(Origin::signed(2), 1, 100),
+				StakePallet::join_delegators(Origin::signed(2), 1, 100, None),
 				Error::<Test>::DelegationsPerRoundExceeded
 			);
 			assert_eq!(StakePallet::last_delegation(2), DelegationCounter { round: 1, counter: 2 });
@@ -2802,7 +2860,7 @@ fn force_remove_candidate() {
 		.build()
 		.execute_with(|| {
 			assert_eq!(CandidatePool::<Test>::count(), 3);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 2, 50));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 2, 50, None));
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
 			assert!(StakePallet::unstaking(1).get(&3).is_none());
 			assert!(StakePallet::unstaking(2).get(&3).is_none());
@@ -2980,7 +3038,7 @@ fn prioritize_collators() {
 			);
 
 			// 7 delegates to 4
-			assert_ok!(StakePallet::join_delegators(Origin::signed(7), 5, 20));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(7), 5, 20, None));
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![5, 3]);
 			assert_eq!(
 				StakePallet::top_candidates(),
@@ -3064,7 +3122,7 @@ fn prioritize_delegators() {
 					.unwrap()
 				)
 			);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(5), 2, 110));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(5), 2, 110, None));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -3096,7 +3154,7 @@ fn prioritize_delegators() {
 			);
 
 			// delegate_more
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(6), 2, 10));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(6), 2, 10, None));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -3110,7 +3168,7 @@ fn prioritize_delegators() {
 					.unwrap()
 				)
 			);
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(7), 2, 10));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(7), 2, 10, None));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -3196,7 +3254,7 @@ fn force_new_round() {
 			assert_eq!(Session::validators(), vec![1, 2]);
 			assert_eq!(Session::current_index(), 0);
 			// 3 should be validator in round 2
-			assert_ok!(StakePallet::join_delegators(Origin::signed(5), 3, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(5), 3, 100, None));
 
 			// init force new round from 0 to 1, updating the authorities
 			assert_ok!(StakePallet::force_new_round(Origin::root()));
@@ -3218,7 +3276,7 @@ fn force_new_round() {
 			// assert_eq!(Session::validators(), vec![3, 1]);
 			assert!(!StakePallet::new_round_forced());
 			// 4 should become validator in session 3 if we do not force a new round
-			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 4, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 4, 100, None));
 
 			// end session 2 naturally
 			roll_to(7, vec![]);
@@ -3260,7 +3318,7 @@ fn replace_lowest_delegator() {
 			);
 
 			// 6 replaces 5
-			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 1, 51));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 1, 51, None));
 			assert!(StakePallet::delegator_state(5).is_none());
 			assert_eq!(
 				StakePallet::candidate_pool(1)
@@ -3278,7 +3336,7 @@ fn replace_lowest_delegator() {
 
 			// 5 attempts to replace 6 with more balance than available
 			frame_support::assert_noop!(
-				StakePallet::join_delegators(Origin::signed(5), 1, 101),
+				StakePallet::join_delegators(Origin::signed(5), 1, 101, None),
 				BalancesError::<Test>::InsufficientBalance
 			);
 			assert!(StakePallet::delegator_state(6).is_some());
@@ -3466,7 +3524,7 @@ fn update_total_stake_collators_stay() {
 				StakePallet::total_collator_stake(),
 				TotalStake { collators: 155, delegators: 150 }
 			);
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, 10, None));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake { collators: 155, delegators: 160 }
@@ -3543,7 +3601,7 @@ fn update_total_stake_new_collators() {
 				StakePallet::total_collator_stake(),
 				TotalStake { collators: 200, delegators: 100 }
 			);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(3), 2, 50));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(3), 2, 50, None));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake { collators: 200, delegators: 150 }
@@ -3583,7 +3641,7 @@ fn update_total_stake_no_collator_changes() {
 				StakePallet::total_collator_stake(),
 				TotalStake { collators: 70, delegators: 110 }
 			);
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(5), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(5), 1, 10, None));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake { collators: 70, delegators: 110 }
@@ -3612,8 +3670,8 @@ fn rewards_candidate_stake_more() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::reward_count(1), 1);
-			assert!(StakePallet::reward_count(2).is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {id:?}");
 			});
@@ -3638,8 +3696,8 @@ fn rewards_candidate_stake_less() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::reward_count(1), 1);
-			assert!(StakePallet::reward_count(2).is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero());
 			});
@@ -3689,8 +3747,8 @@ fn rewards_candidate_leave_network() {
 			assert_eq!(StakePallet::reward_count(1), 4 * 2);
 
 			// count for delegators should not be incremented
-			assert!(StakePallet::reward_count(2).is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 
 			// rewards should not be incremented
 			(1..=3).for_each(|id| {
@@ -3747,9 +3805,9 @@ fn reward_count_join_delegators() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::reward_count(1), 1);
-			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 100, None));
 			// delegator should have same counter as collator upon joining
-			assert_eq!(StakePallet::reward_count(2), 1);
+			assert_eq!(StakePallet::delegation_reward_count(2, 1), 1);
 		});
 }
 
@@ -3764,22 +3822,22 @@ fn rewards_delegator_stake_more() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::reward_count(1), 1);
-			assert!(StakePallet::reward_count(2).is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {id:?}");
 			});
 
 			// stake more to trigger reward incrementing just for 3
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, DECIMALS));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, DECIMALS, None));
 			// 1 should still have counter 1 but no rewards
 			assert_eq!(StakePallet::reward_count(1), 1);
 			assert!(StakePallet::rewards(1).is_zero());
 			// 2 should still have neither rewards nor counter
-			assert!(StakePallet::reward_count(2).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			// 3 should have rewards and the same counter as 1
-			assert_eq!(StakePallet::reward_count(3), 1);
+			assert_eq!(StakePallet::delegation_reward_count(3, 1), 1);
 			assert!(!StakePallet::rewards(3).is_zero());
 		});
 }
@@ -3795,8 +3853,8 @@ fn rewards_delegator_stake_less() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::reward_count(1), 1);
-			assert!(StakePallet::reward_count(2).is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {id:?}");
 			});
@@ -3807,10 +3865,10 @@ fn rewards_delegator_stake_less() {
 			assert_eq!(StakePallet::reward_count(1), 1);
 			assert!(StakePallet::rewards(1).is_zero());
 			// 2 should still have neither rewards nor counter
-			assert!(StakePallet::reward_count(2).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			// 3 should have rewards and the same counter as 1
-			assert_eq!(StakePallet::reward_count(3), 1);
+			assert_eq!(StakePallet::delegation_reward_count(3, 1), 1);
 			assert!(!StakePallet::rewards(3).is_zero());
 		});
 }
@@ -3840,13 +3898,13 @@ fn rewards_delegator_replaced() {
 			assert_eq!(StakePallet::reward_count(1), 1);
 
 			// 6 kicks 5
-			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 1, 2 * DECIMALS));
-			// 5 should have rewards and counter updated
+			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 1, 2 * DECIMALS, None));
+			// 5 should have rewards and the counter of the delegation removed
 			assert!(!StakePallet::rewards(5).is_zero());
-			assert_eq!(StakePallet::reward_count(5), 1);
+			assert!(StakePallet::delegation_reward_count(5, 1).is_zero());
 			// 6 should not have rewards but same counter as former collator
 			assert!(StakePallet::rewards(6).is_zero());
-			assert_eq!(StakePallet::reward_count(6), 1);
+			assert_eq!(StakePallet::delegation_reward_count(6, 1), 1);
 		});
 }
 
@@ -3861,20 +3919,20 @@ fn rewards_delegator_leaves() {
 			// note collator once to set their counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::reward_count(1), 1);
-			assert!(StakePallet::reward_count(2).is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {id:?}");
 			});
 
-			// only 3 should have non-zero rewards and their counter reset
+			// only 3 should have non-zero rewards and their counter removed
 			assert_ok!(StakePallet::leave_delegators(Origin::signed(3)));
 			assert_eq!(StakePallet::reward_count(1), 1);
 			assert!(StakePallet::rewards(1).is_zero());
-			assert!(StakePallet::reward_count(2).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			assert!(!StakePallet::rewards(3).is_zero());
-			assert_eq!(StakePallet::reward_count(3), 1);
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 		});
 }
 
@@ -3975,7 +4033,7 @@ fn rewards_incrementing_and_claiming() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::reward_count(1), 1);
-			assert!(StakePallet::reward_count(2).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
 
 			// claiming should not be possible before incrementing rewards
 			(1..=3).for_each(|id| {
@@ -3987,10 +4045,10 @@ fn rewards_incrementing_and_claiming() {
 
 			// increment rewards for 2 and match counter to collator
 			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(2)));
-			assert_eq!(StakePallet::reward_count(2), 1);
+			assert_eq!(StakePallet::delegation_reward_count(2, 1), 1);
 			let rewards_2 = StakePallet::rewards(2);
 			assert!(!rewards_2.is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 			assert!(StakePallet::rewards(3).is_zero());
 
 			// should set rewards for delegator 3 as well
@@ -3998,8 +4056,8 @@ fn rewards_incrementing_and_claiming() {
 			assert!(StakePallet::reward_count(1).is_zero());
 			assert!(!StakePallet::rewards(1).is_zero());
 			// counter of delegators should be reset to 0 (= cols counter)
-			assert!(StakePallet::reward_count(2).is_zero());
-			assert!(StakePallet::reward_count(3).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
 			// rewards of 2 should not be changed
 			assert_eq!(StakePallet::rewards(2), rewards_2);
 			// 3 should have rewards now (passively)
@@ -4017,7 +4075,7 @@ fn rewards_incrementing_and_claiming() {
 			// claim for 2 to move rewards into balance
 			assert_ok!(StakePallet::claim_rewards(Origin::signed(2)));
 			assert!(Balances::free_balance(&2) > DECIMALS);
-			assert!(StakePallet::reward_count(2).is_zero());
+			assert!(StakePallet::delegation_reward_count(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			assert_eq!(Balances::free_balance(&3), DECIMALS);
 
@@ -4033,6 +4091,146 @@ fn rewards_incrementing_and_claiming() {
 		});
 }
 
+#[test]
+fn rewards_incrementing_and_claiming_for_others() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, DECIMALS), (2, DECIMALS), (3, DECIMALS), (4, DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS), (3, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::increment_collator_rewards_for(Origin::signed(4), 1),
+				Error::<Test>::RewardsNotFound
+			);
+			assert_noop!(
+				StakePallet::claim_rewards_for(Origin::signed(4), 2),
+				Error::<Test>::RewardsNotFound
+			);
+
+			StakePallet::note_author(1);
+
+			// 4 increments the rewards of delegator 2 only
+			assert_ok!(StakePallet::increment_delegator_rewards_for(Origin::signed(4), 2));
+			assert!(!StakePallet::rewards(2).is_zero());
+			assert!(StakePallet::rewards(1).is_zero());
+			assert!(StakePallet::rewards(3).is_zero());
+
+			// 4 increments the rewards of collator 1 and thus of delegator 3
+			assert_ok!(StakePallet::increment_collator_rewards_for(Origin::signed(4), 1));
+			assert!(!StakePallet::rewards(1).is_zero());
+			assert!(!StakePallet::rewards(3).is_zero());
+
+			// rewards are paid out to the target
+			let rewards_2 = StakePallet::rewards(2);
+			assert_ok!(StakePallet::claim_rewards_for(Origin::signed(4), 2));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::Rewarded(2, rewards_2)));
+			assert_eq!(Balances::free_balance(2), DECIMALS + rewards_2);
+			assert_eq!(Balances::free_balance(4), DECIMALS);
+			assert!(StakePallet::rewards(2).is_zero());
+
+			// should not be able to increment for incorrect role
+			assert_noop!(
+				StakePallet::increment_collator_rewards_for(Origin::signed(4), 2),
+				Error::<Test>::CandidateNotFound
+			);
+			assert_noop!(
+				StakePallet::increment_delegator_rewards_for(Origin::signed(4), 1),
+				Error::<Test>::DelegatorNotFound
+			);
+		});
+}
+
+#[test]
+fn payout_stakers() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, DECIMALS), (2, DECIMALS), (3, DECIMALS), (4, DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS), (3, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			StakePallet::note_author(1);
+
+			// accounts without rewards are skipped
+			let accounts: BoundedVec<AccountId, <Test as Config>::MaxPayoutStakers> =
+				vec![2, 1, 4].try_into().unwrap();
+			assert_ok!(StakePallet::payout_stakers(Origin::signed(4), accounts));
+
+			// rewards of 1 and 2 have been incremented and claimed
+			assert!(Balances::free_balance(1) > DECIMALS);
+			assert!(Balances::free_balance(2) > DECIMALS);
+			assert!(StakePallet::rewards(1).is_zero());
+			assert!(StakePallet::rewards(2).is_zero());
+			assert!(StakePallet::reward_count(1).is_zero());
+			assert_eq!(Balances::free_balance(4), DECIMALS);
+			let rewarded: Vec<AccountId> = events()
+				.into_iter()
+				.filter_map(|event| match event {
+					Event::Rewarded(acc, _) => Some(acc),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(rewarded, vec![2, 1]);
+
+			// rewards of 3 have been incremented passively but not claimed
+			let rewards_3 = StakePallet::rewards(3);
+			assert!(!rewards_3.is_zero());
+			assert_eq!(Balances::free_balance(3), DECIMALS);
+			let accounts: BoundedVec<AccountId, <Test as Config>::MaxPayoutStakers> =
+				vec![3].try_into().unwrap();
+			assert_ok!(StakePallet::payout_stakers(Origin::signed(1), accounts));
+			assert_eq!(Balances::free_balance(3), DECIMALS + rewards_3);
+
+			// the batch size is bounded
+			assert!(BoundedVec::<AccountId, <Test as Config>::MaxPayoutStakers>::try_from(vec![
+				1, 2, 3, 4, 5
+			])
+			.is_err());
+		});
+}
+
+#[test]
+fn payout_stakers_skips_failing_claims() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, DECIMALS), (2, DECIMALS), (3, DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS), (3, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			StakePallet::note_author(1);
+
+			// the treasury cannot pay the rewards of 3
+			let treasury_balance = Balances::free_balance(TREASURY_ACC);
+			Rewards::<Test>::insert(3, treasury_balance);
+
+			let accounts: BoundedVec<AccountId, <Test as Config>::MaxPayoutStakers> =
+				vec![2, 3, 1].try_into().unwrap();
+			assert_ok!(StakePallet::payout_stakers(Origin::signed(3), accounts));
+
+			// 1 and 2 have been paid out
+			assert!(Balances::free_balance(1) > DECIMALS);
+			assert!(Balances::free_balance(2) > DECIMALS);
+			assert!(StakePallet::rewards(1).is_zero());
+			assert!(StakePallet::rewards(2).is_zero());
+
+			// the rewards of 3 have been incremented and remain claimable
+			assert_eq!(Balances::free_balance(3), DECIMALS);
+			assert!(StakePallet::rewards(3) > treasury_balance);
+			assert!(events().contains(&Event::PayoutFailed(
+				3,
+				Error::<Test>::TreasuryBalanceInsufficient.into()
+			)));
+			let rewarded: Vec<AccountId> = events()
+				.into_iter()
+				.filter_map(|event| match event {
+					Event::Rewarded(acc, _) => Some(acc),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(rewarded, vec![2, 1]);
+		});
+}
+
 #[test]
 fn api_get_unclaimed_staking_rewards() {
 	let stake = 100_000 * DECIMALS;
@@ -4101,7 +4299,7 @@ fn api_get_staking_rates() {
 			assert_ok!(StakePallet::candidate_stake_less(Origin::signed(1), stake / 2));
 			assert_ok!(StakePallet::candidate_stake_less(Origin::signed(2), stake / 2));
 			// delegator stakes more to exceed
-			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, stake));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, stake, None));
 			rates.collator_staking_rate = Perquintill::from_float(0.125);
 			rates.collator_reward_rate = Perquintill::from_percent(10);
 			rates.delegator_staking_rate = Perquintill::from_percent(25);
@@ -4109,3 +4307,1768 @@ fn api_get_staking_rates() {
 			assert_eq!(rates, StakePallet::get_staking_rates());
 		});
 }
+
+#[test]
+fn api_get_candidates() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 10)])
+		.with_delegators(vec![(4, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let mut candidates = StakePallet::get_candidates();
+			candidates.sort_by_key(|candidate| candidate.id);
+			assert_eq!(
+				candidates,
+				vec![
+					CandidateInfo {
+						id: 1,
+						status: CandidateState::Active,
+						stake: 20,
+						total: 30,
+						delegators: 1,
+						commission: Perquintill::zero(),
+						is_selected: true,
+					},
+					CandidateInfo {
+						id: 2,
+						status: CandidateState::Active,
+						stake: 20,
+						total: 20,
+						delegators: 0,
+						commission: Perquintill::zero(),
+						is_selected: true,
+					},
+					CandidateInfo {
+						id: 3,
+						status: CandidateState::Active,
+						stake: 10,
+						total: 10,
+						delegators: 0,
+						commission: Perquintill::zero(),
+						is_selected: false,
+					},
+				]
+			);
+
+			assert_ok!(StakePallet::init_leave_candidates(Origin::signed(2)));
+			let candidate = StakePallet::get_candidates()
+				.into_iter()
+				.find(|candidate| candidate.id == 2)
+				.unwrap();
+			assert_eq!(candidate.status, CandidateState::Leaving(2));
+			assert!(!candidate.is_selected);
+		});
+}
+
+#[test]
+fn api_get_delegations_and_unstaking() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.with_delegators(vec![(3, 1, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				StakePallet::get_delegations(&3),
+				vec![DelegationInfo { collator: 1, amount: 20 }]
+			);
+			assert!(StakePallet::get_delegations(&1).is_empty());
+			assert!(StakePallet::get_unstaking(&3).is_empty());
+
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(3), 2, 10));
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(3), 1, 5));
+			assert_eq!(
+				StakePallet::get_delegations(&3),
+				vec![
+					DelegationInfo { collator: 1, amount: 15 },
+					DelegationInfo { collator: 2, amount: 10 }
+				]
+			);
+			assert_eq!(
+				StakePallet::get_unstaking(&3),
+				vec![UnstakingInfo { unlock_block: 3, amount: 5 }]
+			);
+		});
+}
+
+#[test]
+fn api_get_collator_apy() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, 2 * stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.with_delegators(vec![(3, 1, stake)])
+		.with_inflation(25, 10, 25, 8, <Test as Config>::BLOCKS_PER_YEAR)
+		.build_and_execute_with_sanity_tests(|| {
+			assert!(StakePallet::get_collator_apy(&3).is_none());
+			assert_eq!(
+				StakePallet::get_collator_apy(&1),
+				Some(CollatorApy {
+					collator: Perquintill::from_percent(10),
+					delegators: Perquintill::from_percent(8),
+				})
+			);
+
+			// the commission is moved from the delegators to the collator
+			assert_ok!(StakePallet::request_commission_change(
+				Origin::signed(1),
+				Perquintill::from_percent(10)
+			));
+			roll_to(BLOCKS_PER_ROUND * 2, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(Origin::signed(1)));
+			assert_eq!(
+				StakePallet::get_collator_apy(&1),
+				Some(CollatorApy {
+					collator: Perquintill::from_perthousand(108),
+					delegators: Perquintill::from_perthousand(72),
+				})
+			);
+		});
+}
+
+#[test]
+fn api_get_next_round_start() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(StakePallet::get_next_round_start(), BLOCKS_PER_ROUND);
+
+			roll_to(BLOCKS_PER_ROUND + 1, vec![]);
+			assert_eq!(StakePallet::get_next_round_start(), 2 * BLOCKS_PER_ROUND);
+		});
+}
+
+#[test]
+fn api_would_enter_top_delegators() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(2, 100),
+			(3, 100),
+			(4, 100),
+			(5, 100),
+			(6, 100),
+			(7, 100),
+			(9, 100),
+		])
+		.with_collators(vec![(1, 20), (2, 20), (9, 20)])
+		.with_delegators(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			// collator 2 has free slots
+			assert!(StakePallet::would_enter_top_delegators(&7, &2, 5));
+			assert!(!StakePallet::would_enter_top_delegators(&7, &2, 4));
+
+			// collator 1 is full, the stake has to exceed the lowest one
+			assert!(!StakePallet::would_enter_top_delegators(&7, &1, 10));
+			assert!(StakePallet::would_enter_top_delegators(&7, &1, 11));
+
+			// existing delegators replace their own stake
+			assert!(StakePallet::would_enter_top_delegators(&6, &1, 5));
+
+			// unknown and leaving candidates do not accept delegations
+			assert!(!StakePallet::would_enter_top_delegators(&7, &8, 50));
+			assert_ok!(StakePallet::init_leave_candidates(Origin::signed(2)));
+			assert!(!StakePallet::would_enter_top_delegators(&7, &2, 50));
+		});
+}
+
+#[test]
+fn commission_change() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 100), (2, 20)])
+		.with_delegators(vec![(3, 1, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::request_commission_change(
+					Origin::signed(1),
+					Perquintill::from_percent(21)
+				),
+				Error::<Test>::CommissionTooHigh
+			);
+			assert_noop!(
+				StakePallet::request_commission_change(
+					Origin::signed(3),
+					Perquintill::from_percent(10)
+				),
+				Error::<Test>::CandidateNotFound
+			);
+			assert_noop!(
+				StakePallet::execute_commission_change(Origin::signed(3), 1),
+				Error::<Test>::NoCommissionChangeRequested
+			);
+
+			assert_ok!(StakePallet::request_commission_change(
+				Origin::signed(1),
+				Perquintill::from_percent(10)
+			));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CommissionChangeRequested(
+					1,
+					Perquintill::zero(),
+					Perquintill::from_percent(10),
+					2
+				))
+			);
+			assert_eq!(
+				StakePallet::get_collator_commission(&1),
+				Some(CollatorCommission {
+					commission: Perquintill::zero(),
+					pending: Some((Perquintill::from_percent(10), 2))
+				})
+			);
+			assert_noop!(
+				StakePallet::execute_commission_change(Origin::signed(3), 1),
+				Error::<Test>::CannotChangeCommissionYet
+			);
+
+			// the change can be executed after CommissionChangeDelay many rounds
+			roll_to(2 * BLOCKS_PER_ROUND, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(Origin::signed(3), 1));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CommissionChanged(
+					1,
+					Perquintill::zero(),
+					Perquintill::from_percent(10)
+				))
+			);
+			assert!(StakePallet::commission_change_request(1).is_none());
+			assert_eq!(
+				StakePallet::get_collator_commission(&1),
+				Some(CollatorCommission {
+					commission: Perquintill::from_percent(10),
+					pending: None
+				})
+			);
+			assert_eq!(StakePallet::get_collator_commission(&3), None);
+		});
+}
+
+#[test]
+fn rewards_with_commission() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, DECIMALS), (2, DECIMALS), (3, DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS), (3, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			let commission = Perquintill::from_percent(20);
+
+			StakePallet::note_author(1);
+
+			// without commission, the delegator keeps the entire reward
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(2)));
+			let reward = StakePallet::rewards(2);
+			assert!(!reward.is_zero());
+			assert!(StakePallet::rewards(1).is_zero());
+
+			CandidatePool::<Test>::mutate(1, |state| {
+				if let Some(state) = state {
+					state.commission = commission;
+				}
+			});
+
+			// the commission is deducted from the delegator's rewards and paid to the
+			// collator
+			assert_eq!(
+				StakePallet::get_unclaimed_staking_rewards(&3),
+				reward - commission * reward
+			);
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(3)));
+			assert_eq!(StakePallet::rewards(3), reward - commission * reward);
+			assert_eq!(StakePallet::rewards(1), commission * reward);
+
+			// the collator claims their commission
+			let balance = Balances::free_balance(1);
+			assert_ok!(StakePallet::claim_rewards(Origin::signed(1)));
+			assert_eq!(Balances::free_balance(1), balance + commission * reward);
+		});
+}
+
+#[test]
+fn delegate_another_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (7, 100)])
+		.with_collators(vec![(1, 10), (2, 10), (3, 10), (4, 10), (5, 10)])
+		.with_delegators(vec![(6, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::delegate_another_candidate(Origin::signed(7), 2, 10),
+				Error::<Test>::NotYetDelegating
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(Origin::signed(6), 1, 10),
+				Error::<Test>::AlreadyDelegatedCollator
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(Origin::signed(6), 2, 4),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(Origin::signed(6), 2, 91),
+				BalancesError::<Test>::InsufficientBalance
+			);
+
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(6), 2, 20));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::Delegation(6, 20, 2, 30)));
+			let delegator = StakePallet::delegator_state(6).unwrap();
+			assert_eq!(delegator.total, 30);
+			assert_eq!(delegator.delegations.len(), 2);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 20);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 30);
+			assert_eq!(Balances::usable_balance(&6), 70);
+
+			// reached max delegations in this round
+			assert_noop!(
+				StakePallet::delegate_another_candidate(Origin::signed(6), 3, 10),
+				Error::<Test>::DelegationsPerRoundExceeded
+			);
+
+			// roll to next round to clear DelegationCounter
+			roll_to(5, vec![]);
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(6), 3, 10));
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(6), 4, 10));
+			assert_eq!(StakePallet::delegator_state(6).unwrap().total, 50);
+			assert_eq!(Balances::usable_balance(&6), 50);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(Origin::signed(6), 5, 10),
+				Error::<Test>::MaxCollatorsPerDelegatorExceeded
+			);
+
+			// removing a collator only removes the corresponding delegation
+			assert_ok!(StakePallet::force_remove_candidate(Origin::root(), 4));
+			let delegator = StakePallet::delegator_state(6).unwrap();
+			assert_eq!(delegator.total, 40);
+			assert_eq!(delegator.delegations.len(), 3);
+			assert_eq!(StakePallet::unstaking(6).get(&7), Some(&10));
+		});
+}
+
+#[test]
+fn revoke_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 10), (2, 10), (3, 10)])
+		.with_delegators(vec![(4, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(4), 2, 20));
+
+			// stake is changed per delegation
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(4), 2, 10, None));
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, 40);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 20);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 40);
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(4), 1, 5));
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, 35);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 15);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 40);
+			assert_eq!(Balances::usable_balance(&4), 60);
+			assert_noop!(
+				StakePallet::delegator_stake_more(Origin::signed(4), 3, 10, None),
+				Error::<Test>::DelegationNotFound
+			);
+
+			// revoking a single delegation keeps the others
+			assert_ok!(StakePallet::revoke_delegation(Origin::signed(4), 2));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::DelegatorLeftCollator(4, 2, 30, 10))
+			);
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, 5);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 10);
+			assert_eq!(StakePallet::unstaking(4).get(&3), Some(&35));
+			assert_noop!(
+				StakePallet::revoke_delegation(Origin::signed(4), 2),
+				Error::<Test>::DelegationNotFound
+			);
+
+			// revoking the last delegation leaves the set of delegators
+			assert_ok!(StakePallet::revoke_delegation(Origin::signed(4), 1));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::DelegatorLeft(4, 5)));
+			assert!(StakePallet::delegator_state(4).is_none());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 10);
+			assert_eq!(StakePallet::unstaking(4).get(&3), Some(&40));
+			assert_noop!(
+				StakePallet::revoke_delegation(Origin::signed(4), 1),
+				Error::<Test>::DelegatorNotFound
+			);
+		});
+}
+
+#[test]
+fn rewards_multiple_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, DECIMALS), (2, DECIMALS), (3, 2 * DECIMALS)])
+		.with_collators(vec![(1, DECIMALS), (2, DECIMALS)])
+		.with_delegators(vec![(3, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(3), 2, DECIMALS));
+
+			// note both collators to increment their counters
+			StakePallet::note_author(1);
+			StakePallet::note_author(2);
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
+			assert!(StakePallet::delegation_reward_count(3, 2).is_zero());
+
+			// incrementing covers all delegations
+			let unclaimed = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert!(!unclaimed.is_zero());
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(3)));
+			assert_eq!(StakePallet::rewards(3), unclaimed);
+			assert_eq!(StakePallet::delegation_reward_count(3, 1), StakePallet::reward_count(1));
+			assert_eq!(StakePallet::delegation_reward_count(3, 2), StakePallet::reward_count(2));
+
+			// incrementing the collator should not reward the delegation twice
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(1)));
+			assert_eq!(StakePallet::rewards(3), unclaimed);
+			assert!(StakePallet::delegation_reward_count(3, 1).is_zero());
+
+			// leaving revokes all delegations at once
+			StakePallet::note_author(2);
+			assert_ok!(StakePallet::leave_delegators(Origin::signed(3)));
+			assert!(StakePallet::rewards(3) > unclaimed);
+			assert!(StakePallet::delegator_state(3).is_none());
+			assert!(StakePallet::delegation_reward_count(3, 2).is_zero());
+			assert!(StakePallet::candidate_pool(1).unwrap().delegators.is_empty());
+			assert!(StakePallet::candidate_pool(2).unwrap().delegators.is_empty());
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&(2 * DECIMALS)));
+		});
+}
+
+#[test]
+fn redelegate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+		.with_collators(vec![(1, 10), (2, 10), (3, 20)])
+		.with_delegators(vec![(4, 1, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake { collators: 30, delegators: 20 }
+			);
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 1, 1, 10),
+				Error::<Test>::RedelegationToSameCollator
+			);
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 1, 2, 0),
+				Error::<Test>::ValStakeZero
+			);
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(5), 1, 2, 10),
+				Error::<Test>::DelegatorNotFound
+			);
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 2, 3, 10),
+				Error::<Test>::DelegationNotFound
+			);
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 1, 2, 21),
+				Error::<Test>::Underflow
+			);
+			// remaining delegation would be below minimum
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 1, 2, 16),
+				Error::<Test>::DelegationBelowMin
+			);
+			// new delegation would be below minimum
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 1, 2, 4),
+				Error::<Test>::DelegationBelowMin
+			);
+
+			// move stake to a new candidate who becomes a collator
+			assert_ok!(StakePallet::redelegate(Origin::signed(4), 1, 2, 15));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::Redelegated(4, 1, 2, 15)));
+			let delegator = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(delegator.total, 20);
+			assert_eq!(delegator.delegation(1), Ok(5));
+			assert_eq!(delegator.delegation(2), Ok(15));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 15);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 25);
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![2, 3]);
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake { collators: 30, delegators: 15 }
+			);
+			// stake stays locked without unstaking
+			assert_eq!(Balances::usable_balance(&4), 80);
+			assert!(StakePallet::unstaking(4).is_empty());
+
+			// reached max delegations in this round
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 1, 2, 5),
+				Error::<Test>::DelegationsPerRoundExceeded
+			);
+
+			// moving the full stake revokes the old delegation
+			roll_to(5, vec![]);
+			assert_ok!(StakePallet::redelegate(Origin::signed(4), 1, 2, 5));
+			let delegator = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(delegator.total, 20);
+			assert_eq!(delegator.delegations.len(), 1);
+			assert_eq!(delegator.delegation(2), Ok(20));
+			assert!(StakePallet::candidate_pool(1).unwrap().delegators.is_empty());
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 30);
+			assert!(StakePallet::delegation_reward_count(4, 1).is_zero());
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake { collators: 30, delegators: 20 }
+			);
+
+			// cannot redelegate to a leaving candidate
+			assert_ok!(StakePallet::init_leave_candidates(Origin::signed(1)));
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(4), 2, 1, 10),
+				Error::<Test>::CannotDelegateIfLeaving
+			);
+		});
+}
+
+#[test]
+fn redelegate_replaces_delegator() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (7, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(3, 1, 30), (4, 2, 10), (5, 2, 10), (6, 2, 10), (7, 2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().delegators.len(), 4);
+			assert_noop!(
+				StakePallet::redelegate(Origin::signed(3), 1, 2, 10),
+				Error::<Test>::TooManyDelegators
+			);
+
+			assert_ok!(StakePallet::redelegate(Origin::signed(3), 1, 2, 20));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::Redelegated(3, 1, 2, 20)));
+			let state = StakePallet::candidate_pool(2).unwrap();
+			assert_eq!(state.delegators.len(), 4);
+			assert_eq!(state.total, 60);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 20);
+
+			// exactly one of the other delegators has been kicked
+			let kicked: Vec<AccountId> =
+				(4..=7).filter(|d| StakePallet::delegator_state(d).is_none()).collect();
+			assert_eq!(kicked.len(), 1);
+			assert_eq!(StakePallet::unstaking(kicked[0]).get(&3), Some(&10));
+		});
+}
+
+#[test]
+fn set_auto_compound() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 10)])
+		.with_delegators(vec![(2, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::set_auto_compound(Origin::signed(3), Perquintill::from_percent(50)),
+				Error::<Test>::NotStaking
+			);
+			// disabling is always possible
+			assert_ok!(StakePallet::set_auto_compound(Origin::signed(3), Perquintill::zero()));
+
+			assert_ok!(StakePallet::set_auto_compound(
+				Origin::signed(1),
+				Perquintill::from_percent(50)
+			));
+			assert_ok!(StakePallet::set_auto_compound(
+				Origin::signed(2),
+				Perquintill::from_percent(100)
+			));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::AutoCompoundSet(2, Perquintill::from_percent(100)))
+			);
+			assert_eq!(StakePallet::auto_compound(1), Perquintill::from_percent(50));
+			assert_eq!(StakePallet::auto_compound(2), Perquintill::from_percent(100));
+
+			assert_ok!(StakePallet::set_auto_compound(Origin::signed(2), Perquintill::zero()));
+			assert!(StakePallet::auto_compound(2).is_zero());
+		});
+}
+
+#[test]
+fn auto_compound_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10 * DECIMALS), (2, 10 * DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_auto_compound(
+				Origin::signed(2),
+				Perquintill::from_percent(100)
+			));
+			let usable_delegator = Balances::usable_balance(&2);
+
+			// delegator restakes all rewards into their delegation
+			StakePallet::note_author(1);
+			let reward = StakePallet::get_unclaimed_staking_rewards(&2);
+			assert!(!reward.is_zero());
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(2)));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::RewardsCompounded(2, 1, reward))
+			);
+			assert!(StakePallet::rewards(2).is_zero());
+			assert_eq!(StakePallet::delegator_state(2).unwrap().total, DECIMALS + reward);
+			let state = StakePallet::candidate_pool(1).unwrap();
+			assert_eq!(state.total, 2 * DECIMALS + reward);
+			assert_eq!(state.delegators.into_bounded_vec()[0].amount, DECIMALS + reward);
+			assert_eq!(StakePallet::total_collator_stake().delegators, DECIMALS + reward);
+			// paid out and locked
+			assert_eq!(Balances::usable_balance(&2), usable_delegator);
+			assert_eq!(Balances::free_balance(&2), 10 * DECIMALS + reward);
+
+			// collator restakes half of their rewards
+			assert_ok!(StakePallet::set_auto_compound(
+				Origin::signed(1),
+				Perquintill::from_percent(50)
+			));
+			StakePallet::note_author(1);
+			let reward = StakePallet::get_unclaimed_staking_rewards(&1);
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(1)));
+			let compounded = Perquintill::from_percent(50) * reward;
+			assert_eq!(StakePallet::rewards(1), reward - compounded);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, DECIMALS + compounded);
+			assert_eq!(StakePallet::total_collator_stake().collators, DECIMALS + compounded);
+			// rewards of the delegator were compounded as well
+			assert!(StakePallet::rewards(2).is_zero());
+
+			// restaking is capped by the maximum candidate stake
+			assert_ok!(StakePallet::set_max_candidate_stake(Origin::root(), DECIMALS + compounded));
+			StakePallet::note_author(1);
+			let reward = StakePallet::get_unclaimed_staking_rewards(&1);
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(1)));
+			assert_eq!(StakePallet::rewards(1), reward);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, DECIMALS + compounded);
+		});
+}
+
+#[test]
+fn auto_compound_does_not_change_past_collator_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 10 * DECIMALS),
+			(2, 10 * DECIMALS),
+			(3, 10 * DECIMALS),
+			(4, 10 * DECIMALS),
+		])
+		.with_collators(vec![(1, DECIMALS), (2, DECIMALS)])
+		.with_delegators(vec![(3, 1, DECIMALS), (4, 2, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			for collator in [1, 2] {
+				assert_ok!(StakePallet::request_commission_change(
+					Origin::signed(collator),
+					Perquintill::from_percent(10)
+				));
+			}
+			roll_to(BLOCKS_PER_ROUND * 3, vec![]);
+			for collator in [1, 2] {
+				assert_ok!(StakePallet::execute_commission_change(Origin::signed(collator)));
+			}
+
+			// only collator 1 restakes their rewards
+			assert_ok!(StakePallet::set_auto_compound(
+				Origin::signed(1),
+				Perquintill::from_percent(100)
+			));
+
+			// both collators author the same blocks and receive the same commission
+			StakePallet::note_author(1);
+			StakePallet::note_author(2);
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(3)));
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(4)));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, DECIMALS);
+			StakePallet::note_author(1);
+			StakePallet::note_author(2);
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(1)));
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(2)));
+
+			let compounded: Balance = events()
+				.into_iter()
+				.filter_map(|event| match event {
+					Event::RewardsCompounded(1, 1, amount) => Some(amount),
+					_ => None,
+				})
+				.sum();
+			assert!(!compounded.is_zero());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, DECIMALS + compounded);
+			assert_eq!(StakePallet::rewards(1) + compounded, StakePallet::rewards(2));
+		});
+}
+
+#[test]
+fn set_reward_destination() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 10)])
+		.with_delegators(vec![(2, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::set_reward_destination(Origin::signed(3), RewardDestination::Staked),
+				Error::<Test>::NotStaking
+			);
+			// paying into the own account is always possible
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(3),
+				RewardDestination::Own
+			));
+
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(1),
+				RewardDestination::Account(3)
+			));
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(2),
+				RewardDestination::Treasury
+			));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::RewardDestinationSet(2, RewardDestination::Treasury))
+			);
+			assert_eq!(StakePallet::reward_destination(1), RewardDestination::Account(3));
+			assert_eq!(StakePallet::reward_destination(2), RewardDestination::Treasury);
+
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(2),
+				RewardDestination::Own
+			));
+			assert_eq!(StakePallet::reward_destination(2), RewardDestination::Own);
+		});
+}
+
+#[test]
+fn rewards_are_paid_to_reward_destination() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 10 * DECIMALS),
+			(2, 10 * DECIMALS),
+			(3, 10 * DECIMALS),
+			(4, 10 * DECIMALS),
+		])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS), (3, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(1),
+				RewardDestination::Account(4)
+			));
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(2),
+				RewardDestination::Treasury
+			));
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(3),
+				RewardDestination::Staked
+			));
+
+			StakePallet::note_author(1);
+			let reward_3 = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(1)));
+
+			// rewards of 3 have been restaked completely
+			assert!(StakePallet::rewards(3).is_zero());
+			assert_eq!(StakePallet::delegator_state(3).unwrap().total, DECIMALS + reward_3);
+			assert_noop!(
+				StakePallet::claim_rewards(Origin::signed(3)),
+				Error::<Test>::RewardsNotFound
+			);
+
+			// rewards of 1 are paid to 4
+			let reward_1 = StakePallet::rewards(1);
+			assert!(!reward_1.is_zero());
+			assert_ok!(StakePallet::claim_rewards(Origin::signed(1)));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::RewardedTo(1, 4, reward_1)));
+			assert_eq!(Balances::free_balance(1), 10 * DECIMALS);
+			assert_eq!(Balances::free_balance(4), 10 * DECIMALS + reward_1);
+			assert!(StakePallet::rewards(1).is_zero());
+
+			// rewards of 2 stay in the treasury
+			let reward_2 = StakePallet::rewards(2);
+			assert!(!reward_2.is_zero());
+			let treasury_balance = Balances::free_balance(TREASURY_ACC);
+			assert_ok!(StakePallet::claim_rewards_for(Origin::signed(4), 2));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::RewardsDonated(2, reward_2)));
+			assert_eq!(Balances::free_balance(2), 10 * DECIMALS);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), treasury_balance);
+			assert!(StakePallet::rewards(2).is_zero());
+		});
+}
+
+#[test]
+fn set_liveness_config() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			let config = LivenessConfig {
+				min_authored: Perquintill::from_percent(50),
+				max_missed_rounds: 2,
+				penalty: LivenessPenalty::Idle,
+			};
+			assert_noop!(
+				StakePallet::set_liveness_config(Origin::signed(1), Some(config)),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			assert_ok!(StakePallet::set_liveness_config(Origin::root(), Some(config)));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::LivenessConfigSet(Some(config)))
+			);
+			assert_eq!(StakePallet::liveness_config(), Some(config));
+
+			// collator 2 misses the first round
+			roll_to(5, vec![Some(1); 5]);
+			assert_eq!(StakePallet::missed_rounds(2), 1);
+
+			// updating the config resets the missed rounds
+			assert_ok!(StakePallet::set_liveness_config(Origin::root(), None));
+			assert_eq!(StakePallet::liveness_config(), None);
+			assert!(StakePallet::missed_rounds(2).is_zero());
+
+			// nothing is tracked without a config
+			roll_to(10, vec![Some(1); 10]);
+			assert!(StakePallet::missed_rounds(2).is_zero());
+		});
+}
+
+#[test]
+fn liveness_idle() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_liveness_config(
+				Origin::root(),
+				Some(LivenessConfig {
+					min_authored: Perquintill::from_percent(50),
+					max_missed_rounds: 2,
+					penalty: LivenessPenalty::Idle,
+				})
+			));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+
+			// collator 1 authors all blocks
+			roll_to(5, vec![Some(1); 5]);
+			assert!(StakePallet::missed_rounds(1).is_zero());
+			assert_eq!(StakePallet::missed_rounds(2), 1);
+			assert!(StakePallet::is_active_candidate(&2).unwrap());
+
+			// collator 2 goes idle after missing the second round
+			roll_to(10, vec![Some(1); 10]);
+			assert!(events().contains(&Event::CollatorWentIdle(2, 2)));
+			assert!(StakePallet::missed_rounds(2).is_zero());
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().status, CandidateStatus::Idle);
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 3]);
+			assert_noop!(StakePallet::go_online(Origin::signed(3)), Error::<Test>::NotIdle);
+
+			assert_ok!(StakePallet::go_online(Origin::signed(2)));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::CollatorWentOnline(2)));
+			assert!(StakePallet::is_active_candidate(&2).unwrap());
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+			assert_noop!(StakePallet::go_online(Origin::signed(2)), Error::<Test>::NotIdle);
+		});
+}
+
+#[test]
+fn liveness_slash() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_liveness_config(
+				Origin::root(),
+				Some(LivenessConfig {
+					min_authored: Perquintill::from_percent(50),
+					max_missed_rounds: 1,
+					penalty: LivenessPenalty::Slash(Perquintill::from_percent(50)),
+				})
+			));
+			let treasury = Balances::free_balance(TREASURY_ACC);
+
+			// collator 2 does not author any block
+			roll_to(5, vec![Some(1); 5]);
+			assert!(events().contains(&Event::CollatorSlashed(2, 10)));
+			assert!(StakePallet::missed_rounds(2).is_zero());
+
+			let state = StakePallet::candidate_pool(2).unwrap();
+			assert_eq!(state.stake, 10);
+			assert_eq!(state.total, 10);
+			assert!(state.is_active());
+			assert_eq!(Balances::free_balance(2), 90);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), treasury + 10);
+			assert_eq!(
+				Balances::locks(2),
+				vec![BalanceLock { id: STAKING_ID, amount: 10, reasons: Reasons::All }]
+			);
+			assert_eq!(StakePallet::total_collator_stake().collators, 30);
+
+			// the remaining stake is never slashed below the minimum
+			roll_to(10, vec![Some(1); 10]);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().stake, 10);
+		});
+}
+
+#[test]
+fn liveness_slash_keeps_earned_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100 * DECIMALS), (2, 100 * DECIMALS), (3, 100 * DECIMALS)])
+		.with_collators(vec![(1, 10 * DECIMALS), (2, 10 * DECIMALS)])
+		.with_delegators(vec![(3, 2, 5 * DECIMALS)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_liveness_config(
+				Origin::root(),
+				Some(LivenessConfig {
+					min_authored: Perquintill::from_percent(100),
+					max_missed_rounds: 1,
+					penalty: LivenessPenalty::Slash(Perquintill::from_percent(50)),
+				})
+			));
+			// the governance-set minimum stake bounds the slash
+			let config = StakePallet::staking_config();
+			assert_ok!(StakePallet::set_staking_config(
+				Origin::root(),
+				StakingConfig { min_collator_stake: 8 * DECIMALS, ..config }
+			));
+
+			// collator 2 authors a single block
+			let authors = vec![None, Some(2), Some(1), Some(1), Some(1)];
+			roll_to(2, authors.clone());
+			let collator_rewards = StakePallet::get_unclaimed_staking_rewards(&2);
+			let delegator_rewards = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert!(!collator_rewards.is_zero());
+			assert!(!delegator_rewards.is_zero());
+
+			roll_to(5, authors);
+			assert!(events().contains(&Event::CollatorSlashed(2, 2 * DECIMALS)));
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().stake, 8 * DECIMALS);
+
+			// the rewards earned before the slash are based on the full stake
+			assert_eq!(StakePallet::rewards(2), collator_rewards);
+			assert_eq!(StakePallet::rewards(3), delegator_rewards);
+			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&2), collator_rewards);
+			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&3), delegator_rewards);
+		});
+}
+
+#[test]
+fn set_staking_config() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			// defaults to the constants
+			let config = StakePallet::staking_config();
+			assert_eq!(config.min_collator_stake, <Test as Config>::MinCollatorStake::get());
+			assert_eq!(config.min_delegator_stake, <Test as Config>::MinDelegatorStake::get());
+			assert_eq!(
+				config.max_delegators_per_collator,
+				<Test as Config>::MaxDelegatorsPerCollator::get()
+			);
+			assert_eq!(config.stake_duration, <Test as Config>::StakeDuration::get());
+			assert_eq!(config.exit_queue_delay, <Test as Config>::ExitQueueDelay::get());
+			assert_eq!(config.network_reward_rate, <Test as Config>::NetworkRewardRate::get());
+
+			let config = StakingConfig {
+				min_collator_stake: 20,
+				min_delegator_stake: 10,
+				max_delegators_per_collator: 2,
+				stake_duration: 7,
+				exit_queue_delay: 3,
+				network_reward_rate: Perquintill::from_percent(5),
+			};
+			assert_noop!(
+				StakePallet::set_staking_config(Origin::signed(1), config),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig { min_collator_stake: 9, ..config }
+				),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig {
+						min_collator_stake: StakePallet::max_candidate_stake() + 1,
+						..config
+					}
+				),
+				Error::<Test>::CannotSetAboveMax
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig { min_delegator_stake: 0, ..config }
+				),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig { max_delegators_per_collator: 0, ..config }
+				),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig {
+						max_delegators_per_collator:
+							<Test as Config>::MaxDelegatorsPerCollator::get() + 1,
+						..config
+					}
+				),
+				Error::<Test>::CannotSetAboveMax
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig { exit_queue_delay: 0, ..config }
+				),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig { stake_duration: 0, ..config }
+				),
+				Error::<Test>::InvalidStakeDuration
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig {
+						stake_duration: <Test as Config>::MaxStakeDuration::get() + 1,
+						..config
+					}
+				),
+				Error::<Test>::InvalidStakeDuration
+			);
+			assert_noop!(
+				StakePallet::set_staking_config(
+					Origin::root(),
+					StakingConfig {
+						network_reward_rate: <Test as Config>::MaxNetworkRewardRate::get() +
+							Perquintill::from_parts(1),
+						..config
+					}
+				),
+				Error::<Test>::NetworkRewardRateTooHigh
+			);
+			// the upper bounds themselves are allowed
+			assert_ok!(StakePallet::set_staking_config(
+				Origin::root(),
+				StakingConfig {
+					stake_duration: <Test as Config>::MaxStakeDuration::get(),
+					network_reward_rate: <Test as Config>::MaxNetworkRewardRate::get(),
+					..config
+				}
+			));
+
+			assert_ok!(StakePallet::set_staking_config(Origin::root(), config));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::StakingConfigSet(config)));
+			assert_eq!(StakePallet::staking_config(), config);
+		});
+}
+
+#[test]
+fn staking_config_min_stakes() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.with_delegators(vec![(3, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let config = StakePallet::staking_config();
+			assert_ok!(StakePallet::set_staking_config(
+				Origin::root(),
+				StakingConfig { min_delegator_stake: 20, ..config }
+			));
+
+			// new delegations have to meet the new minimum
+			assert_noop!(
+				StakePallet::join_delegators(Origin::signed(4), 1, 15, None),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_ok!(StakePallet::join_delegators(Origin::signed(4), 1, 20, None));
+
+			// existing delegations below the minimum cannot be reduced
+			assert_noop!(
+				StakePallet::delegator_stake_less(Origin::signed(3), 1, 1),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, 5, None));
+			assert_eq!(StakePallet::delegator_state(3).unwrap().total, 15);
+			assert_ok!(StakePallet::revoke_delegation(Origin::signed(3), 1));
+
+			// candidates below the minimum collator stake are not selected
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+			assert_ok!(StakePallet::set_staking_config(
+				Origin::root(),
+				StakingConfig { min_collator_stake: 30, ..config }
+			));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1]);
+		});
+}
+
+#[test]
+fn staking_config_max_delegators_per_collator() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(2, 100),
+			(3, 100),
+			(4, 100),
+			(5, 100),
+			(6, 100),
+			(7, 100),
+			(8, 100),
+			(9, 100),
+		])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.with_delegators(vec![(3, 1, 10), (4, 1, 11), (5, 1, 12)])
+		.build_and_execute_with_sanity_tests(|| {
+			let config = StakePallet::staking_config();
+			assert_ok!(StakePallet::set_staking_config(
+				Origin::root(),
+				StakingConfig { max_delegators_per_collator: 2, ..config }
+			));
+
+			// collators exceeding the limit keep their delegators but new delegations
+			// replace the lowest one
+			assert_ok!(StakePallet::join_delegators(Origin::signed(6), 1, 13, None));
+			assert!(StakePallet::delegator_state(3).is_none());
+			let owners: Vec<AccountId> = StakePallet::candidate_pool(1)
+				.unwrap()
+				.delegators
+				.into_iter()
+				.map(|stake| stake.owner)
+				.collect();
+			assert_eq!(owners, vec![6, 5, 4]);
+			assert_noop!(
+				StakePallet::join_delegators(Origin::signed(7), 1, 11, None),
+				Error::<Test>::TooManyDelegators
+			);
+
+			// other collators only accept delegators up to the new limit
+			assert_ok!(StakePallet::join_delegators(Origin::signed(7), 2, 10, None));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(8), 2, 9, None));
+			assert_noop!(
+				StakePallet::join_delegators(Origin::signed(9), 2, 8, None),
+				Error::<Test>::TooManyDelegators
+			);
+			assert_ok!(StakePallet::join_delegators(Origin::signed(9), 2, 11, None));
+			assert!(StakePallet::delegator_state(8).is_none());
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().delegators.len(), 2);
+		});
+}
+
+#[test]
+fn staking_config_delays() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 20)])
+		.with_delegators(vec![(4, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let config = StakePallet::staking_config();
+			assert_ok!(StakePallet::set_staking_config(
+				Origin::root(),
+				StakingConfig { stake_duration: 7, exit_queue_delay: 4, ..config }
+			));
+
+			assert_ok!(StakePallet::init_leave_candidates(Origin::signed(3)));
+			let state = StakePallet::candidate_pool(3).unwrap();
+			assert_eq!(state.status, CandidateStatus::Leaving(4));
+			assert!(!state.can_exit(3));
+
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(4), 1, 5));
+			let mut unstaking: BoundedBTreeMap<
+				BlockNumber,
+				Balance,
+				<Test as Config>::MaxUnstakeRequests,
+			> = BoundedBTreeMap::new();
+			assert_ok!(unstaking.try_insert(8, 5));
+			assert_eq!(StakePallet::unstaking(4), unstaking);
+		});
+}
+
+#[test]
+fn round_snapshot() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, 100 * stake), (4, stake)])
+		.with_collators(vec![(1, stake), (3, 3 * stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			// delegator 4 receives a bonus on their rewards
+			assert_ok!(StakePallet::join_delegators(Origin::signed(4), 1, stake / 2, Some(20)));
+			assert_ok!(StakePallet::request_commission_change(
+				Origin::signed(1),
+				Perquintill::from_percent(10)
+			));
+			roll_to(BLOCKS_PER_ROUND * 3, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(Origin::signed(1)));
+
+			// the snapshot of the current round only contains the total backing
+			let round = StakePallet::round().current;
+			assert_eq!(
+				StakePallet::get_round_snapshot(round),
+				vec![
+					CollatorSnapshot {
+						collator: 3,
+						total: 3 * stake,
+						blocks_authored: 0,
+						collator_rewards: 0,
+						delegator_rewards: 0,
+					},
+					CollatorSnapshot {
+						collator: 1,
+						total: 2 * stake + stake / 2,
+						blocks_authored: 0,
+						collator_rewards: 0,
+						delegator_rewards: 0,
+					},
+				]
+			);
+
+			// collator 1 authors two blocks and 3 authors one block
+			let first = System::block_number() as usize;
+			let mut authors = vec![None; first];
+			authors.extend([Some(1), Some(3), Some(1)]);
+			roll_to(StakePallet::round().first + BLOCKS_PER_ROUND - 1, authors);
+			assert_eq!(StakePallet::round().current, round);
+
+			// nothing has been credited yet
+			for entry in StakePallet::get_round_snapshot(round) {
+				assert!(entry.collator_rewards.is_zero());
+				assert!(entry.delegator_rewards.is_zero());
+			}
+
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(1)));
+			assert_ok!(StakePallet::increment_collator_rewards(Origin::signed(3)));
+			roll_to(StakePallet::round().first + BLOCKS_PER_ROUND, vec![]);
+			assert_eq!(StakePallet::round().current, round + 1);
+
+			// rewards match the credited ones including the bonus of delegator 4
+			let snapshot = StakePallet::get_round_snapshot(round);
+			assert_eq!(snapshot[0].blocks_authored, 1);
+			assert_eq!(
+				snapshot[0].collator_rewards,
+				StakePallet::get_unclaimed_staking_rewards(&3)
+			);
+			assert!(snapshot[0].delegator_rewards.is_zero());
+			assert_eq!(snapshot[1].blocks_authored, 2);
+			assert_eq!(
+				snapshot[1].collator_rewards,
+				StakePallet::get_unclaimed_staking_rewards(&1)
+			);
+			assert_eq!(
+				snapshot[1].delegator_rewards,
+				StakePallet::get_unclaimed_staking_rewards(&2) +
+					StakePallet::get_unclaimed_staking_rewards(&4)
+			);
+			assert!(
+				StakePallet::get_unclaimed_staking_rewards(&4) >
+					StakePallet::get_unclaimed_staking_rewards(&2) / 2
+			);
+			assert!(StakePallet::get_round_snapshot(round + 1).iter().all(|entry| {
+				entry.blocks_authored.is_zero() &&
+					entry.collator_rewards.is_zero() &&
+					entry.delegator_rewards.is_zero()
+			}));
+		});
+}
+
+#[test]
+fn round_snapshot_pruning() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			// snapshots of the first two rounds are recorded at genesis
+			assert_eq!(StakePallet::get_round_snapshot(0).len(), 2);
+			assert_eq!(StakePallet::get_round_snapshot(1).len(), 2);
+			assert!(StakePallet::get_round_snapshot(2).is_empty());
+
+			roll_to(BLOCKS_PER_ROUND * 5, vec![]);
+			assert_eq!(StakePallet::round().current, 5);
+			for round in 0..=3 {
+				assert!(StakePallet::get_round_snapshot(round).is_empty());
+			}
+			for round in 4..=6 {
+				assert_eq!(StakePallet::get_round_snapshot(round).len(), 2);
+			}
+		});
+}
+
+#[test]
+fn invulnerables_are_always_selected() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.build_and_execute_with_sanity_tests(|| {
+			let invulnerables = |accounts: Vec<AccountId>| -> BoundedVec<
+				AccountId,
+				<Test as Config>::MinRequiredCollators,
+			> { accounts.try_into().unwrap() };
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+
+			assert_noop!(
+				StakePallet::set_invulnerables(Origin::signed(1), invulnerables(vec![4])),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				StakePallet::set_invulnerables(Origin::root(), invulnerables(vec![4, 4])),
+				Error::<Test>::DuplicateInvulnerable
+			);
+
+			// invulnerables do not have to be candidates
+			assert_ok!(StakePallet::set_invulnerables(Origin::root(), invulnerables(vec![4])));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::InvulnerablesSet(vec![4])));
+			assert_eq!(StakePallet::invulnerables().into_inner(), vec![4]);
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![4, 1]);
+
+			// invulnerables are selected regardless of their stake
+			assert_ok!(StakePallet::set_invulnerables(Origin::root(), invulnerables(vec![3])));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![3, 1]);
+			assert_ok!(StakePallet::set_invulnerables(Origin::root(), invulnerables(vec![3, 2])));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![3, 2]);
+
+			// invulnerables are not rewarded
+			StakePallet::note_author(3);
+			assert!(StakePallet::reward_count(3).is_zero());
+			assert_eq!(StakePallet::blocks_authored(3), 1);
+			StakePallet::note_author(1);
+			assert!(!StakePallet::reward_count(1).is_zero());
+
+			assert_ok!(StakePallet::set_invulnerables(Origin::root(), invulnerables(vec![])));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+		});
+}
+
+#[test]
+fn stake_lock() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10 * DECIMALS), (2, 10 * DECIMALS), (3, 10 * DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(3, 1, 2 * DECIMALS)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::join_delegators(Origin::signed(2), 1, 2 * DECIMALS, Some(5)),
+				Error::<Test>::InvalidLockPeriod
+			);
+			assert_ok!(StakePallet::join_delegators(Origin::signed(2), 1, 2 * DECIMALS, Some(20)));
+			assert_eq!(
+				StakePallet::stake_lock(2),
+				Some(StakeLock { until: 21, bonus: Perquintill::from_percent(50) })
+			);
+			assert!(events().contains(&Event::StakeLocked(2, 21, Perquintill::from_percent(50))));
+
+			// the lock cannot be shortened
+			assert_noop!(
+				StakePallet::delegator_stake_more(Origin::signed(2), 1, DECIMALS, Some(10)),
+				Error::<Test>::LockCannotBeShortened
+			);
+
+			// delegators with an active lock receive a bonus on their rewards
+			StakePallet::note_author(1);
+			let reward_3 = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert!(!reward_3.is_zero());
+			assert_eq!(
+				StakePallet::get_unclaimed_staking_rewards(&2),
+				reward_3 + Perquintill::from_percent(50) * reward_3
+			);
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(2)));
+			assert_ok!(StakePallet::increment_delegator_rewards(Origin::signed(3)));
+			assert_eq!(
+				StakePallet::rewards(2),
+				reward_3 + Perquintill::from_percent(50) * reward_3
+			);
+			assert_eq!(StakePallet::rewards(3), reward_3);
+
+			// unstaked funds cannot be unlocked before the lock ends
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(2), 1, DECIMALS));
+			assert_eq!(StakePallet::unstaking(2).get(&21), Some(&DECIMALS));
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(3), 1, DECIMALS));
+			assert_eq!(
+				StakePallet::unstaking(3).get(&(1 + <Test as Config>::StakeDuration::get() as u64)),
+				Some(&DECIMALS)
+			);
+
+			// the bonus does not apply once the lock has ended
+			System::set_block_number(21);
+			StakePallet::note_author(1);
+			assert_eq!(
+				StakePallet::get_unclaimed_staking_rewards(&2) - StakePallet::rewards(2),
+				StakePallet::get_unclaimed_staking_rewards(&3) - StakePallet::rewards(3)
+			);
+
+			// the lock is removed when leaving
+			assert_ok!(StakePallet::leave_delegators(Origin::signed(2)));
+			assert!(StakePallet::stake_lock(2).is_none());
+		});
+}
+
+#[test]
+fn forced_removal_ignores_stake_lock() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::join_delegators(Origin::signed(4), 3, 10, Some(20)));
+			assert_eq!(StakePallet::stake_lock(4).map(|lock| lock.until), Some(21));
+
+			assert_ok!(StakePallet::init_leave_candidates(Origin::signed(3)));
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().status, CandidateStatus::Leaving(2));
+			roll_to(2 * BLOCKS_PER_ROUND, vec![]);
+			assert_ok!(StakePallet::execute_leave_candidates(Origin::signed(3), 3));
+
+			// the delegator was forcedly removed and does not have to wait for their lock
+			let mut unstaking: BoundedBTreeMap<
+				BlockNumber,
+				Balance,
+				<Test as Config>::MaxUnstakeRequests,
+			> = BoundedBTreeMap::new();
+			assert_ok!(unstaking.try_insert(12, 10));
+			assert_eq!(StakePallet::unstaking(4), unstaking);
+			assert!(StakePallet::stake_lock(4).is_none());
+		});
+}
+
+#[test]
+fn extra_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (5, 1000)])
+		.with_collators(vec![(1, 600), (2, 1000)])
+		.with_delegators(vec![(3, 1, 400)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pot = StakePallet::extra_rewards_pot();
+			assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(1, &pot, 150));
+
+			assert_noop!(
+				StakePallet::set_extra_reward_rate(Origin::signed(1), 1, 100),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(StakePallet::set_extra_reward_rate(Origin::root(), 1, 100));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::ExtraRewardRateSet(1, 100)));
+			assert_ok!(StakePallet::set_extra_reward_rate(Origin::root(), 2, 50));
+			assert_noop!(
+				StakePallet::set_extra_reward_rate(Origin::root(), 3, 50),
+				Error::<Test>::TooManyExtraRewardCurrencies
+			);
+			// setting the rate to zero stops the distribution
+			assert_ok!(StakePallet::set_extra_reward_rate(Origin::root(), 2, 0));
+			assert_eq!(StakePallet::extra_reward_rates().into_inner(), vec![(1, 100)]);
+
+			// both collators author blocks and receive half of the rewards
+			let round = StakePallet::round().current;
+			StakePallet::note_author(1);
+			StakePallet::note_author(2);
+			roll_to(StakePallet::round().first + BLOCKS_PER_ROUND, vec![]);
+			assert!(events().contains(&Event::ExtraRewardsDistributed(round, 1, 100)));
+			assert_eq!(StakePallet::extra_rewards_allocated(1), 100);
+
+			// the rewards of a collator are shared with their delegators pro rata to stake
+			assert_noop!(
+				StakePallet::claim_extra_rewards(Origin::signed(3), 2),
+				Error::<Test>::RewardsNotFound
+			);
+			assert_ok!(StakePallet::claim_extra_rewards(Origin::signed(3), 1));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::ExtraRewardsClaimed(3, 3, 1, 20))
+			);
+			assert_eq!(<Tokens as MultiCurrency<AccountId>>::free_balance(1, &3), 20);
+			assert_ok!(StakePallet::claim_extra_rewards(Origin::signed(1), 1));
+			assert_eq!(<Tokens as MultiCurrency<AccountId>>::free_balance(1, &1), 30);
+			assert_noop!(
+				StakePallet::claim_extra_rewards(Origin::signed(1), 1),
+				Error::<Test>::RewardsNotFound
+			);
+
+			// extra rewards are paid into the reward destination
+			assert_ok!(StakePallet::set_reward_destination(
+				Origin::signed(2),
+				RewardDestination::Account(4)
+			));
+			assert_ok!(StakePallet::claim_extra_rewards(Origin::signed(2), 1));
+			assert_eq!(<Tokens as MultiCurrency<AccountId>>::free_balance(1, &4), 50);
+			assert!(StakePallet::extra_rewards_allocated(1).is_zero());
+
+			// new delegators only receive rewards of later rounds and the
+			// distribution is limited by the balance of the pot
+			assert_ok!(StakePallet::join_delegators(Origin::signed(5), 2, 1000, None));
+			let round = StakePallet::round().current;
+			StakePallet::note_author(2);
+			roll_to(StakePallet::round().first + BLOCKS_PER_ROUND, vec![]);
+			assert!(events().contains(&Event::ExtraRewardsDistributed(round, 1, 50)));
+			assert_eq!(StakePallet::extra_rewards_allocated(1), 50);
+			assert_ok!(StakePallet::claim_extra_rewards(Origin::signed(5), 1));
+			assert_eq!(<Tokens as MultiCurrency<AccountId>>::free_balance(1, &5), 25);
+			assert_ok!(StakePallet::claim_extra_rewards(Origin::signed(2), 1));
+			assert_eq!(<Tokens as MultiCurrency<AccountId>>::free_balance(1, &4), 75);
+
+			// nothing is distributed once the pot is empty
+			let round = StakePallet::round().current;
+			StakePallet::note_author(2);
+			roll_to(StakePallet::round().first + BLOCKS_PER_ROUND, vec![]);
+			assert!(!events()
+				.iter()
+				.any(|e| matches!(e, Event::ExtraRewardsDistributed(r, ..) if *r == round)));
+			assert!(StakePallet::extra_rewards(5, 1).is_zero());
+		});
+}
+
+#[test]
+fn voting_power() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 50), (2, 40)])
+		.with_delegators(vec![(3, 1, 30)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(StakePallet::voting_power(&1), 50);
+			assert_eq!(StakePallet::voting_power(&2), 40);
+			assert_eq!(StakePallet::voting_power(&3), 30);
+			assert!(StakePallet::voting_power(&4).is_zero());
+
+			// staked funds are locked but remain part of the free balance
+			for acc in 1..=3 {
+				assert_eq!(Balances::free_balance(acc), 100);
+			}
+
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(3), 1, 20, None));
+			assert_eq!(StakePallet::voting_power(&3), 50);
+
+			// unstaked funds do not count even though they are still locked
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(3), 1, 10));
+			assert_eq!(StakePallet::voting_power(&3), 40);
+			assert_ok!(StakePallet::leave_delegators(Origin::signed(3)));
+			assert!(StakePallet::voting_power(&3).is_zero());
+		});
+}
+
+#[test]
+fn stake_voting_currency() {
+	type VotingCurrency = StakeVotingCurrency<Balances, StakePallet>;
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 50), (2, 40)])
+		.with_delegators(vec![(3, 1, 30)])
+		.build_and_execute_with_sanity_tests(|| {
+			// the stake counts but is not added on top of the free balance
+			for acc in 1..=4 {
+				assert_eq!(VotingCurrency::free_balance(&acc), 100);
+			}
+
+			// the vote lock overlays the staking lock
+			VotingCurrency::extend_lock(
+				*b"democrac",
+				&3,
+				100,
+				WithdrawReasons::except(WithdrawReasons::RESERVE),
+			);
+			assert_eq!(Balances::locks(3).len(), 2);
+			assert!(Balances::usable_balance(&3).is_zero());
+			assert_eq!(StakePallet::voting_power(&3), 30);
+			VotingCurrency::remove_lock(*b"democrac", &3);
+			assert_eq!(Balances::usable_balance(&3), 70);
+		});
+}
+
+#[test]
+fn staking_update_hooks() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 200), (2, 200), (3, 200), (4, 200)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			// genesis delegations are reported as well
+			assert_eq!(staking_updates(), vec![StakingUpdate::Created(3, 1, 50)]);
+
+			assert_ok!(StakePallet::join_delegators(Origin::signed(4), 1, 20, None));
+			assert_ok!(StakePallet::delegator_stake_more(Origin::signed(4), 1, 10, None));
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(4), 1, 5));
+			assert_ok!(StakePallet::redelegate(Origin::signed(4), 1, 2, 10));
+			assert_ok!(StakePallet::revoke_delegation(Origin::signed(4), 2));
+			assert_eq!(
+				staking_updates(),
+				vec![
+					StakingUpdate::Created(4, 1, 20),
+					StakingUpdate::Increased(4, 1, 20, 30),
+					StakingUpdate::Reduced(4, 1, 30, 25),
+					StakingUpdate::Reduced(4, 1, 25, 15),
+					StakingUpdate::Created(4, 2, 10),
+					StakingUpdate::Removed(4, 2, 10),
+				]
+			);
+
+			assert_ok!(StakePallet::leave_delegators(Origin::signed(4)));
+			assert_eq!(staking_updates(), vec![StakingUpdate::Removed(4, 1, 15)]);
+
+			// paid out rewards are reported
+			Rewards::<Test>::insert(4, 5);
+			assert_ok!(StakePallet::claim_rewards(Origin::signed(4)));
+			assert_eq!(staking_updates(), vec![StakingUpdate::RewardsPaid(4, 5)]);
+
+			// all hooks of the tuple are called
+			assert!(new_rounds().is_zero());
+			roll_to(StakePallet::round().first + BLOCKS_PER_ROUND, vec![]);
+			assert_eq!(staking_updates(), vec![StakingUpdate::NewRound(1)]);
+			assert_eq!(new_rounds(), 1);
+		});
+}
+
+#[test]
+fn cancel_unstaking() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 200), (2, 200)])
+		.with_collators(vec![(1, 100)])
+		.with_delegators(vec![(2, 1, 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::candidate_stake_less(Origin::signed(1), 30));
+			assert_eq!(StakePallet::unstaking(1).get(&3), Some(&30));
+
+			assert_noop!(
+				StakePallet::cancel_unstaking(Origin::signed(1), 4, 10, None),
+				Error::<Test>::UnstakingNotFound
+			);
+			assert_noop!(
+				StakePallet::cancel_unstaking(Origin::signed(1), 3, 40, None),
+				Error::<Test>::Underflow
+			);
+			assert_noop!(
+				StakePallet::cancel_unstaking(Origin::signed(1), 3, 0, None),
+				Error::<Test>::ValStakeZero
+			);
+
+			assert_ok!(StakePallet::cancel_unstaking(Origin::signed(1), 3, 10, None));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, 80);
+			assert_eq!(StakePallet::unstaking(1).get(&3), Some(&20));
+			assert_eq!(
+				Balances::locks(1),
+				vec![BalanceLock { id: STAKING_ID, amount: 100, reasons: Reasons::All }]
+			);
+			assert!(events().contains(&Event::UnstakingCancelled(1, 3, 10)));
+
+			// only the targeted request is consumed, the lock remains the same
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(2), 1, 10));
+			System::set_block_number(2);
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(2), 1, 10));
+			assert_ok!(StakePallet::cancel_unstaking(Origin::signed(2), 4, 10, Some(1)));
+			assert_eq!(StakePallet::delegator_state(2).unwrap().total, 40);
+			assert_eq!(StakePallet::unstaking(2).len(), 1);
+			assert_eq!(StakePallet::unstaking(2).get(&3), Some(&10));
+			assert_eq!(
+				Balances::locks(2),
+				vec![BalanceLock { id: STAKING_ID, amount: 50, reasons: Reasons::All }]
+			);
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::UnstakingCancelled(2, 4, 10)));
+
+			// delegators have to provide the collator
+			assert_noop!(
+				StakePallet::cancel_unstaking(Origin::signed(2), 3, 10, None),
+				Error::<Test>::CandidateNotFound
+			);
+		});
+}
+
+#[test]
+fn cancel_unstaking_restakes_into_chosen_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 200), (2, 200), (3, 200)])
+		.with_collators(vec![(1, 100), (3, 100)])
+		.with_delegators(vec![(2, 1, 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(2), 3, 20));
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(2), 1, 10));
+			System::set_block_number(2);
+			assert_ok!(StakePallet::delegator_stake_less(Origin::signed(2), 3, 10));
+
+			// the funds unstaked from collator 1 are restaked for collator 3
+			assert_ok!(StakePallet::cancel_unstaking(Origin::signed(2), 3, 10, Some(3)));
+			let delegation = |collator| {
+				StakePallet::get_delegations(&2)
+					.into_iter()
+					.find(|info| info.collator == collator)
+					.map(|info| info.amount)
+			};
+			assert_eq!(delegation(1), Some(40));
+			assert_eq!(delegation(3), Some(20));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 140);
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().total, 120);
+
+			// the other unstaking request is untouched
+			assert_eq!(StakePallet::unstaking(2).len(), 1);
+			assert_eq!(StakePallet::unstaking(2).get(&4), Some(&10));
+			assert_eq!(
+				Balances::locks(2),
+				vec![BalanceLock { id: STAKING_ID, amount: 70, reasons: Reasons::All }]
+			);
+			assert_noop!(
+				StakePallet::cancel_unstaking(Origin::signed(2), 3, 10, Some(3)),
+				Error::<Test>::UnstakingNotFound
+			);
+		});
+}
+
+#[test]
+fn displaced_delegations() {
+	ExtBuilder::default()
+		.with_balances((1..=8).map(|acc| (acc, 200)).collect())
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.with_delegators(vec![(5, 1, 20), (6, 1, 20), (7, 1, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::join_delegators(Origin::signed(4), 1, 10, None));
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(4), 2, 10));
+			assert!(StakePallet::get_displaced_delegations(&4).is_empty());
+
+			// 8 replaces the lowest delegation of collator 1
+			assert_ok!(StakePallet::join_delegators(Origin::signed(8), 1, 30, None));
+			assert_eq!(
+				StakePallet::get_displaced_delegations(&4),
+				vec![DisplacedDelegation { collator: 1, round: 0, amount: 10 }]
+			);
+			assert_eq!(StakePallet::unstaking(4).get(&3), Some(&10));
+
+			// without a grace period the kicked delegation still counts
+			assert_eq!(StakePallet::last_delegation(4), DelegationCounter { round: 0, counter: 2 });
+			assert_noop!(
+				StakePallet::delegate_another_candidate(Origin::signed(4), 3, 10),
+				Error::<Test>::DelegationsPerRoundExceeded
+			);
+		});
+}
+
+#[test]
+fn displaced_delegator_grace_period() {
+	ExtBuilder::default()
+		.with_balances((1..=8).map(|acc| (acc, 200)).collect())
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.with_delegators(vec![(5, 1, 20), (6, 1, 20), (7, 1, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			DisplacedDelegatorGracePeriod::set(true);
+
+			assert_ok!(StakePallet::join_delegators(Origin::signed(4), 1, 10, None));
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(4), 2, 10));
+			assert_ok!(StakePallet::join_delegators(Origin::signed(8), 1, 30, None));
+
+			// the kicked delegator can re-join another collator in the same round
+			assert_eq!(StakePallet::last_delegation(4), DelegationCounter { round: 0, counter: 1 });
+			assert_ok!(StakePallet::delegate_another_candidate(Origin::signed(4), 3, 10));
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, 20);
+			assert_eq!(
+				StakePallet::get_displaced_delegations(&4),
+				vec![DisplacedDelegation { collator: 1, round: 0, amount: 10 }]
+			);
+		});
+}
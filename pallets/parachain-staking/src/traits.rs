@@ -0,0 +1,107 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2023 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Traits which allow other pallets to make use of the staked balances.
+
+use sp_staking::SessionIndex;
+
+/// Provides the voting power which an account derives from their staked
+/// balance.
+///
+/// Staked funds are locked but remain part of the free balance of an
+/// account. Thus, the voting power must not be added on top of the free
+/// balance, otherwise the staked funds would be counted twice.
+pub trait VotingPowerProvider<AccountId, Balance> {
+	/// The voting power of the given account.
+	fn voting_power(who: &AccountId) -> Balance;
+}
+
+/// Hooks which are called whenever a delegation changes, when rewards are paid
+/// out and when a new round starts.
+///
+/// The hooks are called after the change has been applied to the staking
+/// state.
+pub trait OnStakingUpdate<AccountId, Balance> {
+	/// A delegator has delegated `amount` to a collator candidate.
+	fn on_delegation_created(_delegator: &AccountId, _collator: &AccountId, _amount: Balance) {}
+
+	/// The stake of a delegation has increased from `old` to `new`.
+	fn on_delegation_increased(
+		_delegator: &AccountId,
+		_collator: &AccountId,
+		_old: Balance,
+		_new: Balance,
+	) {
+	}
+
+	/// The stake of a delegation has been reduced from `old` to `new`.
+	fn on_delegation_reduced(
+		_delegator: &AccountId,
+		_collator: &AccountId,
+		_old: Balance,
+		_new: Balance,
+	) {
+	}
+
+	/// A delegation with a stake of `amount` has been removed.
+	fn on_delegation_removed(_delegator: &AccountId, _collator: &AccountId, _amount: Balance) {}
+
+	/// Staking rewards of `amount` have been paid out to the account `who`,
+	/// either by restaking them or by transferring them to the account.
+	fn on_rewards_paid(_who: &AccountId, _amount: Balance) {}
+
+	/// The round `round` has started.
+	fn on_new_round(_round: SessionIndex) {}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, Balance: Copy> OnStakingUpdate<AccountId, Balance> for Tuple {
+	fn on_delegation_created(delegator: &AccountId, collator: &AccountId, amount: Balance) {
+		for_tuples!( #( Tuple::on_delegation_created(delegator, collator, amount); )* );
+	}
+
+	fn on_delegation_increased(
+		delegator: &AccountId,
+		collator: &AccountId,
+		old: Balance,
+		new: Balance,
+	) {
+		for_tuples!( #( Tuple::on_delegation_increased(delegator, collator, old, new); )* );
+	}
+
+	fn on_delegation_reduced(
+		delegator: &AccountId,
+		collator: &AccountId,
+		old: Balance,
+		new: Balance,
+	) {
+		for_tuples!( #( Tuple::on_delegation_reduced(delegator, collator, old, new); )* );
+	}
+
+	fn on_delegation_removed(delegator: &AccountId, collator: &AccountId, amount: Balance) {
+		for_tuples!( #( Tuple::on_delegation_removed(delegator, collator, amount); )* );
+	}
+
+	fn on_rewards_paid(who: &AccountId, amount: Balance) {
+		for_tuples!( #( Tuple::on_rewards_paid(who, amount); )* );
+	}
+
+	fn on_new_round(round: SessionIndex) {
+		for_tuples!( #( Tuple::on_new_round(round); )* );
+	}
+}
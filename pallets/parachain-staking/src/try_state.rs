@@ -26,8 +26,9 @@ use sp_runtime::{
 use crate::{
 	set::OrderedSet,
 	types::{BalanceOf, Candidate, Stake},
-	CandidatePool, Config, DelegatorState, LastDelegation, MaxCollatorCandidateStake,
-	MaxSelectedCandidates, Pallet, Round, TopCandidates, TotalCollatorStake,
+	CandidatePool, Config, DelegatorState, LastDelegation, LivenessConfiguration,
+	MaxCollatorCandidateStake, MaxSelectedCandidates, MissedRounds, Pallet, Round, RoundSnapshots,
+	TopCandidates, TotalCollatorStake,
 };
 
 pub fn log_and_return_error_message(error_message: String) -> &'static str {
@@ -40,6 +41,8 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), &'static str> {
 	validate_candiate_pool::<T>()?;
 	validate_delegators::<T>()?;
 	validate_top_candidates::<T>()?;
+	validate_liveness::<T>()?;
+	validate_round_snapshots::<T>()?;
 	validate_stake::<T>()
 }
 
@@ -82,7 +85,18 @@ fn validate_candiate_pool<T: Config>() -> Result<(), &'static str> {
 				))
 			);
 
-			validate_delegators_from_collator::<T>(candidate.delegators)?;
+			// commission must not exceed the maximum
+			ensure!(
+				candidate.commission <= T::MaxCollatorCommission::get(),
+				log_and_return_error_message(format!(
+					"Commission of collator {:?} too high. Allowed commission: {:?}. Set commission: {:?}",
+					candidate.id,
+					T::MaxCollatorCommission::get(),
+					candidate.commission
+				))
+			);
+
+			validate_delegators_from_collator::<T>(&candidate.id, candidate.delegators)?;
 
 			// check min and max stake for each candidate
 			ensure!(
@@ -142,7 +156,61 @@ fn validate_top_candidates<T: Config>() -> Result<(), &'static str> {
 	})
 }
 
+fn validate_liveness<T: Config>() -> Result<(), &'static str> {
+	let config = match LivenessConfiguration::<T>::get() {
+		Some(config) => config,
+		None => {
+			// without a configuration no missed rounds should be tracked.
+			ensure!(
+				MissedRounds::<T>::iter().next().is_none(),
+				log_and_return_error_message(String::from(
+					"Missed rounds are tracked without a liveness configuration."
+				))
+			);
+			return Ok(());
+		},
+	};
+
+	MissedRounds::<T>::iter().try_for_each(|(collator, missed)| -> Result<(), &'static str> {
+		// only collator candidates can miss rounds.
+		ensure!(
+			CandidatePool::<T>::contains_key(&collator),
+			log_and_return_error_message(format!(
+				"Missed rounds are tracked for unknown candidate {:?}.",
+				collator
+			))
+		);
+
+		// the counter is reset as soon as the penalty is applied.
+		ensure!(
+			missed < config.max_missed_rounds.max(1),
+			log_and_return_error_message(format!(
+				"Collator {:?} exceeded missed rounds. Allowed: {:?}. Missed: {:?}",
+				collator, config.max_missed_rounds, missed
+			))
+		);
+
+		Ok(())
+	})
+}
+
+fn validate_round_snapshots<T: Config>() -> Result<(), &'static str> {
+	let count: u32 = RoundSnapshots::<T>::iter_keys().count().saturated_into();
+	// outdated snapshots are removed when a new one is recorded.
+	ensure!(
+		count <= T::MaxRoundSnapshots::get(),
+		log_and_return_error_message(format!(
+			"Too many round snapshots. Allowed: {:?}. Stored: {:?}",
+			T::MaxRoundSnapshots::get(),
+			count
+		))
+	);
+
+	Ok(())
+}
+
 fn validate_delegators_from_collator<T: Config>(
+	collator: &T::AccountId,
 	delegators: OrderedSet<Stake<T::AccountId, BalanceOf<T>>, T::MaxDelegatorsPerCollator>,
 ) -> Result<(), &'static str> {
 	delegators
@@ -165,20 +233,25 @@ fn validate_delegators_from_collator<T: Config>(
 				))
 			);
 
-			// each delegator should have the min required stake
+			// each delegator should have some stake, existing delegations can fall below the
+			// min required stake when it is raised
 			ensure!(
-				delegator_stake.amount >= T::MinDelegatorStake::get(),
+				!delegator_stake.amount.is_zero(),
 				log_and_return_error_message(format!(
-					"Delegator {:?} insufficient stake. Required stake: {:?}. Owned stake: {:?}",
-					delegator_stake.owner,
-					T::MinDelegatorStake::get(),
-					delegator_stake.amount
+					"Delegator {:?} has no stake for collator {:?}",
+					delegator_stake.owner, collator
 				))
 			);
 
+			// the delegator state should contain the same delegation
 			ensure!(
-				DelegatorState::<T>::get(&delegator_stake.owner).is_some(),
-				log_and_return_error_message(format!("Unknown delegator {:?}", delegator_stake.owner))
+				DelegatorState::<T>::get(&delegator_stake.owner)
+					.and_then(|delegator| delegator.delegation(collator.clone()).ok())
+					== Some(delegator_stake.amount),
+				log_and_return_error_message(format!(
+					"Delegation of {:?} to collator {:?} does not match the delegator state",
+					delegator_stake.owner, collator
+				))
 			);
 
 			Ok(())
@@ -226,20 +299,49 @@ fn validate_stake<T: Config>() -> Result<(), &'static str> {
 }
 
 fn validate_delegators<T: Config>() -> Result<(), &'static str> {
-	DelegatorState::<T>::iter_values().try_for_each(
-		|delegator_details| -> Result<(), &'static str> {
-			let Some(owner) = &delegator_details.owner else {
-				return Err("owner not found");
-			};
+	DelegatorState::<T>::iter().try_for_each(
+		|(delegator, delegator_details)| -> Result<(), &'static str> {
+			// a delegator should have at least one delegation
+			ensure!(
+				!delegator_details.delegations.is_empty(),
+				log_and_return_error_message(format!(
+					"Delegator {:?} has no delegations",
+					delegator
+				))
+			);
 
+			// total stake should be the sum of all delegations
+			let sum_delegations: BalanceOf<T> = delegator_details
+				.delegations
+				.iter()
+				.fold(Zero::zero(), |acc, stake| acc.saturating_add(stake.amount));
 			ensure!(
-				CandidatePool::<T>::contains_key(owner),
+				sum_delegations == delegator_details.total,
 				log_and_return_error_message(format!(
-					"Collator {:?} not found",
-					delegator_details.owner
+					"Total stake of delegator {:?} does not match. Saved stake: {:?}. Calculated stake: {:?}",
+					delegator, delegator_details.total, sum_delegations
 				))
 			);
-			Ok(())
+
+			delegator_details.delegations.iter().try_for_each(
+				|delegation| -> Result<(), &'static str> {
+					// each delegated collator should be part of the candidate pool and know the
+					// delegator
+					ensure!(
+						CandidatePool::<T>::get(&delegation.owner).map_or(false, |candidate| {
+							candidate.delegators.contains(&Stake {
+								owner: delegator.clone(),
+								amount: delegation.amount,
+							})
+						}),
+						log_and_return_error_message(format!(
+							"Collator {:?} not found or not delegated by {:?}",
+							delegation.owner, delegator
+						))
+					);
+					Ok(())
+				},
+			)
 		},
 	)
 }
@@ -17,11 +17,16 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::traits::{Currency, Get};
+use frame_system::pallet_prelude::BlockNumberFor;
+use module_pallet_staking_rpc_runtime_api::{
+	CandidateState, CollatorSnapshot, DisplacedDelegation,
+};
+use orml_traits::MultiCurrency;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, CheckedSub, Saturating, Zero},
-	RuntimeDebug,
+	Perquintill, RuntimeDebug,
 };
 use sp_staking::SessionIndex;
 use sp_std::{
@@ -93,6 +98,18 @@ pub enum CandidateStatus {
 	Active,
 	/// Staked until the inner round
 	Leaving(SessionIndex),
+	/// Missed too many blocks and is not selected until going online again
+	Idle,
+}
+
+impl From<CandidateStatus> for CandidateState {
+	fn from(status: CandidateStatus) -> Self {
+		match status {
+			CandidateStatus::Active => CandidateState::Active,
+			CandidateStatus::Leaving(round) => CandidateState::Leaving(round),
+			CandidateStatus::Idle => CandidateState::Idle,
+		}
+	}
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -122,6 +139,9 @@ where
 	/// The current status of the candidate. Indicates whether a candidate is
 	/// active or leaving the candidate pool
 	pub status: CandidateStatus,
+
+	/// The share of the delegators' rewards which is paid to the candidate.
+	pub commission: Perquintill,
 }
 
 impl<A, B, S> Candidate<A, B, S>
@@ -138,6 +158,7 @@ where
 			delegators: OrderedSet::new(),
 			total,
 			status: CandidateStatus::default(), // default active
+			commission: Perquintill::zero(),
 		}
 	}
 
@@ -149,6 +170,18 @@ where
 		matches!(self.status, CandidateStatus::Leaving(_))
 	}
 
+	pub fn is_idle(&self) -> bool {
+		self.status == CandidateStatus::Idle
+	}
+
+	pub fn go_idle(&mut self) {
+		self.status = CandidateStatus::Idle;
+	}
+
+	pub fn go_online(&mut self) {
+		self.status = CandidateStatus::Active;
+	}
+
 	pub fn can_exit(&self, when: u32) -> bool {
 		matches!(self.status, CandidateStatus::Leaving(at) if at <= when )
 	}
@@ -201,8 +234,39 @@ where
 	}
 }
 
-pub type Delegator<AccountId, Balance> = Stake<Option<AccountId>, Balance>;
-impl<AccountId, Balance> Delegator<AccountId, Balance>
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxCollatorsPerDelegator))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+/// Global delegator state with all delegations and their total stake
+pub struct Delegator<AccountId, Balance, MaxCollatorsPerDelegator>
+where
+	AccountId: Eq + Ord,
+	Balance: Eq + Ord,
+	MaxCollatorsPerDelegator: Get<u32>,
+{
+	/// The collator candidates which are backed by the delegator.
+	pub delegations: OrderedSet<Stake<AccountId, Balance>, MaxCollatorsPerDelegator>,
+
+	/// The total stake of the delegator.
+	///
+	/// Should equal the sum of all delegations.
+	pub total: Balance,
+}
+
+impl<AccountId, Balance, MaxCollatorsPerDelegator> Default
+	for Delegator<AccountId, Balance, MaxCollatorsPerDelegator>
+where
+	AccountId: Eq + Ord + Clone,
+	Balance: Eq + Ord + Clone + Zero,
+	MaxCollatorsPerDelegator: Get<u32>,
+{
+	fn default() -> Self {
+		Delegator { delegations: OrderedSet::new(), total: Balance::zero() }
+	}
+}
+
+impl<AccountId, Balance, MaxCollatorsPerDelegator>
+	Delegator<AccountId, Balance, MaxCollatorsPerDelegator>
 where
 	AccountId: Eq + Ord + Clone + Debug,
 	Balance: Copy
@@ -215,41 +279,55 @@ where
 		+ Zero
 		+ Default
 		+ CheckedSub,
+	MaxCollatorsPerDelegator: Get<u32>,
 {
 	/// Adds a new delegation.
 	///
-	/// If already delegating to someone, this call will fail.
-	pub fn add_delegation(&mut self, stake: Stake<AccountId, Balance>) -> Result<(), ()> {
-		if self.owner.is_none() && self.amount.is_zero() {
-			self.owner = Some(stake.owner);
-			self.amount = stake.amount;
-			Ok(())
+	/// Returns Ok(true) if the delegation was added, Ok(false) if the
+	/// collator is already delegated and `Err` if the maximum number of
+	/// delegations would be exceeded.
+	pub fn add_delegation(&mut self, stake: Stake<AccountId, Balance>) -> Result<bool, ()> {
+		let amount = stake.amount;
+		if self.delegations.try_insert(stake).map_err(|_| ())? {
+			self.total = self.total.saturating_add(amount);
+			Ok(true)
 		} else {
-			Err(())
+			Ok(false)
 		}
 	}
 
-	/// Returns Ok if the delegation for the
-	/// collator exists and `Err` otherwise.
-	pub fn rm_delegation(&mut self, collator: AccountId) -> Result<(), ()> {
-		if self.owner == Some(collator) {
-			self.amount = Balance::zero();
-			self.owner = None;
-			Ok(())
-		} else {
-			Err(())
-		}
+	/// Returns Ok(delegated_amount) if the delegation for the collator
+	/// existed and was removed, `Err` otherwise.
+	pub fn rm_delegation(&mut self, collator: AccountId) -> Result<Balance, ()> {
+		let amount = self
+			.delegations
+			.remove(&Stake { owner: collator, amount: Balance::zero() })
+			.map(|stake| stake.amount)
+			.ok_or(())?;
+		self.total = self.total.saturating_sub(amount);
+		Ok(amount)
+	}
+
+	/// Returns Ok(delegated_amount) if the delegation for the collator
+	/// exists, `Err` otherwise.
+	pub fn delegation(&self, collator: AccountId) -> Result<Balance, ()> {
+		self.delegations
+			.linear_search(&Stake { owner: collator, amount: Balance::zero() })
+			.map(|i| self.delegations[i].amount)
+			.map_err(|_| ())
 	}
 
 	/// Returns Ok(delegated_amount) if successful, `Err` if delegation was
 	/// not found.
 	pub fn inc_delegation(&mut self, collator: AccountId, more: Balance) -> Result<Balance, ()> {
-		if self.owner == Some(collator) {
-			self.amount = self.amount.saturating_add(more);
-			Ok(self.amount)
-		} else {
-			Err(())
-		}
+		let i = self
+			.delegations
+			.linear_search(&Stake { owner: collator, amount: Balance::zero() })
+			.map_err(|_| ())?;
+		let amount = self.delegations[i].amount.saturating_add(more);
+		self.delegations.mutate(|vec| vec[i].amount = amount);
+		self.total = self.total.saturating_add(more);
+		Ok(amount)
 	}
 
 	/// Returns Ok(Some(delegated_amount)) if successful, `Err` if delegation
@@ -259,14 +337,15 @@ where
 		collator: AccountId,
 		less: Balance,
 	) -> Result<Option<Balance>, ()> {
-		if self.owner == Some(collator) {
-			Ok(self.amount.checked_sub(&less).map(|new| {
-				self.amount = new;
-				self.amount
-			}))
-		} else {
-			Err(())
-		}
+		let i = self
+			.delegations
+			.linear_search(&Stake { owner: collator, amount: Balance::zero() })
+			.map_err(|_| ())?;
+		Ok(self.delegations[i].amount.checked_sub(&less).map(|amount| {
+			self.delegations.mutate(|vec| vec[i].amount = amount);
+			self.total = self.total.saturating_sub(less);
+			amount
+		}))
 	}
 }
 
@@ -323,6 +402,88 @@ pub struct TotalStake<Balance: Default> {
 	pub delegators: Balance,
 }
 
+/// A scheduled change of the commission of a collator candidate.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CommissionChange {
+	/// The commission which will be applied.
+	pub commission: Perquintill,
+	/// The first round in which the change can be executed.
+	pub effective_round: SessionIndex,
+}
+
+/// The penalty for collators which missed too many rounds in a row.
+#[derive(Clone, Copy, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum LivenessPenalty {
+	/// Remove the collator from the top candidates until they go online
+	/// again.
+	Idle,
+	/// Slash the given share of the collator's own stake to the treasury.
+	Slash(Perquintill),
+}
+
+/// The destination to which the rewards of a staker are paid.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum RewardDestination<AccountId> {
+	/// Pay the rewards into the staker's own account.
+	Own,
+	/// Pay the rewards into the given account.
+	Account(AccountId),
+	/// Restake all newly accumulated rewards as if auto-compounding with a
+	/// share of 100%. Rewards which cannot be restaked are paid into the
+	/// staker's own account.
+	Staked,
+	/// Donate the rewards to the treasury, e.g., leave them in the treasury.
+	Treasury,
+}
+
+impl<AccountId> Default for RewardDestination<AccountId> {
+	fn default() -> Self {
+		Self::Own
+	}
+}
+
+/// The commitment of a delegator to keep their stake locked for a longer
+/// period in exchange for a bonus on their rewards.
+#[derive(Clone, Copy, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct StakeLock<BlockNumber> {
+	/// The block until which unstaked funds of the delegator stay locked.
+	pub until: BlockNumber,
+	/// The bonus on the rewards of the delegator while the lock is active.
+	pub bonus: Perquintill,
+}
+
+/// The liveness requirements of selected collators.
+#[derive(Clone, Copy, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct LivenessConfig {
+	/// The share of the expected number of blocks per round which a
+	/// collator has to author to not miss the round.
+	pub min_authored: Perquintill,
+	/// The number of rounds in a row a collator can miss before the penalty
+	/// is applied.
+	pub max_missed_rounds: u32,
+	/// The penalty for collators which missed too many rounds.
+	pub penalty: LivenessPenalty,
+}
+
+/// The staking parameters which can be changed by governance.
+#[derive(Clone, Copy, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct StakingConfig<Balance, BlockNumber> {
+	/// Minimum stake required for any account to be elected as collator for
+	/// a round.
+	pub min_collator_stake: Balance,
+	/// Minimum stake required for any account to become a delegator.
+	pub min_delegator_stake: Balance,
+	/// Maximum number of delegators a single collator can have.
+	pub max_delegators_per_collator: u32,
+	/// Number of blocks for which unstaked balance will still be locked.
+	pub stake_duration: BlockNumber,
+	/// Number of rounds a collator has to stay active after submitting a
+	/// request to leave the set of collator candidates.
+	pub exit_queue_delay: u32,
+	/// The rate in percent for the network rewards.
+	pub network_reward_rate: Perquintill,
+}
+
 /// The number of delegations a delegator has done within the last session in
 /// which they delegated.
 #[derive(Default, Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
@@ -337,5 +498,12 @@ pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 pub type CandidateOf<T, S> = Candidate<AccountIdOf<T>, BalanceOf<T>, S>;
 pub type StakeOf<T> = Stake<AccountIdOf<T>, BalanceOf<T>>;
+pub type StakingConfigOf<T> = StakingConfig<BalanceOf<T>, BlockNumberFor<T>>;
+pub type RewardDestinationOf<T> = RewardDestination<AccountIdOf<T>>;
+pub type StakeLockOf<T> = StakeLock<BlockNumberFor<T>>;
+pub type ExtraRewardCurrencyIdOf<T> =
+	<<T as Config>::ExtraRewardCurrency as MultiCurrency<AccountIdOf<T>>>::CurrencyId;
+pub type CollatorSnapshotOf<T> = CollatorSnapshot<AccountIdOf<T>, BalanceOf<T>>;
+pub type DisplacedDelegationOf<T> = DisplacedDelegation<AccountIdOf<T>, BalanceOf<T>>;
 pub type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
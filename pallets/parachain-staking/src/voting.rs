@@ -0,0 +1,204 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2023 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Currency adapter which lets governance pallets count staked balances.
+
+use frame_support::traits::{
+	BalanceStatus, Currency, ExistenceRequirement, LockIdentifier, LockableCurrency,
+	ReservableCurrency, SignedImbalance, WithdrawReasons,
+};
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::marker::PhantomData;
+
+use crate::traits::VotingPowerProvider;
+
+/// Wraps the currency `C` and counts the voting power provided by `P` as free
+/// balance, e.g., for the `Currency` of `pallet_democracy`.
+///
+/// The voting power is not added on top of the free balance but the larger
+/// of both is used. Hence, the vote lock overlays the staking lock and staked
+/// funds are not counted twice. All other calls are passed through to `C`.
+pub struct StakeVotingCurrency<C, P>(PhantomData<(C, P)>);
+
+impl<AccountId, C, P> Currency<AccountId> for StakeVotingCurrency<C, P>
+where
+	C: Currency<AccountId>,
+	P: VotingPowerProvider<AccountId, C::Balance>,
+{
+	type Balance = C::Balance;
+	type PositiveImbalance = C::PositiveImbalance;
+	type NegativeImbalance = C::NegativeImbalance;
+
+	fn total_balance(who: &AccountId) -> Self::Balance {
+		C::total_balance(who)
+	}
+
+	fn can_slash(who: &AccountId, value: Self::Balance) -> bool {
+		C::can_slash(who, value)
+	}
+
+	fn total_issuance() -> Self::Balance {
+		C::total_issuance()
+	}
+
+	fn active_issuance() -> Self::Balance {
+		C::active_issuance()
+	}
+
+	fn deactivate(amount: Self::Balance) {
+		C::deactivate(amount)
+	}
+
+	fn reactivate(amount: Self::Balance) {
+		C::reactivate(amount)
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		C::minimum_balance()
+	}
+
+	fn burn(amount: Self::Balance) -> Self::PositiveImbalance {
+		C::burn(amount)
+	}
+
+	fn issue(amount: Self::Balance) -> Self::NegativeImbalance {
+		C::issue(amount)
+	}
+
+	fn free_balance(who: &AccountId) -> Self::Balance {
+		C::free_balance(who).max(P::voting_power(who))
+	}
+
+	fn ensure_can_withdraw(
+		who: &AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+		new_balance: Self::Balance,
+	) -> DispatchResult {
+		C::ensure_can_withdraw(who, amount, reasons, new_balance)
+	}
+
+	fn transfer(
+		source: &AccountId,
+		dest: &AccountId,
+		value: Self::Balance,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		C::transfer(source, dest, value, existence_requirement)
+	}
+
+	fn slash(who: &AccountId, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
+		C::slash(who, value)
+	}
+
+	fn deposit_into_existing(
+		who: &AccountId,
+		value: Self::Balance,
+	) -> Result<Self::PositiveImbalance, DispatchError> {
+		C::deposit_into_existing(who, value)
+	}
+
+	fn deposit_creating(who: &AccountId, value: Self::Balance) -> Self::PositiveImbalance {
+		C::deposit_creating(who, value)
+	}
+
+	fn withdraw(
+		who: &AccountId,
+		value: Self::Balance,
+		reasons: WithdrawReasons,
+		liveness: ExistenceRequirement,
+	) -> Result<Self::NegativeImbalance, DispatchError> {
+		C::withdraw(who, value, reasons, liveness)
+	}
+
+	fn make_free_balance_be(
+		who: &AccountId,
+		balance: Self::Balance,
+	) -> SignedImbalance<Self::Balance, Self::PositiveImbalance> {
+		C::make_free_balance_be(who, balance)
+	}
+}
+
+impl<AccountId, C, P> ReservableCurrency<AccountId> for StakeVotingCurrency<C, P>
+where
+	C: ReservableCurrency<AccountId>,
+	P: VotingPowerProvider<AccountId, C::Balance>,
+{
+	fn can_reserve(who: &AccountId, value: Self::Balance) -> bool {
+		C::can_reserve(who, value)
+	}
+
+	fn slash_reserved(
+		who: &AccountId,
+		value: Self::Balance,
+	) -> (Self::NegativeImbalance, Self::Balance) {
+		C::slash_reserved(who, value)
+	}
+
+	fn reserved_balance(who: &AccountId) -> Self::Balance {
+		C::reserved_balance(who)
+	}
+
+	fn reserve(who: &AccountId, value: Self::Balance) -> DispatchResult {
+		C::reserve(who, value)
+	}
+
+	fn unreserve(who: &AccountId, value: Self::Balance) -> Self::Balance {
+		C::unreserve(who, value)
+	}
+
+	fn repatriate_reserved(
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> Result<Self::Balance, DispatchError> {
+		C::repatriate_reserved(slashed, beneficiary, value, status)
+	}
+}
+
+impl<AccountId, C, P> LockableCurrency<AccountId> for StakeVotingCurrency<C, P>
+where
+	C: LockableCurrency<AccountId>,
+	P: VotingPowerProvider<AccountId, C::Balance>,
+{
+	type Moment = C::Moment;
+	type MaxLocks = C::MaxLocks;
+
+	fn set_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+	) {
+		C::set_lock(id, who, amount, reasons)
+	}
+
+	fn extend_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+	) {
+		C::extend_lock(id, who, amount, reasons)
+	}
+
+	fn remove_lock(id: LockIdentifier, who: &AccountId) {
+		C::remove_lock(id, who)
+	}
+}
@@ -0,0 +1,184 @@
+//! Runtime API definition for the treasury buyout extension.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, FixedU128};
+use sp_std::{fmt::Debug, vec::Vec};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Type of amount
+#[derive(Copy, Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr"
+	))
+)]
+pub enum Amount<Balance> {
+	/// Amount of native asset user get for buyout
+	Buyout(#[cfg_attr(feature = "std", serde(with = "balance_as_string"))] Balance),
+	/// Amount of exchange asset user give for buyout
+	Exchange(#[cfg_attr(feature = "std", serde(with = "balance_as_string"))] Balance),
+}
+
+/// The amounts of a buyout at the current prices.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr"
+	))
+)]
+pub struct BuyoutQuote<Balance> {
+	/// Amount of native asset the caller receives.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub buyout_amount: Balance,
+	/// Amount of exchange asset the caller pays, including the fee.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub exchange_amount: Balance,
+	/// Part of `exchange_amount` which is charged as buyout fee.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub fee: Balance,
+}
+
+/// The amount of native asset an account can still buy out in the current
+/// buyout period.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr"
+	))
+)]
+pub struct BuyoutAllowance<Balance> {
+	/// Amount of native asset which can still be bought out.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub remaining: Balance,
+	/// Block number at which the current buyout period ends and the
+	/// allowance is reset.
+	pub period_end: u32,
+}
+
+/// A buyout recorded in the buyout history.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "AccountId: Serialize, CurrencyId: Serialize, Balance: std::fmt::Display",
+		deserialize = "AccountId: Deserialize<'de>, CurrencyId: Deserialize<'de>, Balance: std::str::FromStr"
+	))
+)]
+pub struct BuyoutRecord<AccountId, CurrencyId, Balance> {
+	/// Account which bought out the native asset.
+	pub who: AccountId,
+	/// Asset which was exchanged for the native asset.
+	pub asset: CurrencyId,
+	/// Amount of native asset bought out.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub buyout_amount: Balance,
+	/// Amount of exchange asset paid, including the fee.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub exchange_amount: Balance,
+	/// Part of `exchange_amount` which was charged as buyout fee.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub fee: Balance,
+	/// Oracle price of the native asset used for the buyout.
+	pub basic_asset_price: FixedU128,
+	/// Oracle price of the exchange asset used for the buyout.
+	pub exchange_asset_price: FixedU128,
+	/// Block number of the buyout.
+	pub block: u32,
+}
+
+/// The buyouts with an asset aggregated over a buyout period.
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr"
+	))
+)]
+pub struct AssetBuyoutSummary<Balance> {
+	/// Number of buyouts.
+	pub buyouts: u32,
+	/// Amount of native asset bought out.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub buyout_amount: Balance,
+	/// Amount of the asset paid, including the fees.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub exchange_amount: Balance,
+	/// Part of `exchange_amount` which was charged as buyout fee.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub fees: Balance,
+}
+
+/// The buyout history of a buyout period.
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "AccountId: Serialize, CurrencyId: Serialize, Balance: std::fmt::Display",
+		deserialize = "AccountId: Deserialize<'de>, CurrencyId: Deserialize<'de>, Balance: std::str::FromStr"
+	))
+)]
+pub struct BuyoutPeriodReport<AccountId, CurrencyId, Balance> {
+	/// Block number at which the buyout period started.
+	pub period_start: u32,
+	/// Number of buyouts in the period, including the ones no longer in `records`.
+	pub buyouts: u32,
+	/// Fees of all buyouts in the period, denominated in the native asset.
+	#[cfg_attr(feature = "std", serde(with = "balance_as_string"))]
+	pub total_fees: Balance,
+	/// Buyouts of the period aggregated per asset.
+	pub assets: Vec<(CurrencyId, AssetBuyoutSummary<Balance>)>,
+	/// The most recent buyouts of the period, oldest first.
+	pub records: Vec<BuyoutRecord<AccountId, CurrencyId, Balance>>,
+}
+
+/// Balances are serialized as strings because they might exceed the range of
+/// JSON numbers.
+#[cfg(feature = "std")]
+mod balance_as_string {
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer, T: std::fmt::Display>(
+		value: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&value.to_string())
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		s.parse::<T>().map_err(|_| D::Error::custom("Parse from string failed"))
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait TreasuryBuyoutApi<AccountId, CurrencyId, Balance>
+	where
+		AccountId: Codec,
+		CurrencyId: Codec,
+		Balance: Codec
+	{
+		fn get_buyout_quote(asset: CurrencyId, amount: Amount<Balance>) -> Result<BuyoutQuote<Balance>, DispatchError>;
+		fn get_buyout_allowance(account: AccountId) -> Option<BuyoutAllowance<Balance>>;
+		fn get_allowed_currencies() -> Vec<CurrencyId>;
+		fn get_buyout_history() -> Vec<BuyoutPeriodReport<AccountId, CurrencyId, Balance>>;
+	}
+}
@@ -0,0 +1,130 @@
+//! RPC interface for the treasury buyout extension.
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr, MaybeSerializeDeserialize};
+use std::sync::Arc;
+use treasury_buyout_extension_rpc_runtime_api::{
+	Amount, BuyoutAllowance, BuyoutPeriodReport, BuyoutQuote,
+	TreasuryBuyoutApi as TreasuryBuyoutRuntimeApi,
+};
+
+#[rpc(client, server)]
+pub trait TreasuryBuyoutApi<BlockHash, AccountId, CurrencyId, Balance>
+where
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+	AccountId: Codec + MaybeSerializeDeserialize,
+	CurrencyId: Codec + MaybeSerializeDeserialize,
+{
+	#[method(name = "treasuryBuyout_getBuyoutQuote")]
+	fn get_buyout_quote(
+		&self,
+		asset: CurrencyId,
+		amount: Amount<Balance>,
+		at: Option<BlockHash>,
+	) -> RpcResult<BuyoutQuote<Balance>>;
+
+	#[method(name = "treasuryBuyout_getBuyoutAllowance")]
+	fn get_buyout_allowance(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BuyoutAllowance<Balance>>>;
+
+	#[method(name = "treasuryBuyout_getAllowedCurrencies")]
+	fn get_allowed_currencies(&self, at: Option<BlockHash>) -> RpcResult<Vec<CurrencyId>>;
+
+	#[method(name = "treasuryBuyout_getBuyoutHistory")]
+	fn get_buyout_history(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<BuyoutPeriodReport<AccountId, CurrencyId, Balance>>>;
+}
+
+fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+		ErrorCode::InternalError.code(),
+		message.to_string(),
+		None::<()>,
+	)))
+}
+
+/// A struct that implements the [`TreasuryBuyoutApiServer`].
+pub struct TreasuryBuyout<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> TreasuryBuyout<C, B> {
+	/// Create new `TreasuryBuyout` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		TreasuryBuyout { client, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId, CurrencyId, Balance>
+	TreasuryBuyoutApiServer<<Block as BlockT>::Hash, AccountId, CurrencyId, Balance>
+	for TreasuryBuyout<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: TreasuryBuyoutRuntimeApi<Block, AccountId, CurrencyId, Balance>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+	AccountId: Codec + MaybeSerializeDeserialize,
+	CurrencyId: Codec + MaybeSerializeDeserialize,
+{
+	fn get_buyout_quote(
+		&self,
+		asset: CurrencyId,
+		amount: Amount<Balance>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<BuyoutQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_buyout_quote(at, asset, amount)
+			.map_err(|_e| internal_err("Unable to get buyout quote"))?
+			.map_err(|e| internal_err(format!("Unable to get buyout quote: {:?}", e)))
+	}
+
+	fn get_buyout_allowance(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<BuyoutAllowance<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_buyout_allowance(at, account)
+			.map_err(|_e| internal_err("Unable to get buyout allowance"))
+	}
+
+	fn get_allowed_currencies(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CurrencyId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_allowed_currencies(at)
+			.map_err(|_e| internal_err("Unable to get allowed currencies"))
+	}
+
+	fn get_buyout_history(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<BuyoutPeriodReport<AccountId, CurrencyId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_buyout_history(at)
+			.map_err(|_e| internal_err("Unable to get buyout history"))
+	}
+}
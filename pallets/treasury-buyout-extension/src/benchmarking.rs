@@ -2,7 +2,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::{Pallet as TreasuryBuyoutExtension, *};
-use crate::types::{AccountIdOf, BalanceOf, CurrencyIdOf};
+use crate::types::{AccountIdOf, AssetBuyoutSummaryOf, BalanceOf, CurrencyIdOf};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
@@ -40,10 +40,22 @@ benchmarks! {
 		// Add token to allowed currencies for buyout
 		AllowedCurrencies::<T>::insert(token_currency_id, ());
 		BuyoutLimit::<T>::put(limit);
+		AssetBuyoutLimits::<T>::insert(token_currency_id, limit);
+		GlobalBuyoutLimit::<T>::put(limit);
+		BuyoutFeeCurve::<T>::put(FeeCurve {
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(10),
+			max_fee_share: Permill::from_percent(10),
+		});
 		// Set previous buyout limit to 0
 		Buyouts::<T>::insert(caller_account.clone(), (BalanceOf::<T>::default(), 0));
+		// The buyout history of the current period already holds the maximum number of records
+		let period_start = TreasuryBuyoutExtension::<T>::current_period_start();
+		let slot = period_start.checked_div(T::BuyoutPeriod::get()).unwrap_or_default() % T::MaxBuyoutHistoryPeriods::get().max(1);
+		BuyoutHistoryPeriods::<T>::insert(slot, (period_start, T::MaxBuyoutRecordsPerPeriod::get(), BalanceOf::<T>::default()));
+		BuyoutHistorySummaries::<T>::insert(slot, token_currency_id, (period_start, AssetBuyoutSummaryOf::<T>::default()));
 
-	}: buyout(origin, token_currency_id, Amount::Buyout(100_000_000_000_000u128.try_into().unwrap_or_default()))
+	}: buyout(origin, token_currency_id, Amount::Buyout(100_000_000_000_000u128.try_into().unwrap_or_default()), None)
 	verify{
 		assert_eq!(
 			<orml_currencies::Pallet<T> as MultiCurrency::<AccountIdOf<T>>>::free_balance(native_currency_id, &caller_account),
@@ -65,6 +77,70 @@ benchmarks! {
 		// will iterate over it the same amount of times.
 		let allowed_currencies = vec![token_currency_id; n as usize];
 	}: update_allowed_assets(RawOrigin::Root, allowed_currencies)
+
+	update_asset_buyout_limit {
+		let token_currency_id = T::RelayChainCurrencyId::get();
+	}: update_asset_buyout_limit(RawOrigin::Root, token_currency_id, Some(100_000_000_000_000u128.try_into().unwrap_or_default()))
+
+	update_global_buyout_limit {
+	}: update_global_buyout_limit(RawOrigin::Root, Some(100_000_000_000_000u128.try_into().unwrap_or_default()))
+
+	update_buyout_fee_curve {
+		let curve = FeeCurve {
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(10),
+			max_fee_share: Permill::from_percent(10),
+		};
+	}: update_buyout_fee_curve(RawOrigin::Root, Some(curve))
+
+	refresh_reference_price {
+		let token_currency_id = T::RelayChainCurrencyId::get();
+	}: refresh_reference_price(RawOrigin::Root, token_currency_id)
+
+	sell {
+		let token_currency_id = T::RelayChainCurrencyId::get();
+		let native_currency_id = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+		let caller_account = account("Caller", 0, 0);
+		let treasury_account = <T as pallet::Config>::TreasuryAccount::get();
+		let amount: BalanceOf<T> = 1_000_000_000_000_000u128.try_into().unwrap_or_default();
+		// Mint native token to caller and token to treasury account
+		assert_ok!(<<T as pallet::Config>::Currency as MultiCurrency::<AccountIdOf<T>>>::deposit(
+			native_currency_id,
+			&caller_account,
+			amount
+		));
+		assert_ok!(<<T as pallet::Config>::Currency as MultiCurrency::<AccountIdOf<T>>>::deposit(
+			token_currency_id,
+			&treasury_account,
+			amount
+		));
+		let origin = RawOrigin::Signed(caller_account.clone());
+		let limit: BalanceOf<T> = 100_000_000_000_000u128.try_into().unwrap_or_default();
+		// Add token to allowed currencies for sell
+		AllowedSellCurrencies::<T>::insert(token_currency_id, ());
+		SellLimit::<T>::put(limit);
+		GlobalSellLimit::<T>::put(limit);
+
+	}: sell(origin, token_currency_id, Amount::Buyout(limit), None)
+	verify{
+		assert_eq!(
+			<orml_currencies::Pallet<T> as MultiCurrency::<AccountIdOf<T>>>::free_balance(native_currency_id, &caller_account),
+			amount - limit
+		);
+	}
+
+	update_sell_limit {
+	}: update_sell_limit(RawOrigin::Root, Some(100_000_000_000_000u128.try_into().unwrap_or_default()))
+
+	update_global_sell_limit {
+	}: update_global_sell_limit(RawOrigin::Root, Some(100_000_000_000_000u128.try_into().unwrap_or_default()))
+
+	update_allowed_sell_assets {
+		let n in 1..T::MaxAllowedBuyoutCurrencies::get();
+
+		let token_currency_id = T::RelayChainCurrencyId::get();
+		let allowed_currencies = vec![token_currency_id; n as usize];
+	}: update_allowed_sell_assets(RawOrigin::Root, allowed_currencies)
 }
 
 impl_benchmark_test_suite!(
@@ -41,6 +41,14 @@ pub trait WeightInfo {
 	fn buyout() -> Weight;
 	fn update_buyout_limit() -> Weight;
 	fn update_allowed_assets(n: u32, ) -> Weight;
+	fn update_asset_buyout_limit() -> Weight;
+	fn update_global_buyout_limit() -> Weight;
+	fn sell() -> Weight;
+	fn update_sell_limit() -> Weight;
+	fn update_global_sell_limit() -> Weight;
+	fn update_allowed_sell_assets(n: u32, ) -> Weight;
+	fn update_buyout_fee_curve() -> Weight;
+	fn refresh_reference_price() -> Weight;
 }
 
 /// Weights for treasury_buyout_extension using the Substrate node and recommended hardware.
@@ -62,14 +70,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Oracle OracleKeys (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension AssetBuyoutLimits (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension AssetBuyoutLimits (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension AssetBuyouts (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension AssetBuyouts (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalBuyoutLimit (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension GlobalBuyoutLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalBuyouts (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalBuyouts (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension LastAcceptedPrices (r:2 w:2)
+	/// Proof: TreasuryBuyoutExtension LastAcceptedPrices (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutFeeCurve (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension BuyoutFeeCurve (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutHistoryPeriods (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutHistoryPeriods (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutHistory (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutHistory (max_values: None, max_size: Some(186), added: 2661, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutHistorySummaries (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutHistorySummaries (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	fn buyout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `863`
 		//  Estimated: `26038`
 		// Minimum execution time: 81_000_000 picoseconds.
 		Weight::from_parts(83_000_000, 26038)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
 	/// Storage: TreasuryBuyoutExtension BuyoutLimit (r:0 w:1)
 	/// Proof: TreasuryBuyoutExtension BuyoutLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
@@ -95,6 +121,77 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: TreasuryBuyoutExtension AssetBuyoutLimits (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension AssetBuyoutLimits (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	fn update_asset_buyout_limit() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension GlobalBuyoutLimit (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalBuyoutLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn update_global_buyout_limit() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension AllowedSellCurrencies (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension AllowedSellCurrencies (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension SellLimit (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension SellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension Sells (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension Sells (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalSellLimit (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension GlobalSellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalSells (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalSells (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension LastAcceptedPrices (r:2 w:2)
+	/// Proof: TreasuryBuyoutExtension LastAcceptedPrices (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	fn sell() -> Weight {
+		Weight::from_parts(85_000_000, 8240)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension SellLimit (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension SellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn update_sell_limit() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension GlobalSellLimit (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalSellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn update_global_sell_limit() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension AllowedSellCurrencies (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension AllowedSellCurrencies (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 20]`.
+	fn update_allowed_sell_assets(n: u32, ) -> Weight {
+		Weight::from_parts(15_935_593, 3527)
+			.saturating_add(Weight::from_parts(1_123_793, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension BuyoutFeeCurve (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutFeeCurve (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn update_buyout_fee_curve() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension LastAcceptedPrices (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension LastAcceptedPrices (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	fn refresh_reference_price() -> Weight {
+		Weight::from_parts(20_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -115,14 +212,32 @@ impl WeightInfo for () {
 	/// Proof Skipped: Oracle OracleKeys (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension AssetBuyoutLimits (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension AssetBuyoutLimits (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension AssetBuyouts (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension AssetBuyouts (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalBuyoutLimit (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension GlobalBuyoutLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalBuyouts (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalBuyouts (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension LastAcceptedPrices (r:2 w:2)
+	/// Proof: TreasuryBuyoutExtension LastAcceptedPrices (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutFeeCurve (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension BuyoutFeeCurve (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutHistoryPeriods (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutHistoryPeriods (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutHistory (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutHistory (max_values: None, max_size: Some(186), added: 2661, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension BuyoutHistorySummaries (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutHistorySummaries (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	fn buyout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `863`
 		//  Estimated: `26038`
 		// Minimum execution time: 81_000_000 picoseconds.
 		Weight::from_parts(83_000_000, 26038)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
 	}
 	/// Storage: TreasuryBuyoutExtension BuyoutLimit (r:0 w:1)
 	/// Proof: TreasuryBuyoutExtension BuyoutLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
@@ -148,4 +263,75 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: TreasuryBuyoutExtension AssetBuyoutLimits (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension AssetBuyoutLimits (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	fn update_asset_buyout_limit() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension GlobalBuyoutLimit (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalBuyoutLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn update_global_buyout_limit() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension AllowedSellCurrencies (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension AllowedSellCurrencies (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension SellLimit (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension SellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension Sells (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension Sells (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalSellLimit (r:1 w:0)
+	/// Proof: TreasuryBuyoutExtension GlobalSellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension GlobalSells (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalSells (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension LastAcceptedPrices (r:2 w:2)
+	/// Proof: TreasuryBuyoutExtension LastAcceptedPrices (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	fn sell() -> Weight {
+		Weight::from_parts(85_000_000, 8240)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension SellLimit (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension SellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn update_sell_limit() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension GlobalSellLimit (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension GlobalSellLimit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn update_global_sell_limit() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension AllowedSellCurrencies (r:1 w:1)
+	/// Proof: TreasuryBuyoutExtension AllowedSellCurrencies (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 20]`.
+	fn update_allowed_sell_assets(n: u32, ) -> Weight {
+		Weight::from_parts(15_935_593, 3527)
+			.saturating_add(Weight::from_parts(1_123_793, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TreasuryBuyoutExtension BuyoutFeeCurve (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension BuyoutFeeCurve (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn update_buyout_fee_curve() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TreasuryBuyoutExtension LastAcceptedPrices (r:0 w:1)
+	/// Proof: TreasuryBuyoutExtension LastAcceptedPrices (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	fn refresh_reference_price() -> Weight {
+		Weight::from_parts(20_000_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
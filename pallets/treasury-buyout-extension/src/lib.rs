@@ -14,7 +14,13 @@ mod tests;
 
 mod types;
 
-use crate::types::{AccountIdOf, Amount, BalanceOf, CurrencyIdOf};
+use crate::types::{
+	AccountIdOf, Amount, AssetBuyoutSummaryOf, BalanceOf, BuyoutPeriodReportOf, BuyoutRecordOf,
+	CurrencyIdOf,
+};
+pub use crate::types::{
+	AssetBuyoutSummary, BuyoutAllowance, BuyoutPeriodReport, BuyoutQuote, BuyoutRecord, FeeCurve,
+};
 
 pub use crate::default_weights::WeightInfo;
 use codec::{Decode, Encode};
@@ -27,13 +33,13 @@ use frame_support::{
 };
 use orml_traits::MultiCurrency;
 pub use pallet::*;
-use sp_arithmetic::traits::{CheckedAdd, CheckedDiv, CheckedMul, Saturating};
+use sp_arithmetic::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating};
 use sp_runtime::{
 	traits::{DispatchInfoOf, One, SignedExtension, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	ArithmeticError, DispatchError, FixedPointNumber, FixedU128,
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill,
 };
 use sp_std::{fmt::Debug, marker::PhantomData, vec, vec::Vec};
 use spacewalk_primitives::DecimalsLookup;
@@ -41,7 +47,7 @@ use spacewalk_primitives::DecimalsLookup;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{pallet_prelude::*, sp_runtime::Permill};
+	use frame_support::pallet_prelude::*;
 	use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 
 	#[pallet::config]
@@ -61,10 +67,26 @@ pub mod pallet {
 		#[pallet::constant]
 		type BuyoutPeriod: Get<u32>;
 
-		/// Fee from the native asset buyouts
+		/// Fee from the native asset buyouts, applies as long as no `BuyoutFeeCurve` is set
 		#[pallet::constant]
 		type SellFee: Get<Permill>;
 
+		/// Fee from the native asset sales to the treasury
+		#[pallet::constant]
+		type BuyFee: Get<Permill>;
+
+		/// Maximum age of an oracle price in seconds, older prices are rejected by the `PriceGetter` as stale
+		#[pallet::constant]
+		type MaxPriceAge: Get<u64>;
+
+		/// Maximum deviation of a price from the last price accepted for a buyout or sell
+		#[pallet::constant]
+		type MaxPriceDeviation: Get<Permill>;
+
+		/// Maximum age of the last accepted price in blocks, older prices are not used as reference for the price deviation check
+		#[pallet::constant]
+		type MaxReferencePriceAge: Get<u32>;
+
 		/// Used for fetching prices of currencies from oracle
 		type PriceGetter: PriceGetter<CurrencyIdOf<Self>>;
 
@@ -75,10 +97,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinAmountToBuyout: Get<BalanceOf<Self>>;
 
-		/// Maximum number of allowed currencies for buyout
+		/// Min amount of native token to sell
+		#[pallet::constant]
+		type MinAmountToSell: Get<BalanceOf<Self>>;
+
+		/// Maximum number of allowed currencies for buyout and for sell
 		#[pallet::constant]
 		type MaxAllowedBuyoutCurrencies: Get<u32>;
 
+		/// Maximum number of buyout periods kept in the buyout history
+		#[pallet::constant]
+		type MaxBuyoutHistoryPeriods: Get<u32>;
+
+		/// Maximum number of buyouts recorded per buyout period, the oldest buyouts of the period are overwritten first
+		#[pallet::constant]
+		type MaxBuyoutRecordsPerPeriod: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -116,6 +150,29 @@ pub mod pallet {
 		ExchangeFailure,
 		/// Decimals conversion error
 		DecimalsConversionError,
+		/// The buyout would pay more or receive less than the given slippage limit
+		SlippageLimitExceeded,
+		/// Buyout limit of the asset exceeded for the current period
+		AssetBuyoutLimitExceeded,
+		/// Buyout limit of the treasury exceeded for the current period
+		GlobalBuyoutLimitExceeded,
+		/// The price of one of transacted currencies is older than `MaxPriceAge`
+		StalePrice,
+		/// The price of one of transacted currencies deviates more than `MaxPriceDeviation` from the last accepted price.
+		/// Root can refresh the reference price via `refresh_reference_price`
+		PriceDeviationTooHigh,
+		/// Attempt to sell native token for an asset which is not allowed for sell
+		WrongAssetToSell,
+		/// Sell limit exceeded for the current period
+		SellLimitExceeded,
+		/// Sell limit of the treasury exceeded for the current period
+		GlobalSellLimitExceeded,
+		/// Less than minimum amount allowed for sell
+		LessThanMinSellAmount,
+		/// Attempt to use treasury account for sell
+		SellWithTreasuryAccount,
+		/// Fee curve with `min_fee` greater than `max_fee`
+		InvalidFeeCurve,
 	}
 
 	#[pallet::event]
@@ -133,6 +190,35 @@ pub mod pallet {
 
 		/// Updated allowed assets for buyout event
 		AllowedAssetsForBuyoutUpdated { allowed_assets: Vec<CurrencyIdOf<T>> },
+
+		/// Buyout limit of an asset updated event
+		AssetBuyoutLimitUpdated { asset: CurrencyIdOf<T>, limit: Option<BalanceOf<T>> },
+
+		/// Buyout limit of the treasury updated event
+		GlobalBuyoutLimitUpdated { limit: Option<BalanceOf<T>> },
+
+		/// Sell event
+		Sell {
+			who: AccountIdOf<T>,
+			sell_amount: BalanceOf<T>,
+			asset: CurrencyIdOf<T>,
+			exchange_amount: BalanceOf<T>,
+		},
+
+		/// Sell limit updated event
+		SellLimitUpdated { limit: Option<BalanceOf<T>> },
+
+		/// Sell limit of the treasury updated event
+		GlobalSellLimitUpdated { limit: Option<BalanceOf<T>> },
+
+		/// Updated allowed assets for sell event
+		AllowedAssetsForSellUpdated { allowed_assets: Vec<CurrencyIdOf<T>> },
+
+		/// Buyout fee curve updated event
+		BuyoutFeeCurveUpdated { curve: Option<FeeCurve> },
+
+		/// Reference price for the price deviation check refreshed event
+		ReferencePriceRefreshed { asset: CurrencyIdOf<T>, price: FixedU128 },
 	}
 
 	/// Stores buyout limit amount user could buy for a period of `BuyoutPeriod` blocks.
@@ -146,19 +232,106 @@ pub mod pallet {
 	pub type Buyouts<T: Config> =
 		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (BalanceOf<T>, u32), ValueQuery>;
 
+	/// Stores buyout limit amount of native token a user could buy with a given asset for a period of `BuyoutPeriod` blocks.
+	/// Applies on top of `BuyoutLimit`. Assets without an entry are not limited
+	#[pallet::storage]
+	pub type AssetBuyoutLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyIdOf<T>, BalanceOf<T>, OptionQuery>;
+
+	/// Stores amount of buyouts per asset (amount, block number of last buyout)
+	#[pallet::storage]
+	pub type AssetBuyouts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		CurrencyIdOf<T>,
+		(BalanceOf<T>, u32),
+		ValueQuery,
+	>;
+
+	/// Stores buyout limit amount of native token the treasury sells to all users for a period of `BuyoutPeriod` blocks.
+	/// When `None` - the total buyouts are not limited
+	#[pallet::storage]
+	pub type GlobalBuyoutLimit<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+	/// Stores amount of buyouts of all users (amount, block number of last buyout)
+	#[pallet::storage]
+	pub type GlobalBuyouts<T: Config> = StorageValue<_, (BalanceOf<T>, u32), ValueQuery>;
+
+	/// Stores the parameters of the buyout fee curve.
+	/// When `None` - the fixed `SellFee` applies
+	#[pallet::storage]
+	pub type BuyoutFeeCurve<T: Config> = StorageValue<_, FeeCurve, OptionQuery>;
+
+	/// Stores sell limit amount of native token a user could sell to the treasury for a period of `BuyoutPeriod` blocks.
+	/// When `None` - sells are not limited
+	#[pallet::storage]
+	pub type SellLimit<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+	/// Stores amount of sells (amount, block number of last sell)
+	#[pallet::storage]
+	pub type Sells<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (BalanceOf<T>, u32), ValueQuery>;
+
+	/// Stores sell limit amount of native token the treasury buys from all users for a period of `BuyoutPeriod` blocks.
+	/// When `None` - the total sells are not limited
+	#[pallet::storage]
+	pub type GlobalSellLimit<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+	/// Stores amount of sells of all users (amount, block number of last sell)
+	#[pallet::storage]
+	pub type GlobalSells<T: Config> = StorageValue<_, (BalanceOf<T>, u32), ValueQuery>;
+
+	/// Stores the buyout periods of the rolling buyout history (start block of the period, number of buyouts, total fees in native token).
+	/// Keyed by the slot of the period, slots of periods older than `MaxBuyoutHistoryPeriods` are reused.
+	/// Only buyouts are recorded, sells to the treasury are excluded from the history
+	#[pallet::storage]
+	pub type BuyoutHistoryPeriods<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (u32, u32, BalanceOf<T>), OptionQuery>;
+
+	/// Stores the most recent `MaxBuyoutRecordsPerPeriod` buyouts of the period in a slot of the rolling buyout history
+	#[pallet::storage]
+	pub type BuyoutHistory<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, u32, BuyoutRecordOf<T>, OptionQuery>;
+
+	/// Stores the buyouts per asset of the period in a slot of the rolling buyout history (start block of the period, aggregated buyouts)
+	#[pallet::storage]
+	pub type BuyoutHistorySummaries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u32,
+		Blake2_128Concat,
+		CurrencyIdOf<T>,
+		(u32, AssetBuyoutSummaryOf<T>),
+		OptionQuery,
+	>;
+
+	/// Stores the last prices accepted for a buyout or sell (price, block number of last buyout or sell).
+	/// Used as reference for the price deviation check as long as they are not older than `MaxReferencePriceAge`
+	#[pallet::storage]
+	pub type LastAcceptedPrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyIdOf<T>, (FixedU128, u32), OptionQuery>;
+
 	/// Stores allowed currencies for buyout
 	#[pallet::storage]
 	pub(super) type AllowedCurrencies<T: Config> =
 		StorageMap<_, Blake2_128Concat, CurrencyIdOf<T>, (), OptionQuery>;
 
+	/// Stores allowed currencies the treasury pays for sold native token
+	#[pallet::storage]
+	pub(super) type AllowedSellCurrencies<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyIdOf<T>, (), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub allowed_currencies: Vec<CurrencyIdOf<T>>,
+		pub allowed_sell_currencies: Vec<CurrencyIdOf<T>>,
 	}
 
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self { allowed_currencies: vec![] }
+			Self { allowed_currencies: vec![], allowed_sell_currencies: vec![] }
 		}
 	}
 
@@ -168,6 +341,9 @@ pub mod pallet {
 			for i in &self.allowed_currencies.clone() {
 				AllowedCurrencies::<T>::insert(i, ());
 			}
+			for i in &self.allowed_sell_currencies {
+				AllowedSellCurrencies::<T>::insert(i, ());
+			}
 		}
 	}
 
@@ -182,6 +358,10 @@ pub mod pallet {
 		/// - `origin`: Caller's origin.
 		/// - `asset`: Exchange asset used for buyout of basic asset.
 		/// - `amount`: Amount of basic asset to buyout or amount of asset to exchange.
+		/// - `slippage_limit`: Protects the caller against price changes until the buyout is executed.
+		/// For `Buyout` this is the maximum amount of the exchange asset the caller is willing to pay,
+		/// for `Exchange` this is the minimum amount of the native token the caller wants to receive.
+		/// If None, the buyout is executed at any price.
 		///
 		/// Emits `Buyout` event when successful.
 		#[pallet::call_index(0)]
@@ -190,9 +370,10 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			asset: CurrencyIdOf<T>,
 			amount: Amount<BalanceOf<T>>,
+			slippage_limit: Option<BalanceOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::do_buyout(who, asset, amount)?;
+			Self::do_buyout(who, asset, amount, slippage_limit)?;
 			Ok(().into())
 		}
 
@@ -272,39 +453,306 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::AllowedAssetsForBuyoutUpdated { allowed_assets });
 			Ok(().into())
 		}
+
+		/// Allows root to update the buyout limit of an asset.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Origin must be root.
+		/// - `asset`: Asset for which the limit applies.
+		/// - `limit`: New buyout limit of the asset per user. If None, then buyouts with this asset are only limited by `BuyoutLimit`.
+		///
+		/// Emits `AssetBuyoutLimitUpdated` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_asset_buyout_limit())]
+		pub fn update_asset_buyout_limit(
+			origin: OriginFor<T>,
+			asset: CurrencyIdOf<T>,
+			limit: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			match limit {
+				Some(limit) => AssetBuyoutLimits::<T>::insert(asset, limit),
+				None => AssetBuyoutLimits::<T>::remove(asset),
+			}
+			Self::deposit_event(Event::<T>::AssetBuyoutLimitUpdated { asset, limit });
+			Ok(().into())
+		}
+
+		/// Allows root to update the buyout limit of the treasury.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Origin must be root.
+		/// - `limit`: New limit of the total buyouts of all users. If None, then the total buyouts are not limited.
+		///
+		/// Emits `GlobalBuyoutLimitUpdated` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_global_buyout_limit())]
+		pub fn update_global_buyout_limit(
+			origin: OriginFor<T>,
+			limit: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			match limit {
+				Some(limit) => GlobalBuyoutLimit::<T>::put(limit),
+				None => GlobalBuyoutLimit::<T>::kill(),
+			}
+			Self::deposit_event(Event::<T>::GlobalBuyoutLimitUpdated { limit });
+			Ok(().into())
+		}
+
+		/// Allows caller to sell a given amount of native token to the treasury.
+		/// When denoting the `amount` as `Buyout` the caller will sell this exact amount of the native token in exchange for a corresponding amount of an allowed asset.
+		/// When denoting the `amount` as `Exchange`, the caller will receive this exact amount of an allowed asset in exchange for a corresponding amount of the native token.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Caller's origin.
+		/// - `asset`: Exchange asset the treasury pays for the basic asset.
+		/// - `amount`: Amount of basic asset to sell or amount of asset to receive.
+		/// - `slippage_limit`: Protects the caller against price changes until the sell is executed.
+		/// For `Buyout` this is the minimum amount of the exchange asset the caller wants to receive,
+		/// for `Exchange` this is the maximum amount of the native token the caller is willing to sell.
+		/// If None, the sell is executed at any price.
+		///
+		/// Sells are limited by `SellLimit` and `GlobalSellLimit` but are not recorded in the buyout history.
+		///
+		/// Emits `Sell` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight((<T as pallet::Config>::WeightInfo::sell(), Pays::No))]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset: CurrencyIdOf<T>,
+			amount: Amount<BalanceOf<T>>,
+			slippage_limit: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_sell(who, asset, amount, slippage_limit)?;
+			Ok(().into())
+		}
+
+		/// Allows root to update the sell limit.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Origin must be root.
+		/// - `limit`: New sell limit. If None, then sells are not limited.
+		///
+		/// Emits `SellLimitUpdated` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_sell_limit())]
+		pub fn update_sell_limit(
+			origin: OriginFor<T>,
+			limit: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			match limit {
+				Some(limit) => SellLimit::<T>::put(limit),
+				None => SellLimit::<T>::kill(),
+			}
+			Self::deposit_event(Event::<T>::SellLimitUpdated { limit });
+			Ok(().into())
+		}
+
+		/// Allows root to update the sell limit of the treasury.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Origin must be root.
+		/// - `limit`: New limit of the total sells of all users. If None, then the total sells are not limited.
+		///
+		/// Emits `GlobalSellLimitUpdated` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_global_sell_limit())]
+		pub fn update_global_sell_limit(
+			origin: OriginFor<T>,
+			limit: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			match limit {
+				Some(limit) => GlobalSellLimit::<T>::put(limit),
+				None => GlobalSellLimit::<T>::kill(),
+			}
+			Self::deposit_event(Event::<T>::GlobalSellLimitUpdated { limit });
+			Ok(().into())
+		}
+
+		/// Allows root to update the allowed currencies the treasury pays for sold native token.
+		/// `AllowedSellCurrencies` storage will be reset and updated with provided `assets`.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Origin must be root.
+		/// - `assets`: List of assets to be inserted into `AllowedSellCurrencies` storage.
+		///
+		/// Emits `AllowedAssetsForSellUpdated` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_allowed_sell_assets(T::MaxAllowedBuyoutCurrencies::get()))]
+		#[transactional]
+		pub fn update_allowed_sell_assets(
+			origin: OriginFor<T>,
+			assets: Vec<CurrencyIdOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			// Ensure number of currencies doesn't exceed the maximum allowed
+			let max_allowed_currencies_for_sell = T::MaxAllowedBuyoutCurrencies::get();
+			ensure!(
+				assets.len() <= max_allowed_currencies_for_sell as usize,
+				Error::<T>::ExceedsNumberOfAllowedCurrencies
+			);
+
+			// Ensure that native token is not allowed for sell
+			let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+			ensure!(
+				!assets.iter().any(|asset| *asset == basic_asset),
+				Error::<T>::NativeTokenNotAllowed
+			);
+
+			// Clear `AllowedSellCurrencies` storage
+			let result = AllowedSellCurrencies::<T>::clear(max_allowed_currencies_for_sell, None);
+			// If storage clearing returns cursor which is `Some`, then clearing was not entirely successful
+			ensure!(result.maybe_cursor.is_none(), Error::<T>::StorageClearingFailure);
+
+			// Used for event data
+			let mut allowed_assets = Vec::new();
+
+			// Update `AllowedSellCurrencies` storage with provided `assets`
+			for asset in assets {
+				// Check for duplicates
+				if !AllowedSellCurrencies::<T>::contains_key(asset) {
+					AllowedSellCurrencies::<T>::insert(asset, ());
+					allowed_assets.push(asset);
+				}
+			}
+
+			Self::deposit_event(Event::<T>::AllowedAssetsForSellUpdated { allowed_assets });
+			Ok(().into())
+		}
+
+		/// Allows root to update the buyout fee curve.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Origin must be root.
+		/// - `curve`: New fee curve. If None, then the fixed `SellFee` applies.
+		///
+		/// Emits `BuyoutFeeCurveUpdated` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_buyout_fee_curve())]
+		pub fn update_buyout_fee_curve(
+			origin: OriginFor<T>,
+			curve: Option<FeeCurve>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			match curve {
+				Some(curve) => {
+					ensure!(curve.min_fee <= curve.max_fee, Error::<T>::InvalidFeeCurve);
+					BuyoutFeeCurve::<T>::put(curve)
+				},
+				None => BuyoutFeeCurve::<T>::kill(),
+			}
+			Self::deposit_event(Event::<T>::BuyoutFeeCurveUpdated { curve });
+			Ok(().into())
+		}
+
+		/// Allows root to replace the reference price of an asset for the price deviation check
+		/// by its current oracle price, e.g. after a legitimate price movement beyond `MaxPriceDeviation`.
+		///
+		/// Parameters
+		///
+		/// - `origin`: Origin must be root.
+		/// - `asset`: Currency id of the asset whose reference price is refreshed.
+		///
+		/// Emits `ReferencePriceRefreshed` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::refresh_reference_price())]
+		pub fn refresh_reference_price(
+			origin: OriginFor<T>,
+			asset: CurrencyIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let price = Self::fetch_oracle_price(&asset)?;
+			let current_block_number =
+				<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+
+			LastAcceptedPrices::<T>::insert(asset, (price, current_block_number));
+			Self::deposit_event(Event::<T>::ReferencePriceRefreshed { asset, price });
+			Ok(().into())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	/// Ensures that buyout limit is not exceeded for the current buyout period
+	/// Ensures that buyout limits of the account, the asset and the treasury are not exceeded for the current buyout period
 	fn ensure_buyout_limit_not_exceeded(
 		account_id: &AccountIdOf<T>,
+		asset: &CurrencyIdOf<T>,
 		buyout_amount: BalanceOf<T>,
 	) -> DispatchResult {
 		if let Some(buyout_limit) = BuyoutLimit::<T>::get() {
-			let buyout_period = T::BuyoutPeriod::get();
-			// Get current block number
-			let current_block_number =
-				<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
-			let current_period_start_number = current_block_number
-				.checked_div(buyout_period)
-				.map(|n| n.saturating_mul(buyout_period))
-				.unwrap_or_default();
-			let (mut buyouts, last_buyout) = Buyouts::<T>::get(account_id);
-
-			// Check if caller's last buyout was in the previous period
-			// If true, reset buyout amount limit for the caller since this is the first buyout in the current period
-			if !buyouts.is_zero() && last_buyout < current_period_start_number {
-				buyouts = Default::default();
-				Buyouts::<T>::insert(account_id, (buyouts, current_block_number));
-			};
-
+			let buyouts = Self::buyouts_in_current_period(Buyouts::<T>::get(account_id));
 			ensure!(
 				buyouts.saturating_add(buyout_amount) <= buyout_limit,
 				Error::<T>::BuyoutLimitExceeded
 			);
 		}
 
+		if let Some(asset_buyout_limit) = AssetBuyoutLimits::<T>::get(asset) {
+			let asset_buyouts =
+				Self::buyouts_in_current_period(AssetBuyouts::<T>::get(account_id, asset));
+			ensure!(
+				asset_buyouts.saturating_add(buyout_amount) <= asset_buyout_limit,
+				Error::<T>::AssetBuyoutLimitExceeded
+			);
+		}
+
+		if let Some(global_buyout_limit) = GlobalBuyoutLimit::<T>::get() {
+			let global_buyouts = Self::buyouts_in_current_period(GlobalBuyouts::<T>::get());
+			ensure!(
+				global_buyouts.saturating_add(buyout_amount) <= global_buyout_limit,
+				Error::<T>::GlobalBuyoutLimitExceeded
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Returns the buyout amount of a (amount, block number of last buyout) entry which counts towards the current period
+	fn buyouts_in_current_period((buyouts, last_buyout): (BalanceOf<T>, u32)) -> BalanceOf<T> {
+		if last_buyout < Self::current_period_start() {
+			Zero::zero()
+		} else {
+			buyouts
+		}
+	}
+
+	/// Returns the block number at which the current buyout period started
+	fn current_period_start() -> u32 {
+		let buyout_period = T::BuyoutPeriod::get();
+		<frame_system::Pallet<T>>::block_number()
+			.saturated_into::<u32>()
+			.checked_div(buyout_period)
+			.map(|n| n.saturating_mul(buyout_period))
+			.unwrap_or_default()
+	}
+
+	/// Ensures that the buyout does not pay more or receive less than the slippage limit
+	fn ensure_slippage_limit_not_exceeded(
+		amount: Amount<BalanceOf<T>>,
+		buyout_amount: BalanceOf<T>,
+		exchange_amount: BalanceOf<T>,
+		slippage_limit: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		if let Some(slippage_limit) = slippage_limit {
+			let within_limit = match amount {
+				Amount::Buyout(_) => exchange_amount <= slippage_limit,
+				Amount::Exchange(_) => buyout_amount >= slippage_limit,
+			};
+			ensure!(within_limit, Error::<T>::SlippageLimitExceeded);
+		}
+
 		Ok(())
 	}
 
@@ -315,31 +763,213 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Updates buyouts storage for the account
-	fn update_buyouts(account_id: &AccountIdOf<T>, buyout_amount: BalanceOf<T>) {
-		if BuyoutLimit::<T>::get().is_some() {
-			Buyouts::<T>::mutate(account_id, |(prev_buyouts, last)| {
-				*prev_buyouts = prev_buyouts.saturating_add(buyout_amount);
-				*last = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
-			});
+	/// Updates buyouts storage for the account, the asset and the treasury
+	fn update_buyouts(
+		account_id: &AccountIdOf<T>,
+		asset: &CurrencyIdOf<T>,
+		buyout_amount: BalanceOf<T>,
+	) {
+		let current_block_number =
+			<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+
+		// The buyouts are tracked regardless of the configured limits and fee curve,
+		// so that setting them within a period accounts for the buyouts made before
+		Buyouts::<T>::mutate(account_id, |entry| {
+			*entry = (
+				Self::buyouts_in_current_period(*entry).saturating_add(buyout_amount),
+				current_block_number,
+			);
+		});
+
+		AssetBuyouts::<T>::mutate(account_id, asset, |entry| {
+			*entry = (
+				Self::buyouts_in_current_period(*entry).saturating_add(buyout_amount),
+				current_block_number,
+			);
+		});
+
+		GlobalBuyouts::<T>::mutate(|entry| {
+			*entry = (
+				Self::buyouts_in_current_period(*entry).saturating_add(buyout_amount),
+				current_block_number,
+			);
+		});
+	}
+
+	/// Returns the `BuyoutFeeCurve` together with the native amount sold in the current buyout period
+	/// and the treasury's native balance at the start of the period
+	fn buyout_fee_curve() -> Option<(FeeCurve, u128, u128)> {
+		let curve = BuyoutFeeCurve::<T>::get()?;
+		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+		let sold: u128 =
+			Self::buyouts_in_current_period(GlobalBuyouts::<T>::get()).saturated_into();
+		let bought: u128 =
+			Self::buyouts_in_current_period(GlobalSells::<T>::get()).saturated_into();
+		let treasury_balance: u128 =
+			T::Currency::free_balance(basic_asset, &T::TreasuryAccount::get()).saturated_into();
+
+		// The treasury's native balance at the start of the period includes what was sold since
+		// but not what was bought back from users since
+		Some((curve, sold, treasury_balance.saturating_add(sold).saturating_sub(bought)))
+	}
+
+	/// Returns the fee for buying out `buyout_amount` of the native token.
+	/// If `BuyoutFeeCurve` is set, the fee is the average of the curve over the share of the treasury's native balance
+	/// sold by this buyout, otherwise the fixed `SellFee` applies
+	fn buyout_fee(
+		fee_curve: Option<(FeeCurve, u128, u128)>,
+		buyout_amount: BalanceOf<T>,
+	) -> Permill {
+		match fee_curve {
+			Some((curve, sold, total)) =>
+				curve.average_fee(sold, buyout_amount.saturated_into(), total),
+			None => T::SellFee::get(),
+		}
+	}
+
+	/// Adds the fee to the price of the basic asset
+	fn basic_asset_price_with_fee(
+		basic_asset_price: FixedU128,
+		fee: Permill,
+	) -> Result<FixedU128, DispatchError> {
+		let fee_plus_one = FixedU128::from(fee)
+			.checked_add(&FixedU128::one())
+			.ok_or::<DispatchError>(ArithmeticError::Overflow.into())?;
+
+		Ok(basic_asset_price.saturating_mul(fee_plus_one))
+	}
+
+	/// Stores the prices of a buyout as reference for the price deviation check
+	fn update_last_accepted_prices(
+		assets: (&CurrencyIdOf<T>, &CurrencyIdOf<T>),
+		(basic_asset_price, exchange_asset_price): (FixedU128, FixedU128),
+	) {
+		let current_block_number =
+			<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+
+		LastAcceptedPrices::<T>::insert(assets.0, (basic_asset_price, current_block_number));
+		LastAcceptedPrices::<T>::insert(assets.1, (exchange_asset_price, current_block_number));
+	}
+
+	/// Records a buyout in the rolling buyout history of the current buyout period
+	fn record_buyout(
+		who: &AccountIdOf<T>,
+		asset: CurrencyIdOf<T>,
+		buyout_amount: BalanceOf<T>,
+		exchange_amount: BalanceOf<T>,
+		(basic_asset_price, exchange_asset_price): (FixedU128, FixedU128),
+	) -> DispatchResult {
+		let max_periods = T::MaxBuyoutHistoryPeriods::get();
+		let max_records = T::MaxBuyoutRecordsPerPeriod::get();
+		if max_periods.is_zero() || max_records.is_zero() {
+			return Ok(());
+		}
+
+		// The fee is the part of the exchange amount which exceeds the value of the buyout amount
+		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+		let exchange_amount_without_fee = Self::convert_amount(
+			buyout_amount,
+			basic_asset_price,
+			exchange_asset_price,
+			T::DecimalsLookup::decimals(basic_asset),
+			T::DecimalsLookup::decimals(asset),
+		)?;
+		let fee = exchange_amount.saturating_sub(exchange_amount_without_fee);
+		let native_fee = Self::convert_amount(
+			fee,
+			exchange_asset_price,
+			basic_asset_price,
+			T::DecimalsLookup::decimals(asset),
+			T::DecimalsLookup::decimals(basic_asset),
+		)?;
+
+		let current_block_number =
+			<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+		let period_start = Self::current_period_start();
+		let slot =
+			period_start.checked_div(T::BuyoutPeriod::get()).unwrap_or_default() % max_periods;
+
+		// The slot still holds an older period if this is the first buyout of the current period
+		let (buyouts, total_fees) = match BuyoutHistoryPeriods::<T>::get(slot) {
+			Some((start, buyouts, total_fees)) if start == period_start => (buyouts, total_fees),
+			_ => (0, Zero::zero()),
+		};
+
+		BuyoutHistory::<T>::insert(
+			slot,
+			buyouts % max_records,
+			BuyoutRecord {
+				who: who.clone(),
+				asset,
+				buyout_amount,
+				exchange_amount,
+				fee,
+				basic_asset_price,
+				exchange_asset_price,
+				block: current_block_number,
+			},
+		);
+		BuyoutHistoryPeriods::<T>::insert(
+			slot,
+			(period_start, buyouts.saturating_add(1), total_fees.saturating_add(native_fee)),
+		);
+		BuyoutHistorySummaries::<T>::mutate(slot, asset, |entry| {
+			let mut summary = match entry.take() {
+				Some((start, summary)) if start == period_start => summary,
+				_ => Default::default(),
+			};
+			summary.buyouts = summary.buyouts.saturating_add(1);
+			summary.buyout_amount = summary.buyout_amount.saturating_add(buyout_amount);
+			summary.exchange_amount = summary.exchange_amount.saturating_add(exchange_amount);
+			summary.fees = summary.fees.saturating_add(fee);
+			*entry = Some((period_start, summary));
+		});
+
+		Ok(())
+	}
+
+	/// Ensures that the price does not deviate more than `MaxPriceDeviation` from the last accepted price
+	/// unless the last accepted price is older than `MaxReferencePriceAge`
+	fn ensure_price_deviation_not_exceeded(
+		asset: &CurrencyIdOf<T>,
+		price: FixedU128,
+	) -> DispatchResult {
+		if let Some((last_price, last_buyout)) = LastAcceptedPrices::<T>::get(asset) {
+			let current_block_number =
+				<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let age = current_block_number.saturating_sub(last_buyout);
+			if age > T::MaxReferencePriceAge::get() || last_price.is_zero() {
+				return Ok(());
+			}
+
+			let deviation = if price > last_price {
+				price.saturating_sub(last_price)
+			} else {
+				last_price.saturating_sub(price)
+			}
+			.checked_div(&last_price)
+			.ok_or(ArithmeticError::DivisionByZero)?;
+
+			ensure!(
+				deviation <= FixedU128::from(T::MaxPriceDeviation::get()),
+				Error::<T>::PriceDeviationTooHigh
+			);
 		}
+
+		Ok(())
 	}
 
 	/// Used for calculating amount of exchange asset user will get for buyout_amount of basic asset
 	fn calc_amount_to_exchange(
 		asset: CurrencyIdOf<T>,
 		buyout_amount: BalanceOf<T>,
+		(basic_asset_price, exchange_asset_price): (FixedU128, FixedU128),
 	) -> Result<BalanceOf<T>, DispatchError> {
 		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
-		ensure!(asset != basic_asset, Error::<T>::WrongAssetToBuyout);
-
-		let (basic_asset_price, exchange_asset_price) = Self::fetch_prices((&basic_asset, &asset))?;
 
 		// Add fee to the basic asset price
-		let fee_plus_one = FixedU128::from(T::SellFee::get())
-			.checked_add(&FixedU128::one())
-			.ok_or::<DispatchError>(ArithmeticError::Overflow.into())?;
-		let basic_asset_price_with_fee = basic_asset_price.saturating_mul(fee_plus_one);
+		let fee = Self::buyout_fee(Self::buyout_fee_curve(), buyout_amount);
+		let basic_asset_price_with_fee = Self::basic_asset_price_with_fee(basic_asset_price, fee)?;
 
 		// Calculate exchange amount taking into consideration assets' prices and decimals
 		let exchange_amount = Self::convert_amount(
@@ -357,45 +987,74 @@ impl<T: Config> Pallet<T> {
 	fn calc_buyout_amount(
 		asset: CurrencyIdOf<T>,
 		exchange_amount: BalanceOf<T>,
+		(basic_asset_price, exchange_asset_price): (FixedU128, FixedU128),
 	) -> Result<BalanceOf<T>, DispatchError> {
 		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
 
-		ensure!(asset != basic_asset, Error::<T>::WrongAssetToBuyout);
-
-		let (basic_asset_price, exchange_asset_price) = Self::fetch_prices((&basic_asset, &asset))?;
-
-		// Add fee to the basic asset price
-		let fee_plus_one = FixedU128::from(T::SellFee::get())
-			.checked_add(&FixedU128::one())
-			.ok_or::<DispatchError>(ArithmeticError::Overflow.into())?;
-		let basic_asset_price_with_fee = basic_asset_price.saturating_mul(fee_plus_one);
-
-		// Calculate buyout amount taking into consideration assets' prices and decimals
-		let buyout_amount = Self::convert_amount(
-			exchange_amount,
-			exchange_asset_price,
-			basic_asset_price_with_fee,
-			T::DecimalsLookup::decimals(asset),
-			T::DecimalsLookup::decimals(basic_asset),
-		);
+		// Calculate buyout amount taking into consideration assets' prices, decimals and the fee
+		let buyout_amount_with_fee = |fee: Permill| -> Result<BalanceOf<T>, DispatchError> {
+			Self::convert_amount(
+				exchange_amount,
+				exchange_asset_price,
+				Self::basic_asset_price_with_fee(basic_asset_price, fee)?,
+				T::DecimalsLookup::decimals(asset),
+				T::DecimalsLookup::decimals(basic_asset),
+			)
+		};
+
+		let fee_curve = Self::buyout_fee_curve();
+		let (curve, sold, total) = match fee_curve {
+			Some(fee_curve) => fee_curve,
+			None => return buyout_amount_with_fee(T::SellFee::get()),
+		};
+
+		// The fee depends on the buyout amount, so the largest buyout amount which does not cost more than
+		// `exchange_amount` is searched. It lies between the buyout amounts at the max fee and the current fee.
+		let mut low: u128 = buyout_amount_with_fee(curve.max_fee)?.saturated_into();
+		let mut high: u128 =
+			buyout_amount_with_fee(curve.fee(Permill::from_rational(sold, total)))?
+				.saturated_into();
+		while low < high {
+			let mid = low.saturating_add(high.saturating_sub(low).saturating_add(1) / 2);
+			let fee = Self::buyout_fee(fee_curve, mid.saturated_into());
+			let cost = Self::convert_amount(
+				mid.saturated_into(),
+				Self::basic_asset_price_with_fee(basic_asset_price, fee)?,
+				exchange_asset_price,
+				T::DecimalsLookup::decimals(basic_asset),
+				T::DecimalsLookup::decimals(asset),
+			)?;
+
+			if cost <= exchange_amount {
+				low = mid;
+			} else {
+				high = mid.saturating_sub(1);
+			}
+		}
 
-		buyout_amount
+		Ok(low.saturated_into())
 	}
 
 	/// Used for splitting calculations of amount based on the input given
 	/// If user's call contains buyout amount, then exchange amount is calculated and viceversa
+	/// Also returns the prices (basic asset price, exchange asset price) used for the calculation
 	fn split_to_buyout_and_exchange(
 		asset: CurrencyIdOf<T>,
 		amount: Amount<BalanceOf<T>>,
-	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+	) -> Result<(BalanceOf<T>, BalanceOf<T>, (FixedU128, FixedU128)), DispatchError> {
+		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+		ensure!(asset != basic_asset, Error::<T>::WrongAssetToBuyout);
+
+		let prices = Self::fetch_prices((&basic_asset, &asset))?;
+
 		match amount {
 			Amount::Buyout(buyout_amount) => {
-				let exchange_amount = Self::calc_amount_to_exchange(asset, buyout_amount)?;
-				Ok((buyout_amount, exchange_amount))
+				let exchange_amount = Self::calc_amount_to_exchange(asset, buyout_amount, prices)?;
+				Ok((buyout_amount, exchange_amount, prices))
 			},
 			Amount::Exchange(exchange_amount) => {
-				let buyout_amount = Self::calc_buyout_amount(asset, exchange_amount)?;
-				Ok((buyout_amount, exchange_amount))
+				let buyout_amount = Self::calc_buyout_amount(asset, exchange_amount, prices)?;
+				Ok((buyout_amount, exchange_amount, prices))
 			},
 		}
 	}
@@ -404,13 +1063,21 @@ impl<T: Config> Pallet<T> {
 		who: AccountIdOf<T>,
 		asset: CurrencyIdOf<T>,
 		amount: Amount<BalanceOf<T>>,
+		slippage_limit: Option<BalanceOf<T>>,
 	) -> DispatchResult {
 		Self::ensure_asset_allowed_for_buyout(&asset)?;
 
 		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
-		let (buyout_amount, exchange_amount) = Self::split_to_buyout_and_exchange(asset, amount)?;
+		let (buyout_amount, exchange_amount, prices) =
+			Self::split_to_buyout_and_exchange(asset, amount)?;
 
-		Self::ensure_buyout_limit_not_exceeded(&who, buyout_amount)?;
+		Self::ensure_slippage_limit_not_exceeded(
+			amount,
+			buyout_amount,
+			exchange_amount,
+			slippage_limit,
+		)?;
+		Self::ensure_buyout_limit_not_exceeded(&who, &asset, buyout_amount)?;
 		let treasury_account_id = T::TreasuryAccount::get();
 
 		// Start exchanging
@@ -440,24 +1107,307 @@ impl<T: Config> Pallet<T> {
 		T::Currency::transfer(basic_asset, &treasury_account_id, &who, buyout_amount)
 			.map_err(|_| Error::<T>::ExchangeFailure)?;
 
-		Self::update_buyouts(&who, buyout_amount);
+		Self::update_buyouts(&who, &asset, buyout_amount);
+		Self::update_last_accepted_prices((&basic_asset, &asset), prices);
+		Self::record_buyout(&who, asset, buyout_amount, exchange_amount, prices)?;
 		Self::deposit_event(Event::<T>::Buyout { who, buyout_amount, asset, exchange_amount });
 
 		Ok(())
 	}
 
+	/// Ensures that asset is allowed for sell
+	fn ensure_asset_allowed_for_sell(asset: &CurrencyIdOf<T>) -> DispatchResult {
+		ensure!(AllowedSellCurrencies::<T>::get(asset) == Some(()), Error::<T>::WrongAssetToSell);
+
+		Ok(())
+	}
+
+	/// Ensures that sell limits of the account and the treasury are not exceeded for the current buyout period
+	fn ensure_sell_limit_not_exceeded(
+		account_id: &AccountIdOf<T>,
+		sell_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if let Some(sell_limit) = SellLimit::<T>::get() {
+			let sells = Self::buyouts_in_current_period(Sells::<T>::get(account_id));
+			ensure!(sells.saturating_add(sell_amount) <= sell_limit, Error::<T>::SellLimitExceeded);
+		}
+
+		if let Some(global_sell_limit) = GlobalSellLimit::<T>::get() {
+			let global_sells = Self::buyouts_in_current_period(GlobalSells::<T>::get());
+			ensure!(
+				global_sells.saturating_add(sell_amount) <= global_sell_limit,
+				Error::<T>::GlobalSellLimitExceeded
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Ensures that the sell does not sell more or receive less than the slippage limit
+	fn ensure_sell_slippage_limit_not_exceeded(
+		amount: Amount<BalanceOf<T>>,
+		sell_amount: BalanceOf<T>,
+		exchange_amount: BalanceOf<T>,
+		slippage_limit: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		if let Some(slippage_limit) = slippage_limit {
+			let within_limit = match amount {
+				Amount::Buyout(_) => exchange_amount >= slippage_limit,
+				Amount::Exchange(_) => sell_amount <= slippage_limit,
+			};
+			ensure!(within_limit, Error::<T>::SlippageLimitExceeded);
+		}
+
+		Ok(())
+	}
+
+	/// Updates sells storage for the account and the treasury
+	fn update_sells(account_id: &AccountIdOf<T>, sell_amount: BalanceOf<T>) {
+		let current_block_number =
+			<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+
+		// The sells are tracked regardless of the configured limits, the sells of all users are
+		// also needed for the fee curve
+		Sells::<T>::mutate(account_id, |entry| {
+			*entry = (
+				Self::buyouts_in_current_period(*entry).saturating_add(sell_amount),
+				current_block_number,
+			);
+		});
+
+		GlobalSells::<T>::mutate(|entry| {
+			*entry = (
+				Self::buyouts_in_current_period(*entry).saturating_add(sell_amount),
+				current_block_number,
+			);
+		});
+	}
+
+	/// Used for splitting calculations of a sell based on the input given
+	/// If user's call contains sell amount, then exchange amount is calculated and viceversa
+	/// Also returns the prices (basic asset price, exchange asset price) used for the calculation
+	fn split_to_sell_and_exchange(
+		asset: CurrencyIdOf<T>,
+		amount: Amount<BalanceOf<T>>,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>, (FixedU128, FixedU128)), DispatchError> {
+		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+		ensure!(asset != basic_asset, Error::<T>::WrongAssetToSell);
+
+		let prices = Self::fetch_prices((&basic_asset, &asset))?;
+		let (basic_asset_price, exchange_asset_price) = prices;
+
+		// Subtract fee from the basic asset price
+		let one_minus_fee = FixedU128::one()
+			.checked_sub(&FixedU128::from(T::BuyFee::get()))
+			.ok_or::<DispatchError>(ArithmeticError::Underflow.into())?;
+		let basic_asset_price_with_fee = basic_asset_price.saturating_mul(one_minus_fee);
+
+		// Calculate amounts taking into consideration assets' prices and decimals
+		match amount {
+			Amount::Buyout(sell_amount) => {
+				let exchange_amount = Self::convert_amount(
+					sell_amount,
+					basic_asset_price_with_fee,
+					exchange_asset_price,
+					T::DecimalsLookup::decimals(basic_asset),
+					T::DecimalsLookup::decimals(asset),
+				)?;
+				Ok((sell_amount, exchange_amount, prices))
+			},
+			Amount::Exchange(exchange_amount) => {
+				let sell_amount = Self::convert_amount(
+					exchange_amount,
+					exchange_asset_price,
+					basic_asset_price_with_fee,
+					T::DecimalsLookup::decimals(asset),
+					T::DecimalsLookup::decimals(basic_asset),
+				)?;
+				Ok((sell_amount, exchange_amount, prices))
+			},
+		}
+	}
+
+	fn do_sell(
+		who: AccountIdOf<T>,
+		asset: CurrencyIdOf<T>,
+		amount: Amount<BalanceOf<T>>,
+		slippage_limit: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		Self::ensure_asset_allowed_for_sell(&asset)?;
+
+		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+		let (sell_amount, exchange_amount, prices) =
+			Self::split_to_sell_and_exchange(asset, amount)?;
+
+		Self::ensure_sell_slippage_limit_not_exceeded(
+			amount,
+			sell_amount,
+			exchange_amount,
+			slippage_limit,
+		)?;
+		Self::ensure_sell_limit_not_exceeded(&who, sell_amount)?;
+		let treasury_account_id = T::TreasuryAccount::get();
+
+		// Check for same accounts
+		if who == treasury_account_id {
+			return Err(Error::<T>::SellWithTreasuryAccount.into());
+		}
+		// Check for exchanging zero values
+		if exchange_amount.is_zero() && sell_amount.is_zero() {
+			return Err(Error::<T>::LessThanMinSellAmount.into());
+		}
+
+		// Check both balances before transfer
+		let user_balance = T::Currency::free_balance(basic_asset, &who);
+		let treasury_balance = T::Currency::free_balance(asset, &treasury_account_id);
+
+		if user_balance < sell_amount {
+			return Err(Error::<T>::InsufficientAccountBalance.into());
+		}
+		if treasury_balance < exchange_amount {
+			return Err(Error::<T>::InsufficientTreasuryBalance.into());
+		}
+
+		// Transfer from user account to treasury then viceversa
+		T::Currency::transfer(basic_asset, &who, &treasury_account_id, sell_amount)
+			.map_err(|_| Error::<T>::ExchangeFailure)?;
+		T::Currency::transfer(asset, &treasury_account_id, &who, exchange_amount)
+			.map_err(|_| Error::<T>::ExchangeFailure)?;
+
+		Self::update_sells(&who, sell_amount);
+		Self::update_last_accepted_prices((&basic_asset, &asset), prices);
+		Self::deposit_event(Event::<T>::Sell { who, sell_amount, asset, exchange_amount });
+
+		Ok(())
+	}
+
+	/// Calculates the amounts of a buyout at the current prices without executing it.
+	/// The fee is the part of the exchange amount which is charged on top due to the current buyout fee.
+	///
+	/// At least used in Runtime API.
+	pub fn get_buyout_quote(
+		asset: CurrencyIdOf<T>,
+		amount: Amount<BalanceOf<T>>,
+	) -> Result<BuyoutQuote<BalanceOf<T>>, DispatchError> {
+		Self::ensure_asset_allowed_for_buyout(&asset)?;
+
+		let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+		let (buyout_amount, exchange_amount, (basic_asset_price, exchange_asset_price)) =
+			Self::split_to_buyout_and_exchange(asset, amount)?;
+
+		let exchange_amount_without_fee = Self::convert_amount(
+			buyout_amount,
+			basic_asset_price,
+			exchange_asset_price,
+			T::DecimalsLookup::decimals(basic_asset),
+			T::DecimalsLookup::decimals(asset),
+		)?;
+
+		Ok(BuyoutQuote {
+			buyout_amount,
+			exchange_amount,
+			fee: exchange_amount.saturating_sub(exchange_amount_without_fee),
+		})
+	}
+
+	/// Returns the amount of native token the account can still buy out in the current buyout period.
+	/// Takes the limits of the account and the treasury into account, limits of single assets are not considered.
+	/// Returns `None` if buyouts are not limited.
+	///
+	/// At least used in Runtime API.
+	pub fn get_buyout_allowance(
+		account_id: &AccountIdOf<T>,
+	) -> Option<BuyoutAllowance<BalanceOf<T>>> {
+		// Buyouts of previous periods do not count towards the limits
+		let account_remaining = BuyoutLimit::<T>::get().map(|limit| {
+			limit.saturating_sub(Self::buyouts_in_current_period(Buyouts::<T>::get(account_id)))
+		});
+		let global_remaining = GlobalBuyoutLimit::<T>::get().map(|limit| {
+			limit.saturating_sub(Self::buyouts_in_current_period(GlobalBuyouts::<T>::get()))
+		});
+
+		let remaining = match (account_remaining, global_remaining) {
+			(Some(account), Some(global)) => account.min(global),
+			(account, global) => account.or(global)?,
+		};
+
+		Some(BuyoutAllowance {
+			remaining,
+			period_end: Self::current_period_start().saturating_add(T::BuyoutPeriod::get()),
+		})
+	}
+
+	/// Returns the rolling buyout history of the last `MaxBuyoutHistoryPeriods` buyout periods, oldest period first.
+	/// Sells to the treasury are not part of the history.
+	///
+	/// At least used in Runtime API.
+	pub fn get_buyout_history() -> Vec<BuyoutPeriodReportOf<T>> {
+		let max_records = T::MaxBuyoutRecordsPerPeriod::get();
+
+		let mut reports: Vec<BuyoutPeriodReportOf<T>> = BuyoutHistoryPeriods::<T>::iter()
+			.map(|(slot, (period_start, buyouts, total_fees))| {
+				// Once a period has more buyouts than records, the oldest record is the next one to be overwritten
+				let oldest = if buyouts > max_records {
+					buyouts.checked_rem(max_records).unwrap_or_default()
+				} else {
+					0
+				};
+				let records = (0..buyouts.min(max_records))
+					.filter_map(|i| {
+						let index = oldest.saturating_add(i).checked_rem(max_records)?;
+						BuyoutHistory::<T>::get(slot, index)
+					})
+					.collect();
+
+				let assets = BuyoutHistorySummaries::<T>::iter_prefix(slot)
+					.filter(|(_, (start, _))| *start == period_start)
+					.map(|(asset, (_, summary))| (asset, summary))
+					.collect();
+
+				BuyoutPeriodReport { period_start, buyouts, total_fees, assets, records }
+			})
+			.collect();
+
+		reports.sort_by_key(|report| report.period_start);
+		reports
+	}
+
+	/// Returns the currencies which are allowed for buyout.
+	///
+	/// At least used in Runtime API.
+	pub fn get_allowed_currencies() -> Vec<CurrencyIdOf<T>> {
+		AllowedCurrencies::<T>::iter_keys().collect()
+	}
+
 	/// Used for fetching asset prices
 	/// The concrete implementation of PriceGetter trait must be provided by the runtime e.g. oracle pallet
 	fn fetch_prices(
 		assets: (&CurrencyIdOf<T>, &CurrencyIdOf<T>),
 	) -> Result<(FixedU128, FixedU128), DispatchError> {
-		let basic_asset_price: FixedU128 =
-			T::PriceGetter::get_price::<FixedU128>(*assets.0).map_err(|_| Error::<T>::NoPrice)?;
-		let exchange_asset_price: FixedU128 =
-			T::PriceGetter::get_price::<FixedU128>(*assets.1).map_err(|_| Error::<T>::NoPrice)?;
+		let basic_asset_price = Self::fetch_price(assets.0)?;
+		let exchange_asset_price = Self::fetch_price(assets.1)?;
 		Ok((basic_asset_price, exchange_asset_price))
 	}
 
+	/// Fetches the price of an asset and checks it against the last accepted price.
+	fn fetch_price(asset: &CurrencyIdOf<T>) -> Result<FixedU128, DispatchError> {
+		let price = Self::fetch_oracle_price(asset)?;
+		Self::ensure_price_deviation_not_exceeded(asset, price)?;
+		Ok(price)
+	}
+
+	/// Fetches the price of an asset from the `PriceGetter`.
+	/// Stale prices are reported as `StalePrice`, any other failure of the `PriceGetter` as `NoPrice`
+	fn fetch_oracle_price(asset: &CurrencyIdOf<T>) -> Result<FixedU128, DispatchError> {
+		T::PriceGetter::get_price::<FixedU128>(*asset).map_err(|err| {
+			if err == Error::<T>::StalePrice.into() {
+				err
+			} else {
+				Error::<T>::NoPrice.into()
+			}
+		})
+	}
+
 	/// Used for converting amount from one asset to another based on their decimals and prices
 	fn convert_amount(
 		from_amount: BalanceOf<T>,
@@ -537,6 +1487,26 @@ pub enum ValidityError {
 	LessThanMinBuyoutAmount = 3,
 	/// Wrong asset
 	WrongAssetToBuyout = 4,
+	/// Slippage limit exceeded
+	SlippageLimitExceeded = 5,
+	/// Buyout limit of the asset exceeded
+	AssetBuyoutLimitExceeded = 6,
+	/// Buyout limit of the treasury exceeded
+	GlobalBuyoutLimitExceeded = 7,
+	/// Price of the oracle is too old
+	StalePrice = 8,
+	/// Price deviates too much from the last accepted price
+	PriceDeviationTooHigh = 9,
+	/// Wrong asset to sell
+	WrongAssetToSell = 10,
+	/// Account balance is too low to make sell
+	NotEnoughToSell = 11,
+	/// Sell limit exceeded
+	SellLimitExceeded = 12,
+	/// Sell limit of the treasury exceeded
+	GlobalSellLimitExceeded = 13,
+	/// Amount to sell less than min amount
+	LessThanMinSellAmount = 14,
 }
 
 impl From<ValidityError> for u8 {
@@ -581,6 +1551,17 @@ where
 	pub fn new() -> Self {
 		Self(PhantomData)
 	}
+
+	/// Maps an error of the price calculation to the matching validity error
+	fn price_validity_error(err: DispatchError) -> ValidityError {
+		if err == Error::<T>::StalePrice.into() {
+			ValidityError::StalePrice
+		} else if err == Error::<T>::PriceDeviationTooHigh.into() {
+			ValidityError::PriceDeviationTooHigh
+		} else {
+			ValidityError::Math
+		}
+	}
 }
 
 impl<T: Config + Send + Sync + scale_info::TypeInfo> SignedExtension for CheckBuyout<T>
@@ -611,9 +1592,13 @@ where
 
 	/// Checks:
 	/// - asset is allowed for buyout
+	/// - prices of the oracle are fresh and do not deviate too much from the last accepted prices
 	/// - buyout amount is greater or equal `MinAmountToBuyout`
 	/// - `who` has enough balance to make buyout
-	/// - buyout limit is not exceeded for `who`
+	/// - buyout limits are not exceeded for `who`, `asset` and the treasury
+	/// - slippage limit is not exceeded at the current prices
+	///
+	/// Sells are checked the same way against `MinAmountToSell`, the sell limits and the allowed sell assets.
 	fn validate(
 		&self,
 		who: &Self::AccountId,
@@ -621,14 +1606,15 @@ where
 		_info: &DispatchInfoOf<Self::Call>,
 		_len: usize,
 	) -> TransactionValidity {
-		if let Some(Call::buyout { asset, amount }) = call.is_sub_type() {
+		if let Some(Call::buyout { asset, amount, slippage_limit }) = call.is_sub_type() {
 			Pallet::<T>::ensure_asset_allowed_for_buyout(asset).map_err(|_| {
 				InvalidTransaction::Custom(ValidityError::WrongAssetToBuyout.into())
 			})?;
 
-			let (buyout_amount, exchange_amount) =
-				Pallet::<T>::split_to_buyout_and_exchange(*asset, *amount)
-					.map_err(|_| InvalidTransaction::Custom(ValidityError::Math.into()))?;
+			let (buyout_amount, exchange_amount, _) =
+				Pallet::<T>::split_to_buyout_and_exchange(*asset, *amount).map_err(|err| {
+					InvalidTransaction::Custom(Self::price_validity_error(err).into())
+				})?;
 
 			ensure!(
 				buyout_amount >= T::MinAmountToBuyout::get(),
@@ -642,9 +1628,64 @@ where
 				InvalidTransaction::Custom(ValidityError::NotEnoughToBuyout.into())
 			);
 
-			Pallet::<T>::ensure_buyout_limit_not_exceeded(who, buyout_amount).map_err(|_| {
-				InvalidTransaction::Custom(ValidityError::BuyoutLimitExceeded.into())
+			Pallet::<T>::ensure_buyout_limit_not_exceeded(who, asset, buyout_amount).map_err(
+				|err| {
+					let validity_error = if err == Error::<T>::AssetBuyoutLimitExceeded.into() {
+						ValidityError::AssetBuyoutLimitExceeded
+					} else if err == Error::<T>::GlobalBuyoutLimitExceeded.into() {
+						ValidityError::GlobalBuyoutLimitExceeded
+					} else {
+						ValidityError::BuyoutLimitExceeded
+					};
+					InvalidTransaction::Custom(validity_error.into())
+				},
+			)?;
+
+			Pallet::<T>::ensure_slippage_limit_not_exceeded(
+				*amount,
+				buyout_amount,
+				exchange_amount,
+				*slippage_limit,
+			)
+			.map_err(|_| InvalidTransaction::Custom(ValidityError::SlippageLimitExceeded.into()))?;
+		} else if let Some(Call::sell { asset, amount, slippage_limit }) = call.is_sub_type() {
+			Pallet::<T>::ensure_asset_allowed_for_sell(asset)
+				.map_err(|_| InvalidTransaction::Custom(ValidityError::WrongAssetToSell.into()))?;
+
+			let (sell_amount, exchange_amount, _) =
+				Pallet::<T>::split_to_sell_and_exchange(*asset, *amount).map_err(|err| {
+					InvalidTransaction::Custom(Self::price_validity_error(err).into())
+				})?;
+
+			ensure!(
+				sell_amount >= T::MinAmountToSell::get(),
+				InvalidTransaction::Custom(ValidityError::LessThanMinSellAmount.into())
+			);
+
+			let basic_asset = <T as orml_currencies::Config>::GetNativeCurrencyId::get();
+			let free_balance = T::Currency::free_balance(basic_asset, who);
+
+			ensure!(
+				free_balance >= sell_amount,
+				InvalidTransaction::Custom(ValidityError::NotEnoughToSell.into())
+			);
+
+			Pallet::<T>::ensure_sell_limit_not_exceeded(who, sell_amount).map_err(|err| {
+				let validity_error = if err == Error::<T>::GlobalSellLimitExceeded.into() {
+					ValidityError::GlobalSellLimitExceeded
+				} else {
+					ValidityError::SellLimitExceeded
+				};
+				InvalidTransaction::Custom(validity_error.into())
 			})?;
+
+			Pallet::<T>::ensure_sell_slippage_limit_not_exceeded(
+				*amount,
+				sell_amount,
+				exchange_amount,
+				*slippage_limit,
+			)
+			.map_err(|_| InvalidTransaction::Custom(ValidityError::SlippageLimitExceeded.into()))?;
 		}
 
 		Ok(ValidTransaction::default())
@@ -13,7 +13,11 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup, One, Zero},
 	BuildStorage, DispatchError,
 };
-use sp_std::fmt::Debug;
+use sp_std::{
+	cell::RefCell,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	fmt::Debug,
+};
 use spacewalk_primitives::DecimalsLookup;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -118,11 +122,19 @@ parameter_types! {
 	pub const MaxReserves: u32 = 50;
 	pub const TreasuryAccount: AccountId = u64::MAX;
 	pub const SellFee: Permill = Permill::from_percent(1);
+	pub const BuyFee: Permill = Permill::from_percent(1);
+	// 1 hour in seconds
+	pub const MaxPriceAge: u64 = 3600;
+	pub const MaxPriceDeviation: Permill = Permill::from_percent(10);
+	pub const MaxReferencePriceAge: u32 = 3 * 7200;
 	pub const MinAmountToBuyout: Balance = 100 * UNIT;
+	pub const MinAmountToSell: Balance = 10 * UNIT;
 	// 24 hours in blocks (where average block time is 12 seconds)
 	pub const BuyoutPeriod: u32 = 7200;
 	// Maximum number of allowed currencies for buyout
 	pub const MaxAllowedBuyoutCurrencies: u32 = 20;
+	pub const MaxBuyoutHistoryPeriods: u32 = 2;
+	pub const MaxBuyoutRecordsPerPeriod: u32 = 3;
 }
 
 impl pallet_balances::Config for Test {
@@ -149,6 +161,21 @@ impl orml_currencies::Config for Test {
 	type WeightInfo = ();
 }
 
+thread_local! {
+	static PRICES: RefCell<BTreeMap<CurrencyId, FixedU128>> = RefCell::new(BTreeMap::new());
+	static STALE_PRICES: RefCell<BTreeSet<CurrencyId>> = RefCell::new(BTreeSet::new());
+}
+
+/// Simulates a price update of the oracle, prices default to one
+pub fn set_price(currency_id: CurrencyId, price: FixedU128) {
+	PRICES.with(|prices| prices.borrow_mut().insert(currency_id, price));
+}
+
+/// Simulates an oracle which did not update the price of a currency for longer than `MaxPriceAge`
+pub fn set_stale_price(currency_id: CurrencyId) {
+	STALE_PRICES.with(|stale_prices| stale_prices.borrow_mut().insert(currency_id));
+}
+
 pub struct OracleMock;
 impl PriceGetter<CurrencyId> for OracleMock {
 	fn get_price<FixedNumber>(currency_id: CurrencyId) -> Result<FixedNumber, DispatchError>
@@ -160,9 +187,15 @@ impl PriceGetter<CurrencyId> for OracleMock {
 			return Err(DispatchError::Other("No price"));
 		}
 
-		let price: FixedNumber = FixedNumber::one()
-			.try_into()
-			.map_err(|_| DispatchError::Other("FixedU128 convert"))?;
+		if STALE_PRICES.with(|stale_prices| stale_prices.borrow().contains(&currency_id)) {
+			return Err(crate::Error::<Test>::StalePrice.into());
+		}
+
+		let price = PRICES
+			.with(|prices| prices.borrow().get(&currency_id).copied())
+			.unwrap_or_else(FixedU128::one);
+		let price: FixedNumber =
+			price.try_into().map_err(|_| DispatchError::Other("FixedU128 convert"))?;
 		Ok(price)
 	}
 }
@@ -192,14 +225,25 @@ impl Config for Test {
 	type BuyoutPeriod = BuyoutPeriod;
 	/// Fee from the native asset buyouts
 	type SellFee = SellFee;
+	/// Fee from the native asset sales
+	type BuyFee = BuyFee;
+	/// Maximum age of an oracle price
+	type MaxPriceAge = MaxPriceAge;
+	/// Maximum deviation of a price from the last accepted price
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type MaxReferencePriceAge = MaxReferencePriceAge;
 	/// Used for fetching prices of currencies from oracle
 	type PriceGetter = OracleMock;
 	/// Used for fetching decimals of assets
 	type DecimalsLookup = DecimalsLookupImpl;
 	/// Min amount of native token to buyout
 	type MinAmountToBuyout = MinAmountToBuyout;
+	/// Min amount of native token to sell
+	type MinAmountToSell = MinAmountToSell;
 	/// Maximum number of storage updates for allowed currencies in one extrinsic call
 	type MaxAllowedBuyoutCurrencies = MaxAllowedBuyoutCurrencies;
+	type MaxBuyoutHistoryPeriods = MaxBuyoutHistoryPeriods;
+	type MaxBuyoutRecordsPerPeriod = MaxBuyoutRecordsPerPeriod;
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo = SubstrateWeight<Test>;
 	/// Currency id of relay chain
@@ -243,6 +287,7 @@ impl ExtBuilder {
 
 		treasury_buyout_extension::GenesisConfig::<Test> {
 			allowed_currencies: vec![dot_currency_id, 1, 2, 6],
+			allowed_sell_currencies: vec![dot_currency_id],
 		}
 		.assimilate_storage(&mut storage)
 		.unwrap();
@@ -2,11 +2,14 @@
 use crate::{
 	mock::*,
 	types::{Amount, CurrencyIdOf},
-	BuyoutLimit, Buyouts, Config, DecimalsLookup, Error, PriceGetter, ValidityError,
+	AssetBuyoutLimits, AssetBuyoutSummary, AssetBuyouts, BuyoutAllowance, BuyoutFeeCurve,
+	BuyoutLimit, Buyouts, Config, DecimalsLookup, Error, FeeCurve, GlobalBuyoutLimit,
+	GlobalBuyouts, GlobalSellLimit, GlobalSells, LastAcceptedPrices, PriceGetter, SellLimit, Sells,
+	ValidityError,
 };
 use frame_support::{assert_err, assert_noop, assert_ok};
 use orml_traits::MultiCurrency;
-use sp_arithmetic::{traits::One, FixedU128};
+use sp_arithmetic::{traits::One, FixedPointNumber, FixedU128, Permill};
 use sp_runtime::{
 	traits::BadOrigin,
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
@@ -17,6 +20,14 @@ fn get_free_balance(currency_id: CurrencyIdOf<Test>, account: &AccountId) -> Bal
 	<orml_currencies::Pallet<Test> as MultiCurrency<AccountId>>::free_balance(currency_id, account)
 }
 
+fn deposit(currency_id: CurrencyIdOf<Test>, account: &AccountId, amount: Balance) {
+	assert_ok!(<orml_currencies::Pallet<Test> as MultiCurrency<AccountId>>::deposit(
+		currency_id,
+		account,
+		amount
+	));
+}
+
 fn run_to_block(new_block: BlockNumber) {
 	frame_system::Pallet::<Test>::set_block_number(new_block);
 }
@@ -44,6 +55,7 @@ fn buyout_using_dot_given_exchange_amount_in_dot_succeeds() {
 			RuntimeOrigin::signed(user),
 			dot_currency_id,
 			Amount::Exchange(exchange_amount),
+			None,
 		));
 
 		// Fetch prices from Oracle mock
@@ -114,6 +126,7 @@ fn buyout_using_dot_given_buyout_amount_in_native_succeeds() {
 			RuntimeOrigin::signed(user),
 			dot_currency_id,
 			Amount::Buyout(buyout_amount),
+			None,
 		));
 
 		// Fetch prices from Oracle mock
@@ -199,6 +212,69 @@ fn user_update_buyout_amount_limit_fails() {
 	});
 }
 
+#[test]
+fn root_update_asset_and_global_buyout_limits_succeeds() {
+	run_test(|| {
+		let dot_currency_id = RelayChainCurrencyId::get();
+
+		assert_ok!(crate::Pallet::<Test>::update_asset_buyout_limit(
+			RuntimeOrigin::root(),
+			dot_currency_id,
+			Some(100 * UNIT),
+		));
+		assert_eq!(AssetBuyoutLimits::<Test>::get(dot_currency_id), Some(100 * UNIT));
+		System::assert_last_event(
+			crate::Event::AssetBuyoutLimitUpdated {
+				asset: dot_currency_id,
+				limit: Some(100 * UNIT),
+			}
+			.into(),
+		);
+
+		assert_ok!(crate::Pallet::<Test>::update_global_buyout_limit(
+			RuntimeOrigin::root(),
+			Some(500 * UNIT),
+		));
+		assert_eq!(GlobalBuyoutLimit::<Test>::get(), Some(500 * UNIT));
+		System::assert_last_event(
+			crate::Event::GlobalBuyoutLimitUpdated { limit: Some(500 * UNIT) }.into(),
+		);
+
+		assert_ok!(crate::Pallet::<Test>::update_asset_buyout_limit(
+			RuntimeOrigin::root(),
+			dot_currency_id,
+			None,
+		));
+		assert_eq!(AssetBuyoutLimits::<Test>::get(dot_currency_id), None);
+		assert_ok!(crate::Pallet::<Test>::update_global_buyout_limit(RuntimeOrigin::root(), None));
+		assert_eq!(GlobalBuyoutLimit::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn user_update_asset_and_global_buyout_limits_fails() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+
+		assert_noop!(
+			crate::Pallet::<Test>::update_asset_buyout_limit(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Some(100 * UNIT),
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::update_global_buyout_limit(
+				RuntimeOrigin::signed(user),
+				Some(100 * UNIT),
+			),
+			BadOrigin
+		);
+	});
+}
+
 #[test]
 fn root_update_allowed_currencies_succeeds() {
 	run_test(|| {
@@ -223,6 +299,7 @@ fn root_update_allowed_currencies_succeeds() {
 				RuntimeOrigin::signed(user),
 				dot_currency_id,
 				Amount::Exchange(exchange_amount),
+				None,
 			),
 			Error::<Test>::WrongAssetToBuyout
 		);
@@ -260,6 +337,7 @@ fn root_update_allowed_currencies_succeeds() {
 			RuntimeOrigin::signed(user),
 			dot_currency_id,
 			Amount::Exchange(exchange_amount),
+			None,
 		));
 	});
 }
@@ -332,6 +410,7 @@ fn attempt_buyout_with_wrong_currency_fails() {
 				RuntimeOrigin::signed(user),
 				native_currency_id,
 				Amount::Buyout(buyout_amount),
+				None,
 			),
 			Error::<Test>::WrongAssetToBuyout
 		);
@@ -346,6 +425,7 @@ fn attempt_buyout_with_wrong_currency_fails() {
 				RuntimeOrigin::signed(user),
 				native_currency_id,
 				Amount::Exchange(exchange_amount),
+				None,
 			),
 			Error::<Test>::WrongAssetToBuyout
 		);
@@ -371,6 +451,7 @@ fn buyout_with_previous_existing_buyouts_succeeds() {
 			RuntimeOrigin::signed(user),
 			dot_currency_id,
 			Amount::Exchange(exchange_amount),
+			None,
 		));
 	});
 }
@@ -404,6 +485,7 @@ fn attempt_buyout_after_buyout_limit_exceeded_fails() {
 				RuntimeOrigin::signed(user),
 				dot_currency_id,
 				Amount::Exchange(exchange_amount),
+				None,
 			),
 			Error::<Test>::BuyoutLimitExceeded
 		);
@@ -434,6 +516,7 @@ fn buyout_after_buyout_limit_reset_succeeds() {
 			RuntimeOrigin::signed(user),
 			dot_currency_id,
 			Amount::Buyout(buyout_amount),
+			None,
 		));
 
 		let new_current_block =
@@ -443,6 +526,146 @@ fn buyout_after_buyout_limit_reset_succeeds() {
 	});
 }
 
+#[test]
+fn attempt_buyout_after_asset_buyout_limit_exceeded_fails() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_amount = 50 * UNIT;
+		let current_block = frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
+
+		AssetBuyoutLimits::<Test>::insert(dot_currency_id, 80 * UNIT);
+
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(AssetBuyouts::<Test>::get(user, dot_currency_id), (50 * UNIT, current_block));
+
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::AssetBuyoutLimitExceeded
+		);
+
+		// The asset buyouts are reset in the next period
+		let buyout_period: u32 = BuyoutPeriod::get();
+		run_to_block((current_block + buyout_period).into());
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(
+			AssetBuyouts::<Test>::get(user, dot_currency_id),
+			(50 * UNIT, current_block + buyout_period)
+		);
+	});
+}
+
+#[test]
+fn attempt_buyout_after_global_buyout_limit_exceeded_fails() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_amount = 50 * UNIT;
+		let current_block = frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
+
+		GlobalBuyoutLimit::<Test>::put(120 * UNIT);
+		// Buyouts of other users in the current period
+		GlobalBuyouts::<Test>::put((60 * UNIT, current_block));
+
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(GlobalBuyouts::<Test>::get(), (110 * UNIT, current_block));
+		assert_eq!(
+			crate::Pallet::<Test>::get_buyout_allowance(&user),
+			Some(BuyoutAllowance { remaining: 10 * UNIT, period_end: BuyoutPeriod::get() })
+		);
+
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::GlobalBuyoutLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn buyouts_before_setting_limits_count_towards_limits() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_amount = 50 * UNIT;
+		let current_block = frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
+
+		// Buyout without any limits set
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(Buyouts::<Test>::get(user), (buyout_amount, current_block));
+		assert_eq!(
+			AssetBuyouts::<Test>::get(user, dot_currency_id),
+			(buyout_amount, current_block)
+		);
+		assert_eq!(GlobalBuyouts::<Test>::get(), (buyout_amount, current_block));
+
+		// Limits set within the same period account for the previous buyout
+		BuyoutLimit::<Test>::put(80 * UNIT);
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::BuyoutLimitExceeded
+		);
+		BuyoutLimit::<Test>::kill();
+
+		AssetBuyoutLimits::<Test>::insert(dot_currency_id, 80 * UNIT);
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::AssetBuyoutLimitExceeded
+		);
+		AssetBuyoutLimits::<Test>::remove(dot_currency_id);
+
+		GlobalBuyoutLimit::<Test>::put(80 * UNIT);
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::GlobalBuyoutLimitExceeded
+		);
+	});
+}
+
 #[test]
 fn attempt_buyout_with_insufficient_user_balance_fails() {
 	run_test(|| {
@@ -456,6 +679,7 @@ fn attempt_buyout_with_insufficient_user_balance_fails() {
 				RuntimeOrigin::signed(user),
 				dot_currency_id,
 				Amount::Buyout(buyout_amount),
+				None,
 			),
 			Error::<Test>::InsufficientAccountBalance
 		);
@@ -485,97 +709,990 @@ fn attempt_buyout_with_insufficient_treasury_balance_fails() {
 				RuntimeOrigin::signed(user),
 				dot_currency_id,
 				Amount::Buyout(buyout_amount),
+				None,
 			),
 			Error::<Test>::InsufficientTreasuryBalance
 		);
 	});
 }
 
-mod signed_extension {
-	use frame_support::{dispatch::DispatchInfo, weights::Weight};
-	use sp_runtime::traits::SignedExtension;
-
-	use crate::CheckBuyout;
+#[test]
+fn buyout_quote_matches_buyout() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let native_currency_id = GetNativeCurrencyId::get();
+		let buyout_amount = 50 * UNIT;
 
-	use super::*;
+		let quote =
+			crate::Pallet::<Test>::get_buyout_quote(dot_currency_id, Amount::Buyout(buyout_amount))
+				.expect("DOT is allowed for buyout");
+		assert_eq!(quote.buyout_amount, buyout_amount);
 
-	pub fn info_from_weight(w: Weight) -> DispatchInfo {
-		DispatchInfo { weight: w, ..Default::default() }
-	}
+		// The fee is charged on top of the exchange amount at the oracle prices
+		let exchange_amount_without_fee = crate::Pallet::<Test>::convert_amount(
+			buyout_amount,
+			OracleMock::get_price::<FixedU128>(native_currency_id).unwrap(),
+			OracleMock::get_price::<FixedU128>(dot_currency_id).unwrap(),
+			<DecimalsLookupImpl as DecimalsLookup>::decimals(native_currency_id),
+			<DecimalsLookupImpl as DecimalsLookup>::decimals(dot_currency_id),
+		)
+		.expect("This is mocked so it should not fail");
+		assert!(quote.fee > 0);
+		assert_eq!(quote.exchange_amount, exchange_amount_without_fee + quote.fee);
 
-	#[test]
-	fn validate_skip_other_calls_succeeds() {
-		run_test(|| {
-			let buyout_call =
-				RuntimeCall::TreasuryBuyoutExtension(crate::Call::update_buyout_limit {
-					limit: None,
-				});
+		let initial_user_dot_balance = get_free_balance(dot_currency_id, &user);
+		let initial_user_native_balance = get_free_balance(native_currency_id, &user);
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(
+			get_free_balance(dot_currency_id, &user),
+			initial_user_dot_balance - quote.exchange_amount
+		);
+		assert_eq!(
+			get_free_balance(native_currency_id, &user),
+			initial_user_native_balance + quote.buyout_amount
+		);
 
-			let check = CheckBuyout::<Test>::new();
-			let info = info_from_weight(Weight::zero());
-			assert_ok!(check.validate(&1, &buyout_call, &info, 0));
-		});
-	}
+		// DOT has 10 decimals
+		let exchange_amount = 100_0000000000;
+		let quote = crate::Pallet::<Test>::get_buyout_quote(
+			dot_currency_id,
+			Amount::Exchange(exchange_amount),
+		)
+		.expect("DOT is allowed for buyout");
+		assert_eq!(quote.exchange_amount, exchange_amount);
 
-	#[test]
-	fn validate_when_wrong_asset_fails() {
-		run_test(|| {
-			let user = USER;
+		let initial_user_native_balance = get_free_balance(native_currency_id, &user);
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Exchange(exchange_amount),
+			None,
+		));
+		assert_eq!(
+			get_free_balance(native_currency_id, &user),
+			initial_user_native_balance + quote.buyout_amount
+		);
 
-			// Some unsupported assets for buyout
-			let native_currency_id = GetNativeCurrencyId::get();
-			let brz_currency_id = 4u64;
+		assert_err!(
+			crate::Pallet::<Test>::get_buyout_quote(3u64, Amount::Buyout(buyout_amount)),
+			Error::<Test>::WrongAssetToBuyout
+		);
+	});
+}
 
-			// Call with unsupported asset
-			for asset in [native_currency_id, brz_currency_id] {
-				let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
-					asset,
-					amount: Amount::Buyout(100 * UNIT),
-				});
+#[test]
+fn buyout_allowance_respects_buyout_period() {
+	run_test(|| {
+		let user = USER;
+		let buyout_period: u32 = BuyoutPeriod::get();
+		let current_block = frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
 
-				let check = CheckBuyout::<Test>::new();
-				let info = info_from_weight(Weight::zero());
+		// Buyouts are not limited
+		assert_eq!(crate::Pallet::<Test>::get_buyout_allowance(&user), None);
 
-				assert_err!(
-					check.validate(&user, &buyout_call, &info, 1),
-					TransactionValidityError::Invalid(InvalidTransaction::Custom(
-						ValidityError::WrongAssetToBuyout.into()
-					))
-				);
-			}
-		});
-	}
+		BuyoutLimit::<Test>::put(200 * UNIT);
+		Buyouts::<Test>::insert(user, (150 * UNIT, current_block));
+		assert_eq!(
+			crate::Pallet::<Test>::get_buyout_allowance(&user),
+			Some(BuyoutAllowance { remaining: 50 * UNIT, period_end: buyout_period })
+		);
 
-	#[test]
-	fn validate_when_no_price_found_fails() {
-		run_test(|| {
-			let user = USER;
-			// For currency id 2u64 there is no price defined in the mock in order to test this case
-			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
-				asset: 2u64,
-				amount: Amount::Buyout(100 * UNIT),
-			});
+		// The allowance is reset in the next period
+		run_to_block((buyout_period + 1).into());
+		assert_eq!(
+			crate::Pallet::<Test>::get_buyout_allowance(&user),
+			Some(BuyoutAllowance { remaining: 200 * UNIT, period_end: 2 * buyout_period })
+		);
+	});
+}
 
-			let check = CheckBuyout::<Test>::new();
-			let info = info_from_weight(Weight::zero());
+#[test]
+fn get_allowed_currencies_returns_genesis_currencies() {
+	run_test(|| {
+		let dot_currency_id = RelayChainCurrencyId::get();
 
-			assert_err!(
-				check.validate(&user, &buyout_call, &info, 1),
-				TransactionValidityError::Invalid(InvalidTransaction::Custom(
-					ValidityError::Math.into()
-				))
-			);
-		});
-	}
+		let mut allowed_currencies = crate::Pallet::<Test>::get_allowed_currencies();
+		allowed_currencies.sort();
+		let mut expected_allowed_currencies = vec![dot_currency_id, 1, 2, 6];
+		expected_allowed_currencies.sort();
+		assert_eq!(allowed_currencies, expected_allowed_currencies);
+	});
+}
 
-	#[test]
-	fn validate_when_not_enough_to_buyout_fails() {
-		run_test(|| {
-			let user = USER;
+#[test]
+fn buyout_with_slippage_limit_after_price_change() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let native_currency_id = GetNativeCurrencyId::get();
+		let buyout_amount = 50 * UNIT;
+		// DOT has 10 decimals
+		let exchange_amount = 50_0000000000;
+
+		let buyout_quote =
+			crate::Pallet::<Test>::get_buyout_quote(dot_currency_id, Amount::Buyout(buyout_amount))
+				.expect("DOT is allowed for buyout");
+		let exchange_quote = crate::Pallet::<Test>::get_buyout_quote(
+			dot_currency_id,
+			Amount::Exchange(exchange_amount),
+		)
+		.expect("DOT is allowed for buyout");
+
+		// The native token becomes more expensive before the buyouts are executed
+		set_price(native_currency_id, FixedU128::saturating_from_rational(11, 10));
+
+		// Caller would pay more than the max paid amount
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				Some(buyout_quote.exchange_amount),
+			),
+			Error::<Test>::SlippageLimitExceeded
+		);
+		// Caller would receive less than the min received amount
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Exchange(exchange_amount),
+				Some(exchange_quote.buyout_amount),
+			),
+			Error::<Test>::SlippageLimitExceeded
+		);
+
+		// The buyouts succeed with a tolerance of 20%
+		let initial_user_dot_balance = get_free_balance(dot_currency_id, &user);
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			Some(buyout_quote.exchange_amount * 12 / 10),
+		));
+		assert!(
+			initial_user_dot_balance - get_free_balance(dot_currency_id, &user) >
+				buyout_quote.exchange_amount
+		);
+
+		let initial_user_native_balance = get_free_balance(native_currency_id, &user);
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Exchange(exchange_amount),
+			Some(exchange_quote.buyout_amount * 8 / 10),
+		));
+		assert!(
+			get_free_balance(native_currency_id, &user) - initial_user_native_balance <
+				exchange_quote.buyout_amount
+		);
+	});
+}
+
+#[test]
+fn attempt_buyout_with_stale_price_fails() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+
+		set_stale_price(dot_currency_id);
+
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(10 * UNIT),
+				None,
+			),
+			Error::<Test>::StalePrice
+		);
+	});
+}
+
+#[test]
+fn attempt_buyout_with_price_deviation_too_high_fails() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_amount = 10 * UNIT;
+		let current_block = frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
+
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(
+			LastAcceptedPrices::<Test>::get(dot_currency_id),
+			Some((FixedU128::one(), current_block))
+		);
+
+		// The price of DOT rises by 20% which is more than `MaxPriceDeviation`
+		set_price(dot_currency_id, FixedU128::saturating_from_rational(12, 10));
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::PriceDeviationTooHigh
+		);
+
+		// A price change within `MaxPriceDeviation` is accepted
+		set_price(dot_currency_id, FixedU128::saturating_from_rational(105, 100));
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(
+			LastAcceptedPrices::<Test>::get(dot_currency_id),
+			Some((FixedU128::saturating_from_rational(105, 100), current_block))
+		);
+
+		// Prices accepted in previous periods are still used as reference
+		let buyout_period: u32 = BuyoutPeriod::get();
+		run_to_block((current_block + buyout_period).into());
+		set_price(dot_currency_id, FixedU128::saturating_from_rational(15, 10));
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::PriceDeviationTooHigh
+		);
+
+		// Prices older than `MaxReferencePriceAge` are not used as reference
+		let max_reference_price_age: u32 = MaxReferencePriceAge::get();
+		run_to_block((current_block + max_reference_price_age + 1).into());
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+	});
+}
+
+#[test]
+fn root_refresh_reference_price_succeeds() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_amount = 10 * UNIT;
+		let current_block = frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
+
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+
+		// The price of DOT rises by 50% which is more than `MaxPriceDeviation`
+		let price = FixedU128::saturating_from_rational(15, 10);
+		set_price(dot_currency_id, price);
+		assert_noop!(
+			crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			),
+			Error::<Test>::PriceDeviationTooHigh
+		);
+
+		run_to_block((current_block + 1).into());
+		assert_ok!(crate::Pallet::<Test>::refresh_reference_price(
+			RuntimeOrigin::root(),
+			dot_currency_id
+		));
+		assert_eq!(
+			LastAcceptedPrices::<Test>::get(dot_currency_id),
+			Some((price, current_block + 1))
+		);
+		System::assert_last_event(
+			crate::Event::ReferencePriceRefreshed { asset: dot_currency_id, price }.into(),
+		);
+
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+
+		// The reference price is refreshed from the oracle, so stale prices are rejected
+		set_stale_price(dot_currency_id);
+		assert_noop!(
+			crate::Pallet::<Test>::refresh_reference_price(RuntimeOrigin::root(), dot_currency_id),
+			Error::<Test>::StalePrice
+		);
+	});
+}
+
+#[test]
+fn user_refresh_reference_price_fails() {
+	run_test(|| {
+		let user = USER;
+
+		assert_noop!(
+			crate::Pallet::<Test>::refresh_reference_price(
+				RuntimeOrigin::signed(user),
+				RelayChainCurrencyId::get()
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn root_update_sell_limits_and_allowed_sell_assets_succeeds() {
+	run_test(|| {
+		assert_ok!(crate::Pallet::<Test>::update_sell_limit(
+			RuntimeOrigin::root(),
+			Some(100 * UNIT)
+		));
+		assert_eq!(SellLimit::<Test>::get(), Some(100 * UNIT));
+		System::assert_last_event(
+			crate::Event::SellLimitUpdated { limit: Some(100 * UNIT) }.into(),
+		);
+
+		assert_ok!(crate::Pallet::<Test>::update_global_sell_limit(
+			RuntimeOrigin::root(),
+			Some(500 * UNIT),
+		));
+		assert_eq!(GlobalSellLimit::<Test>::get(), Some(500 * UNIT));
+		System::assert_last_event(
+			crate::Event::GlobalSellLimitUpdated { limit: Some(500 * UNIT) }.into(),
+		);
+
+		// Duplicates are ignored
+		assert_ok!(crate::Pallet::<Test>::update_allowed_sell_assets(
+			RuntimeOrigin::root(),
+			vec![1u64, 6u64, 1u64],
+		));
+		assert_eq!(crate::AllowedSellCurrencies::<Test>::iter_keys().count(), 2);
+		System::assert_last_event(
+			crate::Event::AllowedAssetsForSellUpdated { allowed_assets: vec![1u64, 6u64] }.into(),
+		);
+
+		assert_noop!(
+			crate::Pallet::<Test>::update_allowed_sell_assets(
+				RuntimeOrigin::root(),
+				vec![GetNativeCurrencyId::get()],
+			),
+			Error::<Test>::NativeTokenNotAllowed
+		);
+	});
+}
+
+#[test]
+fn user_update_sell_limits_and_allowed_sell_assets_fails() {
+	run_test(|| {
+		let user = USER;
+
+		assert_noop!(
+			crate::Pallet::<Test>::update_sell_limit(RuntimeOrigin::signed(user), Some(100 * UNIT)),
+			BadOrigin
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::update_global_sell_limit(
+				RuntimeOrigin::signed(user),
+				Some(100 * UNIT),
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::update_allowed_sell_assets(
+				RuntimeOrigin::signed(user),
+				vec![1u64]
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sell_given_sell_amount_succeeds() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let native_currency_id = GetNativeCurrencyId::get();
+		// DOT has 10 decimals
+		deposit(dot_currency_id, &TreasuryAccount::get(), 100_0000000000);
+
+		let initial_user_native_balance = get_free_balance(native_currency_id, &user);
+		let initial_user_dot_balance = get_free_balance(dot_currency_id, &user);
+		let initial_treasury_native_balance =
+			get_free_balance(native_currency_id, &TreasuryAccount::get());
+
+		let sell_amount = 10 * UNIT;
+		// The treasury buys the native token at its price minus `BuyFee` of 1%
+		let exchange_amount = 9_9000000000;
+
+		// Caller would receive less than the min received amount
+		assert_noop!(
+			crate::Pallet::<Test>::sell(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(sell_amount),
+				Some(exchange_amount + 1),
+			),
+			Error::<Test>::SlippageLimitExceeded
+		);
+
+		assert_ok!(crate::Pallet::<Test>::sell(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(sell_amount),
+			Some(exchange_amount),
+		));
+
+		assert_eq!(
+			get_free_balance(native_currency_id, &user),
+			initial_user_native_balance - sell_amount
+		);
+		assert_eq!(
+			get_free_balance(dot_currency_id, &user),
+			initial_user_dot_balance + exchange_amount
+		);
+		assert_eq!(
+			get_free_balance(native_currency_id, &TreasuryAccount::get()),
+			initial_treasury_native_balance + sell_amount
+		);
+		System::assert_last_event(
+			crate::Event::Sell { who: user, sell_amount, asset: dot_currency_id, exchange_amount }
+				.into(),
+		);
+		// Sells are not recorded in the buyout history
+		assert!(crate::Pallet::<Test>::get_buyout_history().is_empty());
+	});
+}
+
+#[test]
+fn sell_given_exchange_amount_succeeds() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let native_currency_id = GetNativeCurrencyId::get();
+		deposit(dot_currency_id, &TreasuryAccount::get(), 100_0000000000);
+
+		let initial_user_native_balance = get_free_balance(native_currency_id, &user);
+		let exchange_amount = 9_9000000000;
+		let sell_amount = 10 * UNIT;
+
+		// Caller would sell more than the max sold amount
+		assert_noop!(
+			crate::Pallet::<Test>::sell(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Exchange(exchange_amount),
+				Some(sell_amount - 1),
+			),
+			Error::<Test>::SlippageLimitExceeded
+		);
+
+		assert_ok!(crate::Pallet::<Test>::sell(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Exchange(exchange_amount),
+			Some(sell_amount),
+		));
+
+		assert_eq!(
+			get_free_balance(native_currency_id, &user),
+			initial_user_native_balance - sell_amount
+		);
+		System::assert_last_event(
+			crate::Event::Sell { who: user, sell_amount, asset: dot_currency_id, exchange_amount }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn attempt_sell_with_wrong_currency_fails() {
+	run_test(|| {
+		let user = USER;
+
+		// Currency is only allowed for buyouts
+		assert_noop!(
+			crate::Pallet::<Test>::sell(
+				RuntimeOrigin::signed(user),
+				1u64,
+				Amount::Buyout(10 * UNIT),
+				None,
+			),
+			Error::<Test>::WrongAssetToSell
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::sell(
+				RuntimeOrigin::signed(user),
+				GetNativeCurrencyId::get(),
+				Amount::Buyout(10 * UNIT),
+				None,
+			),
+			Error::<Test>::WrongAssetToSell
+		);
+	});
+}
+
+#[test]
+fn attempt_sell_with_insufficient_treasury_balance_fails() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+
+		assert_noop!(
+			crate::Pallet::<Test>::sell(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(10 * UNIT),
+				None,
+			),
+			Error::<Test>::InsufficientTreasuryBalance
+		);
+	});
+}
+
+#[test]
+fn attempt_sell_after_sell_limits_exceeded_fails() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let sell_amount = 10 * UNIT;
+		let current_block = frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
+		deposit(dot_currency_id, &TreasuryAccount::get(), 100_0000000000);
+
+		SellLimit::<Test>::put(15 * UNIT);
+		GlobalSellLimit::<Test>::put(25 * UNIT);
+
+		assert_ok!(crate::Pallet::<Test>::sell(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(sell_amount),
+			None,
+		));
+		assert_eq!(Sells::<Test>::get(user), (sell_amount, current_block));
+		assert_eq!(GlobalSells::<Test>::get(), (sell_amount, current_block));
+
+		assert_noop!(
+			crate::Pallet::<Test>::sell(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(sell_amount),
+				None,
+			),
+			Error::<Test>::SellLimitExceeded
+		);
+
+		// Sells of other users in the current period
+		GlobalSells::<Test>::put((20 * UNIT, current_block));
+		SellLimit::<Test>::kill();
+		assert_noop!(
+			crate::Pallet::<Test>::sell(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(sell_amount),
+				None,
+			),
+			Error::<Test>::GlobalSellLimitExceeded
+		);
+
+		// The sells are reset in the next period
+		let buyout_period: u32 = BuyoutPeriod::get();
+		run_to_block((current_block + buyout_period).into());
+		assert_ok!(crate::Pallet::<Test>::sell(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(sell_amount),
+			None,
+		));
+		assert_eq!(GlobalSells::<Test>::get(), (sell_amount, current_block + buyout_period));
+	});
+}
+
+#[test]
+fn fee_curve_increases_linearly_up_to_max_fee() {
+	let curve = FeeCurve {
+		min_fee: Permill::from_percent(1),
+		max_fee: Permill::from_percent(11),
+		max_fee_share: Permill::from_percent(10),
+	};
+
+	assert_eq!(curve.fee(Permill::zero()), Permill::from_percent(1));
+	assert_eq!(curve.fee(Permill::from_percent(5)), Permill::from_percent(6));
+	assert_eq!(curve.fee(Permill::from_percent(10)), Permill::from_percent(11));
+	assert_eq!(curve.fee(Permill::from_percent(50)), Permill::from_percent(11));
+}
+
+#[test]
+fn root_update_buyout_fee_curve_succeeds() {
+	run_test(|| {
+		let curve = FeeCurve {
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(11),
+			max_fee_share: Permill::from_percent(10),
+		};
+
+		assert_ok!(crate::Pallet::<Test>::update_buyout_fee_curve(
+			RuntimeOrigin::root(),
+			Some(curve)
+		));
+		assert_eq!(BuyoutFeeCurve::<Test>::get(), Some(curve));
+		System::assert_last_event(
+			crate::Event::BuyoutFeeCurveUpdated { curve: Some(curve) }.into(),
+		);
+
+		assert_noop!(
+			crate::Pallet::<Test>::update_buyout_fee_curve(
+				RuntimeOrigin::root(),
+				Some(FeeCurve { min_fee: Permill::from_percent(12), ..curve })
+			),
+			Error::<Test>::InvalidFeeCurve
+		);
+
+		assert_ok!(crate::Pallet::<Test>::update_buyout_fee_curve(RuntimeOrigin::root(), None));
+		assert_eq!(BuyoutFeeCurve::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn user_update_buyout_fee_curve_fails() {
+	run_test(|| {
+		let user = USER;
+
+		assert_noop!(
+			crate::Pallet::<Test>::update_buyout_fee_curve(RuntimeOrigin::signed(user), None),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn buyout_fee_follows_fee_curve() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_amount = 50 * UNIT;
+
+		BuyoutFeeCurve::<Test>::put(FeeCurve {
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(11),
+			max_fee_share: Permill::from_percent(10),
+		});
+
+		// The fee increases from 1% to 6% while the first 5% of the treasury's native balance is sold
+		let initial_user_dot_balance = get_free_balance(dot_currency_id, &user);
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		// DOT has 10 decimals
+		assert_eq!(
+			initial_user_dot_balance - get_free_balance(dot_currency_id, &user),
+			51_7500000000
+		);
+
+		// The fee increases from 6% to 11% while the next 5% are sold
+		let quote =
+			crate::Pallet::<Test>::get_buyout_quote(dot_currency_id, Amount::Buyout(buyout_amount))
+				.expect("DOT is allowed for buyout");
+		assert_eq!(quote.exchange_amount, 54_2500000000);
+		assert_eq!(quote.fee, 4_2500000000);
+
+		let initial_user_dot_balance = get_free_balance(dot_currency_id, &user);
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(buyout_amount),
+			None,
+		));
+		assert_eq!(
+			initial_user_dot_balance - get_free_balance(dot_currency_id, &user),
+			quote.exchange_amount
+		);
+
+		// 10% of the treasury's native balance was sold, so the max fee applies
+		let quote =
+			crate::Pallet::<Test>::get_buyout_quote(dot_currency_id, Amount::Buyout(buyout_amount))
+				.expect("DOT is allowed for buyout");
+		assert_eq!(quote.fee, 5_5000000000);
+
+		// The fee is reset in the next period
+		let buyout_period: u32 = BuyoutPeriod::get();
+		run_to_block((1 + buyout_period).into());
+		let quote =
+			crate::Pallet::<Test>::get_buyout_quote(dot_currency_id, Amount::Buyout(buyout_amount))
+				.expect("DOT is allowed for buyout");
+		assert_eq!(quote.fee, 1_7500000000);
+	});
+}
+
+#[test]
+fn sell_does_not_lower_the_buyout_fee() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_amount = 50 * UNIT;
+		// DOT has 10 decimals
+		deposit(dot_currency_id, &TreasuryAccount::get(), 100_0000000000);
+
+		BuyoutFeeCurve::<Test>::put(FeeCurve {
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(11),
+			max_fee_share: Permill::from_percent(10),
+		});
+
+		let quote =
+			crate::Pallet::<Test>::get_buyout_quote(dot_currency_id, Amount::Buyout(buyout_amount))
+				.expect("DOT is allowed for buyout");
+
+		// The native tokens bought back by the treasury do not count towards its balance at the start of the period
+		assert_ok!(crate::Pallet::<Test>::sell(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(100 * UNIT),
+			None,
+		));
+		assert_eq!(
+			crate::Pallet::<Test>::get_buyout_quote(dot_currency_id, Amount::Buyout(buyout_amount)),
+			Ok(quote)
+		);
+	});
+}
+
+#[test]
+fn splitting_a_buyout_does_not_lower_the_fee() {
+	let dot_currency_id = RelayChainCurrencyId::get();
+	let curve = FeeCurve {
+		min_fee: Permill::from_percent(1),
+		max_fee: Permill::from_percent(11),
+		max_fee_share: Permill::from_percent(10),
+	};
+
+	let paid_for_buyouts = |buyout_amounts: &[Balance]| {
+		let mut paid = 0;
+		run_test(|| {
+			BuyoutFeeCurve::<Test>::put(curve);
+
+			let initial_user_dot_balance = get_free_balance(dot_currency_id, &USER);
+			for buyout_amount in buyout_amounts {
+				assert_ok!(crate::Pallet::<Test>::buyout(
+					RuntimeOrigin::signed(USER),
+					dot_currency_id,
+					Amount::Buyout(*buyout_amount),
+					None,
+				));
+			}
+			paid = initial_user_dot_balance - get_free_balance(dot_currency_id, &USER);
+		});
+		paid
+	};
+
+	// DOT has 10 decimals
+	assert_eq!(paid_for_buyouts(&[100 * UNIT]), 106_0000000000);
+	assert_eq!(paid_for_buyouts(&[50 * UNIT, 50 * UNIT]), 106_0000000000);
+	assert_eq!(paid_for_buyouts(&[25 * UNIT; 4]), 106_0000000000);
+
+	// Beyond `max_fee_share` the max fee applies
+	assert_eq!(paid_for_buyouts(&[150 * UNIT]), 161_5000000000);
+	assert_eq!(paid_for_buyouts(&[50 * UNIT; 3]), 161_5000000000);
+
+	// Buyouts given the exchange amount follow the same fee
+	run_test(|| {
+		BuyoutFeeCurve::<Test>::put(curve);
+
+		let quote = crate::Pallet::<Test>::get_buyout_quote(
+			dot_currency_id,
+			Amount::Exchange(106_0000000000),
+		)
+		.expect("DOT is allowed for buyout");
+		// DOT has 2 decimals less than the native token, so the last 2 digits are rounded
+		assert!(quote.buyout_amount >= 100 * UNIT && quote.buyout_amount < 100 * UNIT + 100);
+	});
+}
+
+#[test]
+fn buyout_history_records_buyouts_per_period() {
+	run_test(|| {
+		let user = USER;
+		let dot_currency_id = RelayChainCurrencyId::get();
+		let buyout_period: u32 = BuyoutPeriod::get();
+		// DOT has 10 decimals
+		deposit(dot_currency_id, &user, 500_0000000000);
+
+		for buyout_amount in [100 * UNIT, 110 * UNIT, 120 * UNIT, 130 * UNIT] {
+			assert_ok!(crate::Pallet::<Test>::buyout(
+				RuntimeOrigin::signed(user),
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+				None,
+			));
+		}
+
+		let history = crate::Pallet::<Test>::get_buyout_history();
+		assert_eq!(history.len(), 1);
+		let report = &history[0];
+		assert_eq!(report.period_start, 0);
+		assert_eq!(report.buyouts, 4);
+		assert_eq!(report.total_fees, 4_600_000_000_000);
+		assert_eq!(
+			report.assets,
+			vec![(
+				dot_currency_id,
+				AssetBuyoutSummary {
+					buyouts: 4,
+					buyout_amount: 460 * UNIT,
+					exchange_amount: 464_6000000000,
+					fees: 4_6000000000,
+				}
+			)]
+		);
+		// Only the most recent buyouts of the period are kept, oldest first
+		let recorded_amounts: Vec<Balance> =
+			report.records.iter().map(|record| record.buyout_amount).collect();
+		assert_eq!(recorded_amounts, vec![110 * UNIT, 120 * UNIT, 130 * UNIT]);
+		let record = &report.records[2];
+		assert_eq!(record.who, user);
+		assert_eq!(record.asset, dot_currency_id);
+		assert_eq!(record.exchange_amount, 131_3000000000);
+		assert_eq!(record.fee, 1_3000000000);
+		assert_eq!(record.block, 1);
+
+		run_to_block((1 + buyout_period).into());
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(100 * UNIT),
+			None,
+		));
+		let history = crate::Pallet::<Test>::get_buyout_history();
+		let period_starts: Vec<u32> = history.iter().map(|report| report.period_start).collect();
+		assert_eq!(period_starts, vec![0, buyout_period]);
+
+		// The oldest period is dropped once more than `MaxBuyoutHistoryPeriods` periods are recorded
+		run_to_block((1 + 2 * buyout_period).into());
+		assert_ok!(crate::Pallet::<Test>::buyout(
+			RuntimeOrigin::signed(user),
+			dot_currency_id,
+			Amount::Buyout(100 * UNIT),
+			None,
+		));
+		let history = crate::Pallet::<Test>::get_buyout_history();
+		let period_starts: Vec<u32> = history.iter().map(|report| report.period_start).collect();
+		assert_eq!(period_starts, vec![buyout_period, 2 * buyout_period]);
+		let report = &history[1];
+		assert_eq!(report.buyouts, 1);
+		assert_eq!(report.records.len(), 1);
+		assert_eq!(report.total_fees, UNIT);
+		assert_eq!(report.assets.len(), 1);
+		assert_eq!(report.assets[0].1.buyouts, 1);
+	});
+}
+
+mod signed_extension {
+	use frame_support::{dispatch::DispatchInfo, weights::Weight};
+	use sp_runtime::traits::SignedExtension;
+
+	use crate::CheckBuyout;
+
+	use super::*;
+
+	pub fn info_from_weight(w: Weight) -> DispatchInfo {
+		DispatchInfo { weight: w, ..Default::default() }
+	}
+
+	#[test]
+	fn validate_skip_other_calls_succeeds() {
+		run_test(|| {
+			let buyout_call =
+				RuntimeCall::TreasuryBuyoutExtension(crate::Call::update_buyout_limit {
+					limit: None,
+				});
+
+			let check = CheckBuyout::<Test>::new();
+			let info = info_from_weight(Weight::zero());
+			assert_ok!(check.validate(&1, &buyout_call, &info, 0));
+		});
+	}
+
+	#[test]
+	fn validate_when_wrong_asset_fails() {
+		run_test(|| {
+			let user = USER;
+
+			// Some unsupported assets for buyout
+			let native_currency_id = GetNativeCurrencyId::get();
+			let brz_currency_id = 4u64;
+
+			// Call with unsupported asset
+			for asset in [native_currency_id, brz_currency_id] {
+				let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
+					asset,
+					amount: Amount::Buyout(100 * UNIT),
+					slippage_limit: None,
+				});
+
+				let check = CheckBuyout::<Test>::new();
+				let info = info_from_weight(Weight::zero());
+
+				assert_err!(
+					check.validate(&user, &buyout_call, &info, 1),
+					TransactionValidityError::Invalid(InvalidTransaction::Custom(
+						ValidityError::WrongAssetToBuyout.into()
+					))
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn validate_when_no_price_found_fails() {
+		run_test(|| {
+			let user = USER;
+			// For currency id 2u64 there is no price defined in the mock in order to test this case
+			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
+				asset: 2u64,
+				amount: Amount::Buyout(100 * UNIT),
+				slippage_limit: None,
+			});
+
+			let check = CheckBuyout::<Test>::new();
+			let info = info_from_weight(Weight::zero());
+
+			assert_err!(
+				check.validate(&user, &buyout_call, &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::Math.into()
+				))
+			);
+		});
+	}
+
+	#[test]
+	fn validate_when_not_enough_to_buyout_fails() {
+		run_test(|| {
+			let user = USER;
 			let dot_currency_id = RelayChainCurrencyId::get();
 			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
 				asset: dot_currency_id,
 				amount: Amount::Buyout(1000 * UNIT),
+				slippage_limit: None,
 			});
 
 			let check = CheckBuyout::<Test>::new();
@@ -599,6 +1716,7 @@ mod signed_extension {
 			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
 				asset: dot_currency_id,
 				amount: Amount::Buyout(100 * UNIT),
+				slippage_limit: None,
 			});
 
 			let current_block =
@@ -630,6 +1748,7 @@ mod signed_extension {
 			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
 				asset: dot_currency_id,
 				amount: Amount::Buyout(10 * UNIT),
+				slippage_limit: None,
 			});
 
 			let check = CheckBuyout::<Test>::new();
@@ -653,6 +1772,7 @@ mod signed_extension {
 			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
 				asset: dot_currency_id,
 				amount: Amount::Buyout(100 * UNIT),
+				slippage_limit: None,
 			});
 
 			let check = CheckBuyout::<Test>::new();
@@ -661,4 +1781,169 @@ mod signed_extension {
 			assert_ok!(check.validate(&user, &buyout_call, &info, 1));
 		});
 	}
+
+	#[test]
+	fn validate_when_slippage_limit_exceeded_fails() {
+		run_test(|| {
+			let user = USER;
+			let dot_currency_id = RelayChainCurrencyId::get();
+			let native_currency_id = GetNativeCurrencyId::get();
+			let buyout_amount = 100 * UNIT;
+
+			let quote = crate::Pallet::<Test>::get_buyout_quote(
+				dot_currency_id,
+				Amount::Buyout(buyout_amount),
+			)
+			.expect("DOT is allowed for buyout");
+			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
+				asset: dot_currency_id,
+				amount: Amount::Buyout(buyout_amount),
+				slippage_limit: Some(quote.exchange_amount),
+			});
+
+			let check = CheckBuyout::<Test>::new();
+			let info = info_from_weight(Weight::zero());
+			assert_ok!(check.validate(&user, &buyout_call, &info, 1));
+
+			// The price of DOT drops before the transaction is included
+			set_price(dot_currency_id, FixedU128::saturating_from_rational(9, 10));
+
+			assert_err!(
+				check.validate(&user, &buyout_call, &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::SlippageLimitExceeded.into()
+				))
+			);
+		});
+	}
+
+	#[test]
+	fn validate_when_asset_or_global_buyout_limit_exceeded_fails() {
+		run_test(|| {
+			let user = USER;
+			let dot_currency_id = RelayChainCurrencyId::get();
+
+			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
+				asset: dot_currency_id,
+				amount: Amount::Buyout(100 * UNIT),
+				slippage_limit: None,
+			});
+
+			let check = CheckBuyout::<Test>::new();
+			let info = info_from_weight(Weight::zero());
+
+			AssetBuyoutLimits::<Test>::insert(dot_currency_id, 50 * UNIT);
+			assert_err!(
+				check.validate(&user, &buyout_call, &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::AssetBuyoutLimitExceeded.into()
+				))
+			);
+
+			AssetBuyoutLimits::<Test>::remove(dot_currency_id);
+			GlobalBuyoutLimit::<Test>::put(50 * UNIT);
+			assert_err!(
+				check.validate(&user, &buyout_call, &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::GlobalBuyoutLimitExceeded.into()
+				))
+			);
+		});
+	}
+
+	#[test]
+	fn validate_when_price_stale_or_deviation_too_high_fails() {
+		run_test(|| {
+			let user = USER;
+			let dot_currency_id = RelayChainCurrencyId::get();
+			let current_block =
+				frame_system::Pallet::<Test>::block_number().saturated_into::<u32>();
+
+			let buyout_call = RuntimeCall::TreasuryBuyoutExtension(crate::Call::buyout {
+				asset: dot_currency_id,
+				amount: Amount::Buyout(100 * UNIT),
+				slippage_limit: None,
+			});
+
+			let check = CheckBuyout::<Test>::new();
+			let info = info_from_weight(Weight::zero());
+
+			LastAcceptedPrices::<Test>::insert(
+				dot_currency_id,
+				(FixedU128::saturating_from_rational(5, 10), current_block),
+			);
+			assert_err!(
+				check.validate(&user, &buyout_call, &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::PriceDeviationTooHigh.into()
+				))
+			);
+
+			set_stale_price(dot_currency_id);
+			assert_err!(
+				check.validate(&user, &buyout_call, &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::StalePrice.into()
+				))
+			);
+		});
+	}
+
+	#[test]
+	fn validate_sell_when_checks_fail() {
+		run_test(|| {
+			let user = USER;
+			let dot_currency_id = RelayChainCurrencyId::get();
+			let sell_call = |asset, sell_amount| {
+				RuntimeCall::TreasuryBuyoutExtension(crate::Call::sell {
+					asset,
+					amount: Amount::Buyout(sell_amount),
+					slippage_limit: None,
+				})
+			};
+
+			let check = CheckBuyout::<Test>::new();
+			let info = info_from_weight(Weight::zero());
+
+			assert_ok!(check.validate(&user, &sell_call(dot_currency_id, 100 * UNIT), &info, 1));
+			// Sells are bound by `MinAmountToSell` instead of `MinAmountToBuyout`
+			assert_ok!(check.validate(&user, &sell_call(dot_currency_id, 50 * UNIT), &info, 1));
+
+			assert_err!(
+				check.validate(&user, &sell_call(1u64, 100 * UNIT), &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::WrongAssetToSell.into()
+				))
+			);
+			assert_err!(
+				check.validate(&user, &sell_call(dot_currency_id, 5 * UNIT), &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::LessThanMinSellAmount.into()
+				))
+			);
+			assert_err!(
+				check.validate(&user, &sell_call(dot_currency_id, 300 * UNIT), &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::NotEnoughToSell.into()
+				))
+			);
+
+			SellLimit::<Test>::put(50 * UNIT);
+			assert_err!(
+				check.validate(&user, &sell_call(dot_currency_id, 100 * UNIT), &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::SellLimitExceeded.into()
+				))
+			);
+
+			SellLimit::<Test>::kill();
+			GlobalSellLimit::<Test>::put(50 * UNIT);
+			assert_err!(
+				check.validate(&user, &sell_call(dot_currency_id, 100 * UNIT), &info, 1),
+				TransactionValidityError::Invalid(InvalidTransaction::Custom(
+					ValidityError::GlobalSellLimitExceeded.into()
+				))
+			);
+		});
+	}
 }
@@ -2,6 +2,13 @@ use crate::Config;
 use codec::{Decode, Encode, MaxEncodedLen};
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, Permill, Rounding, SaturatedConversion,
+};
+
+pub use treasury_buyout_extension_rpc_runtime_api::{
+	Amount, AssetBuyoutSummary, BuyoutAllowance, BuyoutPeriodReport, BuyoutQuote, BuyoutRecord,
+};
 
 #[allow(type_alias_bounds)]
 pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -16,11 +23,80 @@ pub(crate) type CurrencyIdOf<T> =
 pub(crate) type BalanceOf<T: Config> =
 	<<T as Config>::Currency as MultiCurrency<AccountIdOf<T>>>::Balance;
 
-/// Type of amount
+#[allow(type_alias_bounds)]
+pub(crate) type BuyoutRecordOf<T: Config> =
+	BuyoutRecord<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>>;
+
+#[allow(type_alias_bounds)]
+pub(crate) type AssetBuyoutSummaryOf<T: Config> = AssetBuyoutSummary<BalanceOf<T>>;
+
+#[allow(type_alias_bounds)]
+pub(crate) type BuyoutPeriodReportOf<T: Config> =
+	BuyoutPeriodReport<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>>;
+
+/// Parameters of the buyout fee curve set by governance.
+/// The fee increases linearly from `min_fee` to `max_fee` with the share of the treasury's native balance
+/// sold in the current buyout period. A buyout pays the average fee over the share it sells.
 #[derive(Copy, Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
-pub enum Amount<Balance> {
-	/// Amount of native asset user get for buyout
-	Buyout(Balance),
-	/// Amount of exchange asset user give for buyout
-	Exchange(Balance),
+pub struct FeeCurve {
+	/// Fee when nothing was sold in the current buyout period
+	pub min_fee: Permill,
+	/// Fee when at least `max_fee_share` was sold in the current buyout period
+	pub max_fee: Permill,
+	/// Share of the treasury's native balance sold at which the fee reaches `max_fee`
+	pub max_fee_share: Permill,
+}
+
+impl FeeCurve {
+	/// Returns the fee for the given share of the treasury's native balance sold in the current buyout period
+	pub fn fee(&self, sold_share: Permill) -> Permill {
+		if sold_share >= self.max_fee_share {
+			return self.max_fee
+		}
+
+		let progress =
+			Permill::from_rational(sold_share.deconstruct(), self.max_fee_share.deconstruct());
+		let fee_range = self.max_fee.deconstruct().saturating_sub(self.min_fee.deconstruct());
+		Permill::from_parts(
+			self.min_fee.deconstruct().saturating_add(progress.mul_floor(fee_range)),
+		)
+	}
+
+	/// Returns the average fee of buying out `amount` when `sold` of the treasury's native balance `total`
+	/// was already sold in the current buyout period.
+	/// The fee is averaged over the share sold by the buyout itself, so splitting a buyout does not lower its fee.
+	pub fn average_fee(&self, sold: u128, amount: u128, total: u128) -> Permill {
+		if amount == 0 {
+			return self.fee(Permill::from_rational(sold, total))
+		}
+
+		let min_fee = u128::from(self.min_fee.deconstruct());
+		let max_fee = u128::from(self.max_fee.deconstruct());
+		let fee_range = max_fee.saturating_sub(min_fee);
+		// Sold amount at which the fee reaches `max_fee`
+		let max_fee_sold = self.max_fee_share.mul_floor(total);
+		let end = sold.saturating_add(amount);
+
+		// Sum of the fee in parts per million over every unit bought out
+		let mut fee_sum = 0u128;
+		if sold < max_fee_sold {
+			// The fee increases linearly, so its average is the fee in the middle of the range
+			let linear_end = end.min(max_fee_sold);
+			let increase = multiply_by_rational_with_rounding(
+				fee_range,
+				sold.saturating_add(linear_end),
+				max_fee_sold.saturating_mul(2),
+				Rounding::Down,
+			)
+			.unwrap_or(fee_range);
+			fee_sum =
+				linear_end.saturating_sub(sold).saturating_mul(min_fee.saturating_add(increase));
+		}
+		if end > max_fee_sold {
+			fee_sum = fee_sum
+				.saturating_add(end.saturating_sub(sold.max(max_fee_sold)).saturating_mul(max_fee));
+		}
+
+		Permill::from_parts((fee_sum / amount).saturated_into())
+	}
 }
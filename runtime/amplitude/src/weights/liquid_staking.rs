@@ -0,0 +1,138 @@
+
+//! Placeholder weights for liquid_staking
+//!
+//! THESE WEIGHTS WERE ESTIMATED BY HAND AND NOT GENERATED USING THE SUBSTRATE BENCHMARK CLI.
+//! They have to be regenerated by running `benchmark pallet --pallet liquid_staking` with the
+//! `runtime-benchmarks` feature before this pallet is used in production.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for liquid_staking using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> liquid_staking::WeightInfo for SubstrateWeight<T> {
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking TotalRedeeming (r:1 w:0)
+	/// Proof: LiquidStaking TotalRedeeming (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking PendingRedemption (r:1 w:0)
+	/// Proof: LiquidStaking PendingRedemption (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(375), added: 2850, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn deposit() -> Weight {
+		Weight::from_parts(145_000_000, 5255)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking TotalRedeeming (r:1 w:1)
+	/// Proof: LiquidStaking TotalRedeeming (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking CurrentBatch (r:1 w:0)
+	/// Proof: LiquidStaking CurrentBatch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking Redemptions (r:1 w:1)
+	/// Proof: LiquidStaking Redemptions (max_values: None, max_size: Some(701), added: 3176, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking PendingRedemption (r:1 w:1)
+	/// Proof: LiquidStaking PendingRedemption (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn redeem() -> Weight {
+		Weight::from_parts(61_000_000, 4166)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: LiquidStaking Redemptions (r:1 w:1)
+	/// Proof: LiquidStaking Redemptions (max_values: None, max_size: Some(701), added: 3176, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking UnstakedBatches (r:10 w:10)
+	/// Proof: LiquidStaking UnstakedBatches (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(375), added: 2850, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking TotalRedeeming (r:1 w:1)
+	/// Proof: LiquidStaking TotalRedeeming (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn withdraw_unstaked() -> Weight {
+		Weight::from_parts(124_000_000, 26140)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking TotalRedeeming (r:1 w:0)
+	/// Proof: LiquidStaking TotalRedeeming (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(375), added: 2850, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:4 w:4)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Rewards (r:5 w:5)
+	/// Proof: ParachainStaking Rewards (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking RewardCount (r:5 w:5)
+	/// Proof: ParachainStaking RewardCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking AutoCompound (r:5 w:0)
+	/// Proof: ParachainStaking AutoCompound (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Proof: ParachainStaking InflationConfig (max_values: Some(1), max_size: Some(96), added: 591, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:5 w:5)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking PendingRedemption (r:1 w:0)
+	/// Proof: LiquidStaking PendingRedemption (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn compound() -> Weight {
+		Weight::from_parts(315_000_000, 22060)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
+	/// Storage: LiquidStaking CurrentBatch (r:1 w:1)
+	/// Proof: LiquidStaking CurrentBatch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking PendingRedemption (r:1 w:1)
+	/// Proof: LiquidStaking PendingRedemption (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(375), added: 2850, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking TotalRedeeming (r:1 w:0)
+	/// Proof: LiquidStaking TotalRedeeming (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:4 w:4)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking UnstakedBatches (r:0 w:1)
+	/// Proof: LiquidStaking UnstakedBatches (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn process_batch() -> Weight {
+		Weight::from_parts(239_000_000, 18050)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+}
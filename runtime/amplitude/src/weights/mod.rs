@@ -22,6 +22,7 @@ pub mod dia_oracle;
 pub mod extrinsic_weights;
 pub mod fee;
 pub mod issue;
+pub mod liquid_staking;
 pub mod nomination;
 pub mod oracle;
 pub mod orml_asset_registry;
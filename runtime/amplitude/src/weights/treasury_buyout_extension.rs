@@ -52,14 +52,32 @@ impl<T: frame_system::Config> treasury_buyout_extension::WeightInfo for Substrat
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::AssetBuyoutLimits` (r:1 w:0)
+	/// Proof: `TreasuryBuyoutExtension::AssetBuyoutLimits` (`max_values`: None, `max_size`: Some(78), added: 2553, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::AssetBuyouts` (r:1 w:1)
+	/// Proof: `TreasuryBuyoutExtension::AssetBuyouts` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::GlobalBuyoutLimit` (r:1 w:0)
+	/// Proof: `TreasuryBuyoutExtension::GlobalBuyoutLimit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::GlobalBuyouts` (r:1 w:1)
+	/// Proof: `TreasuryBuyoutExtension::GlobalBuyouts` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::LastAcceptedPrices` (r:2 w:2)
+	/// Proof: `TreasuryBuyoutExtension::LastAcceptedPrices` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::BuyoutFeeCurve` (r:1 w:0)
+	/// Proof: `TreasuryBuyoutExtension::BuyoutFeeCurve` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::BuyoutHistoryPeriods` (r:1 w:1)
+	/// Proof: `TreasuryBuyoutExtension::BuyoutHistoryPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::BuyoutHistory` (r:0 w:1)
+	/// Proof: `TreasuryBuyoutExtension::BuyoutHistory` (`max_values`: None, `max_size`: Some(186), added: 2661, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::BuyoutHistorySummaries` (r:1 w:1)
+	/// Proof: `TreasuryBuyoutExtension::BuyoutHistorySummaries` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
 	fn buyout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `944`
 		//  Estimated: `7734`
 		// Minimum execution time: 82_000_000 picoseconds.
 		Weight::from_parts(84_000_000, 7734)
-			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 	}
 	/// Storage: `TreasuryBuyoutExtension::BuyoutLimit` (r:0 w:1)
 	/// Proof: `TreasuryBuyoutExtension::BuyoutLimit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
@@ -85,4 +103,77 @@ impl<T: frame_system::Config> treasury_buyout_extension::WeightInfo for Substrat
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TreasuryBuyoutExtension::AssetBuyoutLimits` (r:0 w:1)
+	/// Proof: `TreasuryBuyoutExtension::AssetBuyoutLimits` (`max_values`: None, `max_size`: Some(78), added: 2553, mode: `MaxEncodedLen`)
+	fn update_asset_buyout_limit() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryBuyoutExtension::GlobalBuyoutLimit` (r:0 w:1)
+	/// Proof: `TreasuryBuyoutExtension::GlobalBuyoutLimit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn update_global_buyout_limit() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryBuyoutExtension::AllowedSellCurrencies` (r:1 w:0)
+	/// Proof: `TreasuryBuyoutExtension::AllowedSellCurrencies` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::SellLimit` (r:1 w:0)
+	/// Proof: `TreasuryBuyoutExtension::SellLimit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::Sells` (r:1 w:1)
+	/// Proof: `TreasuryBuyoutExtension::Sells` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::GlobalSellLimit` (r:1 w:0)
+	/// Proof: `TreasuryBuyoutExtension::GlobalSellLimit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::GlobalSells` (r:1 w:1)
+	/// Proof: `TreasuryBuyoutExtension::GlobalSells` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::LastAcceptedPrices` (r:2 w:2)
+	/// Proof: `TreasuryBuyoutExtension::LastAcceptedPrices` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	fn sell() -> Weight {
+		Weight::from_parts(85_000_000, 8240)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TreasuryBuyoutExtension::SellLimit` (r:0 w:1)
+	/// Proof: `TreasuryBuyoutExtension::SellLimit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn update_sell_limit() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryBuyoutExtension::GlobalSellLimit` (r:0 w:1)
+	/// Proof: `TreasuryBuyoutExtension::GlobalSellLimit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn update_global_sell_limit() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryBuyoutExtension::AllowedSellCurrencies` (r:1 w:1)
+	/// Proof: `TreasuryBuyoutExtension::AllowedSellCurrencies` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn update_allowed_sell_assets(n: u32, ) -> Weight {
+		Weight::from_parts(15_935_593, 3527)
+			.saturating_add(Weight::from_parts(1_123_793, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryBuyoutExtension::BuyoutFeeCurve` (r:0 w:1)
+	/// Proof: `TreasuryBuyoutExtension::BuyoutFeeCurve` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn update_buyout_fee_curve() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DiaOracleModule::CoinInfosMap` (r:1 w:0)
+	/// Proof: `DiaOracleModule::CoinInfosMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryBuyoutExtension::LastAcceptedPrices` (r:0 w:1)
+	/// Proof: `TreasuryBuyoutExtension::LastAcceptedPrices` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	fn refresh_reference_price() -> Weight {
+		Weight::from_parts(20_000_000, 3372)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
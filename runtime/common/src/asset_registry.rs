@@ -1,5 +1,8 @@
 use crate::*;
-use frame_support::traits::AsEnsureOriginWithArg;
+use frame_support::{
+	traits::{AsEnsureOriginWithArg, OnRuntimeUpgrade},
+	weights::Weight,
+};
 use frame_system::EnsureRoot;
 use orml_traits::{
 	asset_registry::{AssetMetadata, AssetProcessor, Inspect},
@@ -59,6 +62,26 @@ impl AssetProcessor<CurrencyId, AssetMetadata<Balance, CustomMetadata, StringLim
 	}
 }
 
+/// The metadata of an asset which is native to the chain and not tracked by the oracle.
+pub fn local_asset_metadata(
+	name: &str,
+	symbol: &str,
+	decimals: u32,
+	existential_deposit: Balance,
+) -> AssetMetadata<Balance, CustomMetadata, StringLimit> {
+	AssetMetadata {
+		decimals,
+		name: BoundedVec::truncate_from(name.as_bytes().to_vec()),
+		symbol: BoundedVec::truncate_from(symbol.as_bytes().to_vec()),
+		existential_deposit,
+		location: None,
+		additional: CustomMetadata {
+			dia_keys: DiaKeys { blockchain: BoundedVec::new(), symbol: BoundedVec::new() },
+			fee_per_second: 0,
+		},
+	}
+}
+
 pub type AssetAuthority = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
 pub struct FixedConversionRateProvider<OrmlAssetRegistry>(PhantomData<OrmlAssetRegistry>);
 
@@ -122,3 +145,36 @@ impl<
 		})
 	}
 }
+
+/// Registers the metadata of an asset in the asset registry on runtime upgrade, unless the
+/// asset is registered already.
+pub struct RegisterAssetMetadata<Runtime, Asset>(PhantomData<(Runtime, Asset)>);
+
+impl<Runtime, Asset> OnRuntimeUpgrade for RegisterAssetMetadata<Runtime, Asset>
+where
+	Runtime: orml_asset_registry::Config<
+		AssetId = CurrencyId,
+		Balance = Balance,
+		CustomMetadata = CustomMetadata,
+		StringLimit = StringLimit,
+	>,
+	Asset: Get<(CurrencyId, AssetMetadata<Balance, CustomMetadata, StringLimit>)>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let (currency_id, metadata) = Asset::get();
+		if orml_asset_registry::Metadata::<Runtime>::contains_key(&currency_id) {
+			return db_weight.reads(1);
+		}
+
+		if orml_asset_registry::Pallet::<Runtime>::do_register_asset_without_asset_processor(
+			metadata,
+			currency_id,
+		)
+		.is_err()
+		{
+			frame_support::defensive!("asset-registry: failed to register asset metadata");
+		}
+		db_weight.reads_writes(2, 2)
+	}
+}
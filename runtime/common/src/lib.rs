@@ -54,6 +54,12 @@ pub const NANOUNIT: Balance = 1_000;
 /// The existential deposit. Set to 1/10 of the Connected Relay Chain.
 pub const EXISTENTIAL_DEPOSIT: Balance = MILLIUNIT;
 
+/// The id of the liquid staking derivative of the native token, see `CurrencyId::Token`.
+///
+/// Taken from the top of the ids which can be traded on Zenlink, so that it does not clash
+/// with tokens created by users.
+pub const LIQUID_STAKING_TOKEN_ID: u64 = 0xff;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -193,6 +199,7 @@ pub struct OraclePriceGetter<Runtime>(PhantomData<Runtime>);
 impl<
 		Runtime: treasury_buyout_extension::Config
 			+ dia_oracle::Config
+			+ pallet_timestamp::Config<Moment = u64>
 			+ orml_asset_registry::Config<AssetId = CurrencyId, CustomMetadata = CustomMetadata>,
 	> PriceGetter<CurrencyId> for OraclePriceGetter<Runtime>
 {
@@ -201,6 +208,8 @@ impl<
 	where
 		FixedNumber: FixedPointNumber + One + Zero + Debug + TryFrom<FixedU128>,
 	{
+		use frame_support::traits::Get;
+
 		let asset_metadata = orml_asset_registry::Pallet::<Runtime>::metadata(currency_id)
 			.ok_or(DispatchError::Other("Asset not found"))?;
 
@@ -210,6 +219,13 @@ impl<
 		if let Ok(asset_info) =
 			<dia_oracle::Pallet<Runtime> as DiaOracle>::get_coin_info(blockchain, symbol)
 		{
+			// Timestamps of the oracle are in seconds, the ones of the timestamp pallet in milliseconds
+			let now = pallet_timestamp::Pallet::<Runtime>::get() / 1000;
+			let max_price_age = <Runtime as treasury_buyout_extension::Config>::MaxPriceAge::get();
+			if now.saturating_sub(asset_info.last_update_timestamp) > max_price_age {
+				return Err(treasury_buyout_extension::Error::<Runtime>::StalePrice.into());
+			}
+
 			let price = FixedNumber::try_from(FixedU128::from_inner(asset_info.price))
 				.map_err(|_| DispatchError::Other("Failed to convert price"))?;
 			return Ok(price);
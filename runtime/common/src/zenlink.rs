@@ -1,4 +1,7 @@
-use crate::stellar::{AUDD_ISSUER, BRL_ISSUER, EURC_ISSUER, NGNC_ISSUER, TZS_ISSUER, USDC_ISSUER};
+use crate::{
+	stellar::{AUDD_ISSUER, BRL_ISSUER, EURC_ISSUER, NGNC_ISSUER, TZS_ISSUER, USDC_ISSUER},
+	LIQUID_STAKING_TOKEN_ID,
+};
 use spacewalk_primitives::{Asset, CurrencyId};
 use zenlink_protocol::{LOCAL, NATIVE};
 pub type ZenlinkAssetId = zenlink_protocol::AssetId;
@@ -9,6 +12,7 @@ fn discriminant(currency: &CurrencyId) -> u8 {
 		CurrencyId::XCM(_) => 1,
 		CurrencyId::Stellar(_) => 2,
 		CurrencyId::ZenlinkLPToken(_, _, _, _) => 3,
+		CurrencyId::Token(LIQUID_STAKING_TOKEN_ID) => 4,
 		_ => 0,
 	}
 }
@@ -71,6 +75,8 @@ pub fn zenlink_id_to_currency_id(
 
 			Some(CurrencyId::ZenlinkLPToken(token1_id, token1_type, token2_id, token2_type))
 		},
+		(4, LOCAL) if symbol as u64 == LIQUID_STAKING_TOKEN_ID =>
+			Some(CurrencyId::Token(LIQUID_STAKING_TOKEN_ID)),
 		_ => None,
 	}
 }
@@ -117,6 +123,12 @@ pub fn currency_id_to_zenlink_id(
 				+ ((token2_type as u64) << 40);
 			Some(ZenlinkAssetId { chain_id: parachain_id, asset_type: LOCAL, asset_index: index })
 		},
+		// only the liquid staking derivative can be traded
+		CurrencyId::Token(LIQUID_STAKING_TOKEN_ID) => Some(ZenlinkAssetId {
+			chain_id: parachain_id,
+			asset_type: LOCAL,
+			asset_index: (disc << 8) + LIQUID_STAKING_TOKEN_ID,
+		}),
 		CurrencyId::Token(_) => None,
 	}
 }
@@ -299,10 +311,35 @@ mod zenlink_tests {
 		}
 	}
 
+	#[test]
+	fn convert_token_currency_to_zenlink_token() {
+		let fake_currency_id = CurrencyId::Token(0xff);
+		let expected_zenlink_asset =
+			ZenlinkAssetId { chain_id: 1000, asset_type: LOCAL, asset_index: 0x04ff };
+		assert_eq!(currency_id_to_zenlink_id(fake_currency_id, 1000), Some(expected_zenlink_asset));
+	}
+
+	#[test]
+	fn convert_zenlink_token_to_token_currency() {
+		// Token(255) ZenlinkAsset index = 0x0000_0000_0000_04ff
+		let fake_zenlink_asset =
+			ZenlinkAssetId { chain_id: 1000, asset_type: LOCAL, asset_index: 0x04ff };
+		let currency = zenlink_id_to_currency_id(fake_zenlink_asset, 1000);
+		assert_eq!(currency, Some(CurrencyId::Token(0xff)));
+	}
+
 	#[test]
 	fn convert_token_to_zenlink_error() {
+		// tokens other than the liquid staking derivative are not supported
 		let fake_currency_id = CurrencyId::Token(1);
 		assert_eq!(currency_id_to_zenlink_id(fake_currency_id, 1000), None);
+		let fake_currency_id = CurrencyId::Token(0x100);
+		assert_eq!(currency_id_to_zenlink_id(fake_currency_id, 1000), None);
+
+		// Token(1) ZenlinkAsset index = 0x0000_0000_0000_0401
+		let fake_zenlink_asset =
+			ZenlinkAssetId { chain_id: 1000, asset_type: LOCAL, asset_index: 0x0401 };
+		assert_eq!(zenlink_id_to_currency_id(fake_zenlink_asset, 1000), None);
 	}
 
 	#[test]
@@ -655,4 +655,162 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for SubstrateWeight<
 			.saturating_add(Weight::from_parts(0, 35157).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 35643).saturating_mul(n.into()))
 	}
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:0)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Storage: `ParachainStaking::CommissionChangeRequests` (r:0 w:1)
+	fn request_commission_change() -> Weight {
+		Weight::from_parts(16_000_000, 5495)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Storage: `ParachainStaking::CommissionChangeRequests` (r:1 w:1)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Storage: `ParachainStaking::RewardCount` (r:41 w:41)
+	/// Storage: `ParachainStaking::Rewards` (r:41 w:41)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:0)
+	/// Storage: `ParachainStaking::InflationConfig` (r:1 w:0)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// The range of component `m` is `[0, 40]`.
+	fn execute_commission_change(m: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 10420)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+	}
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Storage: `ParachainStaking::CandidatePool` (r:2 w:1)
+	/// Storage: `ParachainStaking::LastDelegation` (r:1 w:1)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Storage: `ParachainStaking::Unstaking` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Storage: `ParachainStaking::MaxSelectedCandidates` (r:1 w:0)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// Storage: `ParachainStaking::RewardCount` (r:1 w:0)
+	/// Storage: `ParachainStaking::DelegationRewardCount` (r:0 w:1)
+	/// The range of component `n` is `[1, 40]`.
+	/// The range of component `m` is `[1, 39]`.
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 10000)
+			.saturating_add(Weight::from_parts(700_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(720_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Storage: `ParachainStaking::RewardCount` (r:1 w:0)
+	/// Storage: `ParachainStaking::DelegationRewardCount` (r:1 w:1)
+	/// Storage: `ParachainStaking::Unstaking` (r:1 w:1)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Storage: `ParachainStaking::MaxSelectedCandidates` (r:1 w:0)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// The range of component `n` is `[1, 40]`.
+	/// The range of component `m` is `[1, 39]`.
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(17_000_000, 6028)
+			.saturating_add(Weight::from_parts(702_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(706_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Storage: `ParachainStaking::CandidatePool` (r:3 w:2)
+	/// Storage: `ParachainStaking::LastDelegation` (r:1 w:1)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Storage: `ParachainStaking::RewardCount` (r:2 w:0)
+	/// Storage: `ParachainStaking::DelegationRewardCount` (r:2 w:2)
+	/// Storage: `ParachainStaking::Rewards` (r:1 w:1)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Storage: `ParachainStaking::MaxSelectedCandidates` (r:1 w:0)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// The range of component `n` is `[2, 40]`.
+	/// The range of component `m` is `[1, 39]`.
+	fn redelegate(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 12000)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_420_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:0)
+	/// Storage: `ParachainStaking::AutoCompound` (r:0 w:1)
+	fn set_auto_compound() -> Weight {
+		Weight::from_parts(12_500_000, 3654)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::MissedRounds` (r:0 w:40)
+	/// Storage: `ParachainStaking::LivenessConfiguration` (r:0 w:1)
+	/// The range of component `n` is `[0, 40]`.
+	fn set_liveness_config(n: u32, ) -> Weight {
+		Weight::from_parts(9_800_000, 1489)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Storage: `ParachainStaking::MaxSelectedCandidates` (r:1 w:0)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// Storage: `ParachainStaking::MissedRounds` (r:0 w:1)
+	/// The range of component `n` is `[1, 40]`.
+	/// The range of component `m` is `[0, 40]`.
+	fn go_online(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(26_000_000, 21400)
+			.saturating_add(Weight::from_parts(640_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_650_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::MaxCollatorCandidateStake` (r:1 w:0)
+	/// Storage: `ParachainStaking::StakingConfiguration` (r:0 w:1)
+	fn set_staking_config() -> Weight {
+		Weight::from_parts(11_200_000, 1505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:0)
+	/// Storage: `ParachainStaking::RewardDestinations` (r:0 w:1)
+	fn set_reward_destination() -> Weight {
+		Weight::from_parts(13_100_000, 4712)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::Invulnerables` (r:0 w:1)
+	/// The range of component `n` is `[0, 4]`.
+	fn set_invulnerables(n: u32, ) -> Weight {
+		Weight::from_parts(7_400_000, 0)
+			.saturating_add(Weight::from_parts(210_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::ExtraRewardRates` (r:1 w:1)
+	fn set_extra_reward_rate() -> Weight {
+		Weight::from_parts(9_800_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:0)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Storage: `ParachainStaking::ExtraRewardsPerStake` (r:35 w:0)
+	/// Storage: `ParachainStaking::ExtraRewardCheckpoints` (r:35 w:35)
+	/// Storage: `ParachainStaking::ExtraRewards` (r:1 w:1)
+	/// Storage: `ParachainStaking::RewardDestinations` (r:1 w:0)
+	/// Storage: `ParachainStaking::ExtraRewardsAllocated` (r:1 w:1)
+	/// The range of component `n` is `[1, 35]`.
+	fn claim_extra_rewards(n: u32, ) -> Weight {
+		Weight::from_parts(52_100_000, 0)
+			.saturating_add(Weight::from_parts(6_300_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
 }
\ No newline at end of file
@@ -100,7 +100,9 @@ use xcm_config::XcmOriginToTransactDispatchOrigin;
 
 use module_oracle_rpc_runtime_api::BalanceWrapper;
 use orml_currencies::BasicCurrencyAdapter;
-use orml_traits::{currency::MutationHooks, parameter_type_with_key};
+use orml_traits::{
+	asset_registry::AssetMetadata, currency::MutationHooks, parameter_type_with_key,
+};
 
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -175,6 +177,10 @@ pub type Executive = frame_executive::Executive<
 	(
 		cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
 		pallet_identity::migration::v1::VersionUncheckedMigrateV0ToV1<Runtime, { u64::MAX }>,
+		parachain_staking::migrations::v8::MigrateToV8<Runtime>,
+		parachain_staking::migrations::v9::MigrateToV9<Runtime>,
+		parachain_staking::migrations::v10::MigrateToV10<Runtime>,
+		asset_registry::RegisterAssetMetadata<Runtime, LiquidStakingAsset>,
 	),
 >;
 
@@ -625,7 +631,7 @@ parameter_types! {
 
 impl pallet_democracy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
+	type Currency = parachain_staking::StakeVotingCurrency<Balances, ParachainStaking>;
 	type EnactmentPeriod = EnactmentPeriod;
 	type LaunchPeriod = LaunchPeriod;
 	type VotingPeriod = VotingPeriod;
@@ -923,12 +929,16 @@ parameter_types! {
 	pub const MinBlocksPerRound: BlockNumber = HOURS;
 	pub const DefaultBlocksPerRound: BlockNumber = 2 * HOURS;
 	pub const StakeDuration: BlockNumber = 7 * DAYS;
+	// liquid staking keeps at most `MaxUnstakeRequests - 1` daily batches unstaking
+	pub const MaxStakeDuration: BlockNumber = 8 * DAYS;
 	pub const ExitQueueDelay: u32 = 2;
 	pub const MinCollators: u32 = 8;
 	pub const MinRequiredCollators: u32 = 4;
 	pub const MaxDelegationsPerRound: u32 = 1;
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxDelegatorsPerCollator: u32 = 40;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorsPerDelegator: u32 = 8;
 	pub const MinCollatorStake: Balance = 5_000 * UNIT;
 	pub const MinDelegatorStake: Balance = 10 * UNIT;
 	#[derive(Debug, Eq, PartialEq)]
@@ -936,7 +946,21 @@ parameter_types! {
 	pub const MaxUnstakeRequests: u32 = 10;
 	pub const NetworkRewardStart: BlockNumber = BlockNumber::MAX;
 	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(0);
+	pub const MaxNetworkRewardRate: Perquintill = Perquintill::from_percent(10);
 	pub const CollatorRewardRateDecay: Perquintill = Perquintill::from_parts(938_252_045_000_000_000u64);
+	pub const MaxCollatorCommission: Perquintill = Perquintill::from_percent(20);
+	pub const CommissionChangeDelay: u32 = 12;
+	// one week worth of rounds
+	pub const MaxRoundSnapshots: u32 = 7 * DAYS / DefaultBlocksPerRound::get();
+	pub const MaxPayoutStakers: u32 = 64;
+	pub StakeLockPeriods: Vec<(BlockNumber, Perquintill)> = vec![
+		(30 * DAYS, Perquintill::from_percent(5)),
+		(90 * DAYS, Perquintill::from_percent(15)),
+		(180 * DAYS, Perquintill::from_percent(30)),
+	];
+	pub const ParachainStakingExtraRewardsPotId: PalletId = PalletId(*b"pe/stkrw");
+	pub const MaxExtraRewardCurrencies: u32 = 8;
+	pub const DisplacedDelegatorGracePeriod: bool = true;
 }
 
 impl parachain_staking::Config for Runtime {
@@ -952,18 +976,57 @@ impl parachain_staking::Config for Runtime {
 	type MinRequiredCollators = MinRequiredCollators;
 	type MaxDelegationsPerRound = MaxDelegationsPerRound;
 	type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
 	type MinCollatorStake = MinCollatorStake;
 	type MinCollatorCandidateStake = MinCollatorStake;
 	type MaxTopCandidates = MaxTopCandidates;
 	type MinDelegatorStake = MinDelegatorStake;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type NetworkRewardRate = NetworkRewardRate;
+	type MaxNetworkRewardRate = MaxNetworkRewardRate;
+	type MaxStakeDuration = MaxStakeDuration;
 	type NetworkRewardStart = NetworkRewardStart;
 	type NetworkRewardBeneficiary = Treasury;
 	type CollatorRewardRateDecay = CollatorRewardRateDecay;
 	type WeightInfo = weights::parachain_staking::SubstrateWeight<Runtime>;
 	const BLOCKS_PER_YEAR: BlockNumber = BLOCKS_PER_YEAR;
 	type TreasuryAccount = PendulumTreasuryAccount;
+	type MaxCollatorCommission = MaxCollatorCommission;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type MaxRoundSnapshots = MaxRoundSnapshots;
+	type MaxPayoutStakers = MaxPayoutStakers;
+	type StakeLockPeriods = StakeLockPeriods;
+	type OnStakingUpdate = LiquidStaking;
+	type DisplacedDelegatorGracePeriod = DisplacedDelegatorGracePeriod;
+	type ExtraRewardCurrency = Tokens;
+	type ExtraRewardsPotId = ParachainStakingExtraRewardsPotId;
+	type MaxExtraRewardCurrencies = MaxExtraRewardCurrencies;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ExtraRewardBenchmarkCurrencyId = RelayChainCurrencyId;
+}
+
+parameter_types! {
+	pub const LiquidStakingPalletId: PalletId = PalletId(*b"pe/lqstk");
+	pub const LiquidStakingCurrencyId: CurrencyId = CurrencyId::Token(runtime_common::LIQUID_STAKING_TOKEN_ID);
+	pub const MinLiquidStakingDeposit: Balance = UNIT;
+	// at most `StakeDuration / RedemptionPeriod` batches are unstaking at the same time
+	pub const RedemptionPeriod: BlockNumber = DAYS;
+	pub const MaxRedemptionRequests: u32 = 10;
+	pub LiquidStakingAsset: (CurrencyId, AssetMetadata<Balance, asset_registry::CustomMetadata, StringLimit>) = (
+		LiquidStakingCurrencyId::get(),
+		asset_registry::local_asset_metadata("Staked PEN", "stPEN", 12, NANOUNIT),
+	);
+}
+
+impl liquid_staking::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Tokens = Tokens;
+	type DerivativeCurrencyId = LiquidStakingCurrencyId;
+	type PalletId = LiquidStakingPalletId;
+	type MinDeposit = MinLiquidStakingDeposit;
+	type RedemptionPeriod = RedemptionPeriod;
+	type MaxRedemptionRequests = MaxRedemptionRequests;
+	type WeightInfo = weights::liquid_staking::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -1481,11 +1544,21 @@ impl spacewalk_primitives::DecimalsLookup for DecimalsLookupImpl {
 
 parameter_types! {
 	pub const SellFee: Permill = Permill::from_percent(5);
+	pub const BuyFee: Permill = Permill::from_percent(5);
+	// 1 hour in seconds
+	pub const MaxPriceAge: u64 = 3600;
+	pub const MaxPriceDeviation: Permill = Permill::from_percent(10);
+	// 7 days in blocks (where average block time is 12 seconds)
+	pub const MaxReferencePriceAge: u32 = 7 * 7200;
 	pub const MinAmountToBuyout: Balance = 100 * MILLIUNIT; // 0.1 PEN or 100_000_000_000
+	pub const MinAmountToSell: Balance = 100 * MILLIUNIT; // 0.1 PEN or 100_000_000_000
 	// 24 hours in blocks (where average block time is 12 seconds)
 	pub const BuyoutPeriod: u32 = 7200;
 	// Maximum number of allowed currencies for buyout
 	pub const MaxAllowedBuyoutCurrencies: u32 = 20;
+	// Number of buyout periods (30 days) and buyouts per period kept in the buyout history
+	pub const MaxBuyoutHistoryPeriods: u32 = 30;
+	pub const MaxBuyoutRecordsPerPeriod: u32 = 100;
 }
 
 impl treasury_buyout_extension::Config for Runtime {
@@ -1494,10 +1567,17 @@ impl treasury_buyout_extension::Config for Runtime {
 	type TreasuryAccount = PendulumTreasuryAccount;
 	type BuyoutPeriod = BuyoutPeriod;
 	type SellFee = SellFee;
+	type BuyFee = BuyFee;
+	type MaxPriceAge = MaxPriceAge;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type MaxReferencePriceAge = MaxReferencePriceAge;
 	type PriceGetter = runtime_common::OraclePriceGetter<Runtime>;
 	type DecimalsLookup = DecimalsLookupImpl;
 	type MinAmountToBuyout = MinAmountToBuyout;
+	type MinAmountToSell = MinAmountToSell;
 	type MaxAllowedBuyoutCurrencies = MaxAllowedBuyoutCurrencies;
+	type MaxBuyoutHistoryPeriods = MaxBuyoutHistoryPeriods;
+	type MaxBuyoutRecordsPerPeriod = MaxBuyoutRecordsPerPeriod;
 	type WeightInfo = weights::treasury_buyout_extension::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type RelayChainCurrencyId = RelayChainCurrencyId;
@@ -1576,6 +1656,7 @@ construct_runtime!(
 
 		TokenAllowance: orml_currencies_allowance_extension = 80,
 		TreasuryBuyoutExtension: treasury_buyout_extension = 82,
+		LiquidStaking: liquid_staking = 83,
 
 		//Farming
 		Farming: farming = 90,
@@ -1617,6 +1698,7 @@ mod benches {
 
 		[orml_currencies_allowance_extension, TokenAllowance]
 		[treasury_buyout_extension, TreasuryBuyoutExtension]
+		[liquid_staking, LiquidStaking]
 
 		[dia_oracle, DiaOracleModule]
 	);
@@ -1761,6 +1843,64 @@ impl_runtime_apis! {
 		fn get_staking_rates() -> module_pallet_staking_rpc_runtime_api::StakingRates {
 			ParachainStaking::get_staking_rates()
 		}
+
+		fn get_collator_commission(account: AccountId) -> Option<module_pallet_staking_rpc_runtime_api::CollatorCommission> {
+			ParachainStaking::get_collator_commission(&account)
+		}
+
+		fn get_auto_compound(account: AccountId) -> Perquintill {
+			ParachainStaking::auto_compound(&account)
+		}
+
+		fn get_round_snapshot(round: u32) -> Vec<module_pallet_staking_rpc_runtime_api::CollatorSnapshot<AccountId, Balance>> {
+			ParachainStaking::get_round_snapshot(round)
+		}
+
+		fn get_candidates() -> Vec<module_pallet_staking_rpc_runtime_api::CandidateInfo<AccountId, Balance>> {
+			ParachainStaking::get_candidates()
+		}
+
+		fn get_delegations(account: AccountId) -> Vec<module_pallet_staking_rpc_runtime_api::DelegationInfo<AccountId, Balance>> {
+			ParachainStaking::get_delegations(&account)
+		}
+
+		fn get_unstaking(account: AccountId) -> Vec<module_pallet_staking_rpc_runtime_api::UnstakingInfo<BlockNumber, Balance>> {
+			ParachainStaking::get_unstaking(&account)
+		}
+
+		fn get_collator_apy(account: AccountId) -> Option<module_pallet_staking_rpc_runtime_api::CollatorApy> {
+			ParachainStaking::get_collator_apy(&account)
+		}
+
+		fn get_next_round_start() -> BlockNumber {
+			ParachainStaking::get_next_round_start()
+		}
+
+		fn would_enter_top_delegators(delegator: AccountId, collator: AccountId, amount: Balance) -> bool {
+			ParachainStaking::would_enter_top_delegators(&delegator, &collator, amount)
+		}
+
+		fn get_displaced_delegations(account: AccountId) -> Vec<module_pallet_staking_rpc_runtime_api::DisplacedDelegation<AccountId, Balance>> {
+			ParachainStaking::get_displaced_delegations(&account)
+		}
+	}
+
+	impl treasury_buyout_extension_rpc_runtime_api::TreasuryBuyoutApi<Block, AccountId, CurrencyId, Balance> for Runtime {
+		fn get_buyout_quote(asset: CurrencyId, amount: treasury_buyout_extension_rpc_runtime_api::Amount<Balance>) -> Result<treasury_buyout_extension_rpc_runtime_api::BuyoutQuote<Balance>, DispatchError> {
+			TreasuryBuyoutExtension::get_buyout_quote(asset, amount)
+		}
+
+		fn get_buyout_allowance(account: AccountId) -> Option<treasury_buyout_extension_rpc_runtime_api::BuyoutAllowance<Balance>> {
+			TreasuryBuyoutExtension::get_buyout_allowance(&account)
+		}
+
+		fn get_allowed_currencies() -> Vec<CurrencyId> {
+			TreasuryBuyoutExtension::get_allowed_currencies()
+		}
+
+		fn get_buyout_history() -> Vec<treasury_buyout_extension_rpc_runtime_api::BuyoutPeriodReport<AccountId, CurrencyId, Balance>> {
+			TreasuryBuyoutExtension::get_buyout_history()
+		}
 	}
 
 